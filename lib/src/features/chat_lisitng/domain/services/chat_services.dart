@@ -1,3 +1,4 @@
+import 'package:matrix/src/rust/matrix/room_list.dart' as room_list;
 import 'package:matrix/src/rust/matrix/rooms.dart' as rooms;
 import 'package:result_dart/result_dart.dart';
 
@@ -13,7 +14,7 @@ class ChatServices {
     }
   }
 
-  Stream<rooms.RoomUpdate> subscribeToAllRoomUpdates() {
-    return rooms.subscribeToAllRoomUpdates().map((roomUpdate) => roomUpdate);
+  Stream<List<room_list.RoomListUpdate>> subscribeToAllRoomUpdates() {
+    return rooms.subscribeToAllRoomUpdates();
   }
 }
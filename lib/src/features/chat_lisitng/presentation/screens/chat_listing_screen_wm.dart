@@ -5,6 +5,8 @@ import 'package:matrix/src/features/chat_lisitng/domain/models/chat_state.dart';
 import 'package:matrix/src/features/chat_lisitng/domain/services/chat_services.dart';
 import 'package:matrix/src/features/chat_lisitng/presentation/screens/chat_listing_screen.dart';
 import 'package:matrix/src/core/logging_service.dart';
+import 'package:matrix/src/rust/matrix/room_list.dart';
+import 'package:matrix/src/rust/matrix/rooms.dart';
 
 class ChatListingScreenModel extends ElementaryModel {
   ChatListingScreenModel(this._chatServices) : super();
@@ -13,28 +15,7 @@ class ChatListingScreenModel extends ElementaryModel {
   Future<List<Chat>> loadRooms() async {
     final result = await _chatServices.loadRooms();
     return result.fold(
-      (success) =>
-          success
-              .map(
-                (room) => Chat(
-                  id: room.roomId,
-                  name: room.displayName ?? room.rawName ?? '',
-                  lastMessage: room.message?.content ?? '',
-                  lastActivity:
-                      (room.message?.timestamp ?? BigInt.from(0)) >
-                              BigInt.from(0)
-                          ? DateTime.fromMillisecondsSinceEpoch(
-                            room.message!.timestamp.toInt(),
-                          )
-                          : null,
-                  isDirect: room.isDm ?? false,
-                  unreadCount: room.unreadMessages?.toInt() ?? 0,
-                  status: ChatRoomStatus.values.firstWhere(
-                    (status) => status.name == room.updateType.name,
-                  ),
-                ),
-              )
-              .toList(),
+      (success) => success.map(chatFromRoom).toList(),
       (failure) {
         LoggingService.error(
           'CHAT_LISTING_SCREEN',
@@ -45,25 +26,27 @@ class ChatListingScreenModel extends ElementaryModel {
     );
   }
 
-  Stream<Chat> subscribeToAllRoomUpdates() {
-    return _chatServices.subscribeToAllRoomUpdates().map((roomUpdate) {
-      return Chat(
-        id: roomUpdate.roomId,
-        name: roomUpdate.displayName ?? roomUpdate.rawName ?? '',
-        lastMessage: roomUpdate.message?.content ?? '',
-        lastActivity:
-            (roomUpdate.message?.timestamp ?? BigInt.from(0)) > BigInt.from(0)
-                ? DateTime.fromMillisecondsSinceEpoch(
-                  roomUpdate.message!.timestamp.toInt(),
-                )
-                : null,
-        isDirect: roomUpdate.isDm ?? false,
-        unreadCount: roomUpdate.unreadMessages?.toInt() ?? 0,
-        status: ChatRoomStatus.values.firstWhere(
-          (status) => status.name == roomUpdate.updateType.name,
-        ),
-      );
-    });
+  Stream<List<RoomListUpdate>> subscribeToAllRoomUpdates() {
+    return _chatServices.subscribeToAllRoomUpdates();
+  }
+
+  Chat chatFromRoom(RoomUpdate room) {
+    return Chat(
+      id: room.roomId,
+      name: room.displayName ?? room.rawName ?? '',
+      lastMessage: room.message?.content ?? '',
+      lastActivity:
+          (room.message?.timestamp ?? BigInt.from(0)) > BigInt.from(0)
+              ? DateTime.fromMillisecondsSinceEpoch(
+                room.message!.timestamp.toInt(),
+              )
+              : null,
+      isDirect: room.isDm ?? false,
+      unreadCount: room.unreadMessages?.toInt() ?? 0,
+      status: ChatRoomStatus.values.firstWhere(
+        (status) => status.name == room.updateType.name,
+      ),
+    );
   }
 }
 
@@ -142,51 +125,69 @@ class ChatListingScreenWM
     model.subscribeToAllRoomUpdates().listen((updates) {
       LoggingService.info(
         'CHAT_LISTING_SCREEN',
-        'Received chat update: $updates',
+        'Received ${updates.length} room list updates',
       );
-      final int index = _rooms.indexWhere(
-        (element) => element.id == updates.id,
-      );
-      switch (updates.status) {
-        case ChatRoomStatus.joined:
-          if (index == -1) {
-            _rooms.add(updates);
-          } else {
-            _rooms[index] = updates;
-          }
-          break;
-        case ChatRoomStatus.left:
-          if (index != -1) {
-            _rooms.removeAt(index);
-          }
-          break;
-        case ChatRoomStatus.invited:
-          if (index == -1) {
-            _rooms.add(updates);
-          } else {
-            _rooms[index] = updates;
-          }
-          break;
-        case ChatRoomStatus.knocked:
-          _rooms.removeWhere((element) => element.id == updates.id);
-          break;
-        case ChatRoomStatus.banned:
-          if (index == -1) {
-            _rooms.add(updates);
-          } else {
-            _rooms[index] = updates;
-          }
-          break;
+      for (final update in updates) {
+        _applyRoomListUpdate(update);
       }
-      _rooms.sort(
-        (a, b) =>
-            b.lastActivity?.compareTo(a.lastActivity ?? DateTime.now()) ?? 0,
-      );
       _chatState.value = ChatState.loaded(rooms: [..._rooms]);
       _selectedChatType.value = _selectedChatType.value;
     });
   }
 
+  // The updates mirror the SDK's room list diffs, so applying them in order
+  // keeps `_rooms` sorted like the SDK does.
+  void _applyRoomListUpdate(RoomListUpdate update) {
+    final rooms = update.rooms?.map(model.chatFromRoom).toList() ?? [];
+    final index = update.index?.toInt();
+    switch (update.updateType) {
+      case RoomListUpdateType.reset:
+        _rooms = rooms;
+        break;
+      case RoomListUpdateType.append:
+      case RoomListUpdateType.pushBack:
+        _rooms.addAll(rooms);
+        break;
+      case RoomListUpdateType.pushFront:
+        _rooms.insertAll(0, rooms);
+        break;
+      case RoomListUpdateType.insert:
+        if (index != null && index <= _rooms.length) {
+          _rooms.insertAll(index, rooms);
+        }
+        break;
+      case RoomListUpdateType.set_:
+        if (index != null && index < _rooms.length && rooms.length == 1) {
+          _rooms[index] = rooms.first;
+        }
+        break;
+      case RoomListUpdateType.remove:
+        if (index != null && index < _rooms.length) {
+          _rooms.removeAt(index);
+        }
+        break;
+      case RoomListUpdateType.popFront:
+        if (_rooms.isNotEmpty) {
+          _rooms.removeAt(0);
+        }
+        break;
+      case RoomListUpdateType.popBack:
+        if (_rooms.isNotEmpty) {
+          _rooms.removeLast();
+        }
+        break;
+      case RoomListUpdateType.truncate:
+        final length = update.length?.toInt();
+        if (length != null && length < _rooms.length) {
+          _rooms.removeRange(length, _rooms.length);
+        }
+        break;
+      case RoomListUpdateType.clear:
+        _rooms.clear();
+        break;
+    }
+  }
+
   void retry() {
     _loadAllChats();
   }
@@ -13,8 +13,21 @@ class ConversationService {
     }
   }
 
-  Stream<timelines.MessageUpdate> subscribeToTimelineUpdates(String roomId) {
-    return timelines.subscribeToTimelineUpdates(roomId: roomId);
+  Stream<List<timelines.MessageUpdate>> subscribeToTimelineUpdates(
+    String roomId,
+  ) async* {
+    final subscriptionId = await timelines.newTimelineSubscriptionId();
+    try {
+      yield* timelines.subscribeToTimelineUpdates(
+        roomId: roomId,
+        subscriptionId: subscriptionId,
+      );
+    } finally {
+      await timelines.unsubscribeFromTimelineUpdates(
+        roomId: roomId,
+        subscriptionId: subscriptionId,
+      );
+    }
   }
 
   Future<ConversationInfo> loadRoomInfo() async {
@@ -53,16 +66,16 @@ class ConversationService {
     }
   }
 
-  Future<Result<List<timelines.Message>>> fetchOlderMessages({
+  Future<Result<bool>> fetchOlderMessages({
     required String roomId,
     int count = 20,
   }) async {
     try {
-      final previousMessages = await timelines.getOlderMessages(
+      final hasMore = await timelines.getOlderMessages(
         roomId: roomId,
         count: count,
       );
-      return Success(previousMessages);
+      return Success(hasMore);
     } catch (e) {
       return Failure(Exception(e));
     }
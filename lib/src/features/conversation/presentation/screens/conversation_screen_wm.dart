@@ -1,3 +1,5 @@
+import 'dart:async';
+
 import 'package:elementary/elementary.dart';
 import 'package:flutter/material.dart';
 
@@ -30,7 +32,7 @@ class ConversationScreenModel extends ElementaryModel {
     return result;
   }
 
-  Stream<MessageUpdate> subscribeToChatUpdates(String roomId) {
+  Stream<List<MessageUpdate>> subscribeToChatUpdates(String roomId) {
     return conversationService.subscribeToTimelineUpdates(roomId);
   }
 
@@ -42,15 +44,15 @@ class ConversationScreenModel extends ElementaryModel {
     return conversationService.rejectInvite(roomId);
   }
 
-  Future<Result<List<Message>>> fetchOlderMessages({
+  Future<Result<bool>> fetchOlderMessages({
     required String conversationId,
     int count = 20,
   }) async {
-    final messages = await conversationService.fetchOlderMessages(
+    final hasMore = await conversationService.fetchOlderMessages(
       roomId: conversationId,
       count: count,
     );
-    return messages;
+    return hasMore;
   }
 }
 
@@ -60,6 +62,7 @@ class ConversationScreenWM
   late final ValueNotifier<ConversationState> _roomState;
   late final ValueNotifier<bool> _isInvited;
   late final TextEditingController _messageController;
+  StreamSubscription<List<MessageUpdate>>? _chatUpdates;
 
   ValueNotifier<ConversationState> get roomState => _roomState;
   TextEditingController get messageController => _messageController;
@@ -84,6 +87,7 @@ class ConversationScreenWM
 
   @override
   void dispose() {
+    _chatUpdates?.cancel();
     _roomState.dispose();
     _messageController.dispose();
     _isInvited.dispose();
@@ -148,103 +152,112 @@ class ConversationScreenWM
   }
 
   void _listenToChatUpdates() {
-    model.subscribeToChatUpdates(widget.roomId).listen((update) {
+    _chatUpdates?.cancel();
+    _chatUpdates = model.subscribeToChatUpdates(widget.roomId).listen((
+      updates,
+    ) {
       final currentState = roomState.value;
       if (currentState is! RoomStateLoaded) {
         return;
       }
-      List<Message> newMessages = [];
-      switch (update.messageUpdateType) {
-        case MessageUpdateType.append:
-          if (update.messages != null) {
-            newMessages = [...currentState.messages, ...update.messages ?? []];
-          }
-          break;
-        case MessageUpdateType.pushFront:
-          if (update.messages != null && update.messages!.length == 1) {
-            newMessages = [...update.messages ?? [], ...currentState.messages];
-          }
-          break;
-        case MessageUpdateType.remove:
-          if (update.index != null &&
-              update.index!.toInt() < currentState.messages.length &&
-              update.index!.toInt() >= 0) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.removeAt(update.index!.toInt());
-          }
-          break;
-        case MessageUpdateType.reset:
-          if (update.messages != null) {
-            newMessages = update.messages ?? [];
-          }
-          break;
-        case MessageUpdateType.truncate:
-          if (update.index != null &&
-              update.index!.toInt() < currentState.messages.length &&
-              update.index!.toInt() >= 0) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.removeRange(update.index!.toInt(), newMessages.length);
-          }
-          break;
-        case MessageUpdateType.set_:
-          if (update.index != null &&
-              update.messages != null &&
-              update.messages!.length == 1 &&
-              update.index!.toInt() < currentState.messages.length &&
-              update.index!.toInt() >= 0) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages[update.index!.toInt()] = update.messages!.first;
-          }
-          break;
-        case MessageUpdateType.insert:
-          if (update.index != null &&
-              update.messages != null &&
-              update.messages!.length == 1 &&
-              update.index!.toInt() < currentState.messages.length &&
-              update.index!.toInt() >= 0) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.insert(update.index!.toInt(), update.messages!.first);
-          }
-          break;
-        case MessageUpdateType.popBack:
-          if (update.index != null &&
-              update.messages != null &&
-              update.messages!.length == 1) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.removeLast();
-          }
-          break;
-        case MessageUpdateType.popFront:
-          if (update.messages != null && update.messages!.length == 1) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.removeAt(0);
-          }
-          break;
-
-        case MessageUpdateType.pushBack:
-          if (update.messages != null && update.messages!.length == 1) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.add(update.messages!.first);
-          }
-          break;
-
-        case MessageUpdateType.clear:
-          if (update.messages != null && update.messages!.length == 1) {
-            newMessages = List<Message>.from(currentState.messages);
-            newMessages.clear();
-          }
-          break;
+      var messages = currentState.messages;
+      for (final update in updates) {
+        messages = _applyMessageUpdate(messages, update);
       }
 
       if (!_disposed) {
         _roomState.value = ConversationState.loaded(
-          messages: newMessages,
+          messages: messages,
           roomInfo: currentState.roomInfo,
         );
       }
     });
   }
 
+  List<Message> _applyMessageUpdate(
+    List<Message> messages,
+    MessageUpdate update,
+  ) {
+    var newMessages = messages;
+    switch (update.messageUpdateType) {
+      case MessageUpdateType.append:
+        if (update.messages != null) {
+          newMessages = [...messages, ...update.messages ?? []];
+        }
+        break;
+      case MessageUpdateType.pushFront:
+        if (update.messages != null && update.messages!.length == 1) {
+          newMessages = [...update.messages ?? [], ...messages];
+        }
+        break;
+      case MessageUpdateType.remove:
+        if (update.index != null &&
+            update.index!.toInt() < messages.length &&
+            update.index!.toInt() >= 0) {
+          newMessages = List<Message>.from(messages);
+          newMessages.removeAt(update.index!.toInt());
+        }
+        break;
+      case MessageUpdateType.reset:
+        if (update.messages != null) {
+          newMessages = update.messages ?? [];
+        }
+        break;
+      case MessageUpdateType.truncate:
+        if (update.length != null &&
+            update.length!.toInt() < messages.length &&
+            update.length!.toInt() >= 0) {
+          newMessages = List<Message>.from(messages);
+          newMessages.removeRange(update.length!.toInt(), newMessages.length);
+        }
+        break;
+      case MessageUpdateType.set_:
+        if (update.index != null &&
+            update.messages != null &&
+            update.messages!.length == 1 &&
+            update.index!.toInt() < messages.length &&
+            update.index!.toInt() >= 0) {
+          newMessages = List<Message>.from(messages);
+          newMessages[update.index!.toInt()] = update.messages!.first;
+        }
+        break;
+      case MessageUpdateType.insert:
+        if (update.index != null &&
+            update.messages != null &&
+            update.messages!.length == 1 &&
+            update.index!.toInt() <= messages.length &&
+            update.index!.toInt() >= 0) {
+          newMessages = List<Message>.from(messages);
+          newMessages.insert(update.index!.toInt(), update.messages!.first);
+        }
+        break;
+      case MessageUpdateType.popBack:
+        if (messages.isNotEmpty) {
+          newMessages = List<Message>.from(messages);
+          newMessages.removeLast();
+        }
+        break;
+      case MessageUpdateType.popFront:
+        if (messages.isNotEmpty) {
+          newMessages = List<Message>.from(messages);
+          newMessages.removeAt(0);
+        }
+        break;
+
+      case MessageUpdateType.pushBack:
+        if (update.messages != null && update.messages!.length == 1) {
+          newMessages = List<Message>.from(messages);
+          newMessages.add(update.messages!.first);
+        }
+        break;
+
+      case MessageUpdateType.clear:
+        newMessages = [];
+        break;
+    }
+    return newMessages;
+  }
+
   Future<void> acceptInvite() async {
     final result = await model.acceptInvite(widget.roomId);
     if (result.isSuccess()) {
@@ -260,7 +273,7 @@ class ConversationScreenWM
     }
   }
 
-  Future<List<Message>> fetchOlderMessages({
+  Future<bool> fetchOlderMessages({
     required String conversationId,
     int limit = 20,
   }) async {
@@ -269,6 +282,6 @@ class ConversationScreenWM
       count: limit,
     );
 
-    return result.fold((success) => success, (failure) => []);
+    return result.fold((hasMore) => hasMore, (failure) => false);
   }
 }
@@ -37,7 +37,8 @@ class _PaginatedMessageListState extends State<PaginatedMessageList> {
     super.initState();
     if (widget.initialMessages.isNotEmpty) {
       _hasMore =
-          widget.initialMessages.first.messageType != MessageType.timelineStart;
+          widget.initialMessages.first.messageType
+              is! MessageType_TimelineStart;
       _controller.addListener(_onScroll);
 
       WidgetsBinding.instance.addPostFrameCallback((_) {
@@ -91,7 +92,8 @@ class _PaginatedMessageListState extends State<PaginatedMessageList> {
       widget.initialMessages.insertAll(0, older);
       _isLoadingOlder = false;
       _hasMore =
-          widget.initialMessages.first.messageType != MessageType.timelineStart;
+          widget.initialMessages.first.messageType
+              is! MessageType_TimelineStart;
     });
 
     // Adjust by delta in maxScrollExtent so content doesn't jump.
@@ -171,10 +173,8 @@ class _PaginatedMessageListState extends State<PaginatedMessageList> {
                   final message = display[index];
 
                   // Filter system markers here or render date dividers lazily
-                  if ([
-                        MessageType.dateDivider,
-                        MessageType.readMarker,
-                      ].contains(message.messageType) ||
+                  if (message.messageType is MessageType_DateDivider ||
+                      message.messageType is MessageType_ReadMarker ||
                       message.content.isEmpty) {
                     return const SizedBox.shrink();
                   }
@@ -14,9 +14,26 @@ import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'matrix/authentication.dart';
 import 'matrix/client.dart';
+import 'matrix/direct_messages.dart';
+import 'matrix/directory.dart';
+import 'matrix/ignored_users.dart';
+import 'matrix/invites.dart';
+import 'matrix/joining.dart';
+import 'matrix/knocking.dart';
+import 'matrix/members.dart';
+import 'matrix/moderation.dart';
+import 'matrix/presence.dart';
+import 'matrix/profile.dart';
+import 'matrix/receipts.dart';
+import 'matrix/reporting.dart';
+import 'matrix/room_list.dart';
+import 'matrix/room_settings.dart';
 import 'matrix/rooms.dart';
+import 'matrix/spaces.dart';
 import 'matrix/sync_service.dart';
+import 'matrix/tags.dart';
 import 'matrix/timelines.dart';
+import 'matrix/typing.dart';
 import 'matrix/user_serach.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'third_party/reqwest/tls.dart';
@@ -76,7 +93,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1787116871;
+  int get rustContentHash => 1184203577;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -87,15 +104,45 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<CreateRoomParams> crateMatrixRoomsCreateRoomParamsDefault();
+
   Future<FieldsFormatterForFiles>
   crateApiPlatformFieldsFormatterForFilesDefault();
 
+  Future<PowerLevelOverrides> crateMatrixRoomsPowerLevelOverridesDefault();
+
+  Future<RoomVisibility> crateMatrixRoomsRoomVisibilityDefault();
+
   Future<void> crateApiLoggerLog({
     required String level,
     required String message,
     required String tag,
   });
 
+  Future<String> crateMatrixInvitesAcceptInvite({required String roomId});
+
+  Future<void> crateMatrixKnockingAcceptKnockRequest({
+    required String roomId,
+    required String userId,
+  });
+
+  Future<bool> crateMatrixDirectoryAddRoomAlias({
+    required String roomId,
+    required String alias,
+  });
+
+  Future<void> crateMatrixSpacesAddRoomToSpace({
+    required String spaceId,
+    required String roomId,
+    required bool suggested,
+  });
+
+  Future<void> crateMatrixModerationBanUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  });
+
   Future<String> crateApiInitCheckMatrixSdkStatus();
 
   Future<bool> crateMatrixClientConfigureClient({required ClientConfig config});
@@ -109,21 +156,103 @@ abstract class RustLibApi extends BaseApi {
 
   Stream<LogEntry> crateApiLoggerCreateLogStream();
 
+  Future<String> crateMatrixRoomsCreateRoom({required CreateRoomParams params});
+
+  Future<String> crateMatrixSpacesCreateSpace({
+    required CreateRoomParams params,
+  });
+
+  Future<String> crateMatrixInvitesDeclineInvite({
+    required String roomId,
+    String? reason,
+    required bool ignoreInviter,
+  });
+
+  Future<void> crateMatrixKnockingDeclineKnockRequest({
+    required String roomId,
+    required String userId,
+    String? reason,
+    required bool ban,
+  });
+
+  Future<void> crateMatrixRoomSettingsEnableRoomEncryption({
+    required String roomId,
+  });
+
   Future<List<RoomUpdate>> crateMatrixRoomsGetAllRooms();
 
+  Future<List<String>> crateMatrixIgnoredUsersGetIgnoredUsers();
+
+  Future<InviteDetails> crateMatrixInvitesGetInviteDetails({
+    required String roomId,
+  });
+
+  Future<List<InviteDetails>> crateMatrixInvitesGetInvites();
+
+  Future<List<RoomUpdate>> crateMatrixSpacesGetJoinedSpaces();
+
   Future<String> crateApiInitGetMatrixConfig();
 
-  Future<List<Message>> crateMatrixTimelinesGetOlderMessages({
+  Future<bool> crateMatrixTimelinesGetOlderMessages({
     required String roomId,
     required int count,
   });
 
+  Future<String> crateMatrixDirectMessagesGetOrCreateDm({
+    required String userId,
+  });
+
+  Future<User> crateMatrixProfileGetOwnProfile();
+
+  Future<ReadReceipt?> crateMatrixReceiptsGetOwnReadReceipt({
+    required String roomId,
+  });
+
+  Future<RoomAliases> crateMatrixDirectoryGetRoomAliases({
+    required String roomId,
+  });
+
+  Future<RoomMembersPage> crateMatrixMembersGetRoomMembers({
+    required String roomId,
+    required int offset,
+    required int limit,
+  });
+
+  Future<RoomPermissions> crateMatrixModerationGetRoomPermissions({
+    required String roomId,
+  });
+
+  Future<RoomPowerLevelsInfo> crateMatrixModerationGetRoomPowerLevels({
+    required String roomId,
+  });
+
+  Future<RoomPreviewInfo> crateMatrixJoiningGetRoomPreview({
+    required String target,
+    required List<String> viaServers,
+  });
+
+  Future<SpaceHierarchyPage> crateMatrixSpacesGetSpaceHierarchy({
+    required String spaceId,
+    String? from,
+    int? limit,
+    int? maxDepth,
+    required bool suggestedOnly,
+  });
+
   Future<String> crateApiInitGetSyncOperationsStatus();
 
   Future<List<Message>> crateMatrixTimelinesGetTimelineItemsByRoomId({
     required String roomId,
   });
 
+  Future<UserPresence> crateMatrixPresenceGetUserPresence({
+    required String userId,
+  });
+
+  Future<User> crateMatrixProfileGetUserProfile({required String userId});
+
+  Future<void> crateMatrixIgnoredUsersIgnoreUser({required String userId});
+
   Future<void> crateApiInitInitApp();
 
   Stream<LogEntry> crateApiLoggerInitLogger();
@@ -133,10 +262,34 @@ abstract class RustLibApi extends BaseApi {
     required bool useLightweightTokioRuntime,
   });
 
+  Future<void> crateMatrixModerationInviteUser({
+    required String roomId,
+    required String userId,
+  });
+
   Future<bool> crateMatrixAuthenticationIsClientAuthenticated();
 
+  Future<bool> crateMatrixDirectoryIsRoomPublished({required String roomId});
+
   Future<String> crateMatrixRoomsJoinRoom({required String roomId});
 
+  Future<String> crateMatrixJoiningJoinRoomByIdOrAlias({
+    required String target,
+    required List<String> viaServers,
+  });
+
+  Future<void> crateMatrixModerationKickUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  });
+
+  Future<String> crateMatrixKnockingKnockRoom({
+    required String target,
+    String? reason,
+    required List<String> viaServers,
+  });
+
   Future<String> crateMatrixRoomsLeaveRoom({required String roomId});
 
   Future<void> crateApiLoggerLogDebug({required String message});
@@ -162,6 +315,36 @@ abstract class RustLibApi extends BaseApi {
 
   Future<bool> crateMatrixAuthenticationLogout();
 
+  Future<void> crateMatrixKnockingMarkKnockRequestsAsSeen({
+    required String roomId,
+    required List<String> userIds,
+  });
+
+  Future<void> crateMatrixReceiptsMarkRoomAsRead({
+    required String roomId,
+    required ReceiptKind kind,
+  });
+
+  Future<BigInt> crateMatrixPresenceNewPresenceSubscriptionId();
+
+  Future<BigInt> crateMatrixRoomListNewRoomListSubscriptionId();
+
+  Future<BigInt> crateMatrixTimelinesNewTimelineSubscriptionId();
+
+  Future<bool> crateMatrixTimelinesPaginateEventTimelineBackwards({
+    required String roomId,
+    required int count,
+  });
+
+  Future<bool> crateMatrixTimelinesPaginateEventTimelineForwards({
+    required String roomId,
+    required int count,
+  });
+
+  Future<void> crateMatrixTimelinesPreloadTimelines({
+    required List<String> roomIds,
+  });
+
   Future<bool> crateMatrixAuthenticationRegister({
     required String username,
     required String password,
@@ -171,6 +354,69 @@ abstract class RustLibApi extends BaseApi {
     required TracingFileConfiguration configuration,
   });
 
+  Future<void> crateMatrixProfileRemoveAvatar();
+
+  Future<bool> crateMatrixDirectoryRemoveRoomAlias({
+    required String roomId,
+    required String alias,
+  });
+
+  Future<void> crateMatrixRoomSettingsRemoveRoomAvatar({
+    required String roomId,
+  });
+
+  Future<void> crateMatrixSpacesRemoveRoomFromSpace({
+    required String spaceId,
+    required String roomId,
+  });
+
+  Future<void> crateMatrixTagsRemoveRoomTag({
+    required String roomId,
+    required String tag,
+  });
+
+  Future<void> crateMatrixReportingReportEvent({
+    required String roomId,
+    required String eventId,
+    String? reason,
+    int? score,
+  });
+
+  Future<void> crateMatrixReportingReportRoom({
+    required String roomId,
+    String? reason,
+  });
+
+  Future<void> crateMatrixReportingReportUser({
+    required String userId,
+    required String reason,
+  });
+
+  Future<void> crateMatrixTimelinesReturnToLiveTimeline({
+    required String roomId,
+  });
+
+  Future<void> crateMatrixRoomListRoomListLoadNextPage({
+    required BigInt subscriptionId,
+  });
+
+  Future<void> crateMatrixRoomListRoomListResetToOnePage({
+    required BigInt subscriptionId,
+  });
+
+  Future<PublicRoomsPage> crateMatrixDirectorySearchPublicRooms({
+    String? searchTerm,
+    String? server,
+    int? limit,
+    String? since,
+  });
+
+  Future<RoomMembersPage> crateMatrixMembersSearchRoomMembers({
+    required String roomId,
+    required String query,
+    required int limit,
+  });
+
   Future<UserSearchResult> crateMatrixUserSerachSearchUsers({
     required String query,
   });
@@ -180,16 +426,186 @@ abstract class RustLibApi extends BaseApi {
     required String content,
   });
 
+  Future<void> crateMatrixReceiptsSendReadReceipt({
+    required String roomId,
+    required String eventId,
+    required ReceiptKind kind,
+  });
+
+  Future<void> crateMatrixModerationSetActionPowerLevel({
+    required String roomId,
+    required PowerLevelAction action,
+    required PlatformInt64 powerLevel,
+  });
+
+  Future<String> crateMatrixProfileSetAvatar({
+    required Uint8List data,
+    required String mimeType,
+  });
+
+  Future<void> crateMatrixDirectorySetCanonicalAlias({
+    required String roomId,
+    String? alias,
+    required List<String> altAliases,
+  });
+
+  Future<void> crateMatrixProfileSetDisplayName({String? displayName});
+
+  Future<void> crateMatrixPresenceSetOwnPresence({
+    required PresenceKind presence,
+    String? statusMessage,
+  });
+
+  Future<void> crateMatrixReceiptsSetReadMarkers({
+    required String roomId,
+    String? fullyReadEventId,
+    String? readReceiptEventId,
+    required bool privateReadReceipt,
+  });
+
+  Future<void> crateMatrixRoomSettingsSetRoomAvatar({
+    required String roomId,
+    required Uint8List data,
+    required String mimeType,
+  });
+
+  Future<void> crateMatrixTagsSetRoomFavourite({
+    required String roomId,
+    required bool isFavourite,
+    double? order,
+  });
+
+  Future<void> crateMatrixRoomSettingsSetRoomHistoryVisibility({
+    required String roomId,
+    required RoomHistoryVisibility historyVisibility,
+  });
+
+  Future<void> crateMatrixRoomSettingsSetRoomJoinRule({
+    required String roomId,
+    required RoomJoinRule joinRule,
+  });
+
+  Future<void> crateMatrixRoomListSetRoomListFilter({
+    required BigInt subscriptionId,
+    required RoomListFilterKind filter,
+  });
+
+  Future<void> crateMatrixTagsSetRoomLowPriority({
+    required String roomId,
+    required bool isLowPriority,
+    double? order,
+  });
+
+  Future<void> crateMatrixReceiptsSetRoomMarkedUnread({
+    required String roomId,
+    required bool unread,
+  });
+
+  Future<void> crateMatrixRoomSettingsSetRoomName({
+    required String roomId,
+    required String name,
+  });
+
+  Future<void> crateMatrixDirectorySetRoomPublished({
+    required String roomId,
+    required bool published,
+  });
+
+  Future<void> crateMatrixTagsSetRoomTag({
+    required String roomId,
+    required String tag,
+    double? order,
+  });
+
+  Future<void> crateMatrixRoomSettingsSetRoomTopic({
+    required String roomId,
+    required String topic,
+  });
+
+  Future<void> crateMatrixTypingSetTyping({
+    required String roomId,
+    required bool typing,
+  });
+
+  Future<void> crateMatrixModerationSetUserPowerLevel({
+    required String roomId,
+    required String userId,
+    required PlatformInt64 powerLevel,
+  });
+
   Future<bool> crateMatrixSyncServiceStartSyncService();
 
-  Stream<RoomUpdate> crateMatrixRoomsSubscribeToAllRoomUpdates();
+  Stream<List<RoomListUpdate>> crateMatrixRoomsSubscribeToAllRoomUpdates();
+
+  Stream<List<MessageUpdate>>
+  crateMatrixTimelinesSubscribeToEventTimelineUpdates({
+    required String roomId,
+    required String eventId,
+    int? numContextEvents,
+    BigInt? throttleMs,
+  });
+
+  Stream<List<KnockRequestInfo>> crateMatrixKnockingSubscribeToKnockRequests({
+    required String roomId,
+  });
+
+  Stream<PaginationStatus> crateMatrixTimelinesSubscribeToPaginationStatus({
+    required String roomId,
+  });
+
+  Stream<UserPresence> crateMatrixPresenceSubscribeToPresence({
+    required List<String> userIds,
+    required BigInt subscriptionId,
+  });
+
+  Stream<List<ReadReceipt>> crateMatrixReceiptsSubscribeToReadReceipts({
+    required String roomId,
+  });
+
+  Stream<List<RoomListUpdate>> crateMatrixRoomListSubscribeToRoomList({
+    required BigInt subscriptionId,
+    required RoomListFilterKind filter,
+    int? pageSize,
+  });
+
+  Stream<List<RoomMembersUpdate>> crateMatrixMembersSubscribeToRoomMembers({
+    required String roomId,
+  });
+
+  Stream<List<MessageUpdate>> crateMatrixTimelinesSubscribeToTimelineUpdates({
+    required String roomId,
+    required BigInt subscriptionId,
+    BigInt? throttleMs,
+  });
 
-  Stream<MessageUpdate> crateMatrixTimelinesSubscribeToTimelineUpdates({
+  Stream<List<User>> crateMatrixTypingSubscribeToTypingUsers({
     required String roomId,
   });
 
   Future<String> crateApiInitTestServerConnectivity();
 
+  Future<void> crateMatrixModerationUnbanUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  });
+
+  Future<void> crateMatrixIgnoredUsersUnignoreUser({required String userId});
+
+  Future<void> crateMatrixPresenceUnsubscribeFromPresence({
+    required BigInt subscriptionId,
+  });
+
+  Future<void> crateMatrixTimelinesUnsubscribeFromTimelineUpdates({
+    required String roomId,
+    required BigInt subscriptionId,
+  });
+
+  Future<String> crateMatrixRoomSettingsUpgradeRoom({
+    required String roomId,
+    required String newVersion,
+  });
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_Certificate;
 
@@ -216,6 +632,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<CreateRoomParams> crateMatrixRoomsCreateRoomParamsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_create_room_params,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixRoomsCreateRoomParamsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsCreateRoomParamsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "CreateRoomParams_default", argNames: []);
+
   @override
   Future<FieldsFormatterForFiles>
   crateApiPlatformFieldsFormatterForFilesDefault() {
@@ -226,7 +669,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 1,
+            funcId: 2,
             port: port_,
           );
         },
@@ -249,27 +692,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiLoggerLog({
-    required String level,
-    required String message,
-    required String tag,
-  }) {
+  Future<PowerLevelOverrides> crateMatrixRoomsPowerLevelOverridesDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(level, serializer);
-          sse_encode_String(message, serializer);
-          sse_encode_String(tag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 3,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_power_level_overrides,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixRoomsPowerLevelOverridesDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsPowerLevelOverridesDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "PowerLevelOverrides_default",
+        argNames: [],
+      );
+
+  @override
+  Future<RoomVisibility> crateMatrixRoomsRoomVisibilityDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_room_visibility,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixRoomsRoomVisibilityDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsRoomVisibilityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "RoomVisibility_default", argNames: []);
+
+  @override
+  Future<void> crateApiLoggerLog({
+    required String level,
+    required String message,
+    required String tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          sse_encode_String(message, serializer);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
         constMeta: kCrateApiLoggerLogConstMeta,
@@ -284,6 +784,178 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["level", "message", "tag"],
   );
 
+  @override
+  Future<String> crateMatrixInvitesAcceptInvite({required String roomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixInvitesAcceptInviteConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixInvitesAcceptInviteConstMeta =>
+      const TaskConstMeta(debugName: "accept_invite", argNames: ["roomId"]);
+
+  @override
+  Future<void> crateMatrixKnockingAcceptKnockRequest({
+    required String roomId,
+    required String userId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixKnockingAcceptKnockRequestConstMeta,
+        argValues: [roomId, userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixKnockingAcceptKnockRequestConstMeta =>
+      const TaskConstMeta(
+        debugName: "accept_knock_request",
+        argNames: ["roomId", "userId"],
+      );
+
+  @override
+  Future<bool> crateMatrixDirectoryAddRoomAlias({
+    required String roomId,
+    required String alias,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectoryAddRoomAliasConstMeta,
+        argValues: [roomId, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectoryAddRoomAliasConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_room_alias",
+        argNames: ["roomId", "alias"],
+      );
+
+  @override
+  Future<void> crateMatrixSpacesAddRoomToSpace({
+    required String spaceId,
+    required String roomId,
+    required bool suggested,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(spaceId, serializer);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(suggested, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixSpacesAddRoomToSpaceConstMeta,
+        argValues: [spaceId, roomId, suggested],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixSpacesAddRoomToSpaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_room_to_space",
+        argNames: ["spaceId", "roomId", "suggested"],
+      );
+
+  @override
+  Future<void> crateMatrixModerationBanUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixModerationBanUserConstMeta,
+        argValues: [roomId, userId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixModerationBanUserConstMeta =>
+      const TaskConstMeta(
+        debugName: "ban_user",
+        argNames: ["roomId", "userId", "reason"],
+      );
+
   @override
   Future<String> crateApiInitCheckMatrixSdkStatus() {
     return handler.executeNormal(
@@ -293,7 +965,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 11,
             port: port_,
           );
         },
@@ -323,7 +995,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 12,
             port: port_,
           );
         },
@@ -351,7 +1023,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 13,
             port: port_,
           );
         },
@@ -386,7 +1058,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 14,
             port: port_,
           );
         },
@@ -419,7 +1091,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 7,
+              funcId: 15,
               port: port_,
             );
           },
@@ -440,126 +1112,143 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "create_log_stream", argNames: ["s"]);
 
   @override
-  Future<List<RoomUpdate>> crateMatrixRoomsGetAllRooms() {
+  Future<String> crateMatrixRoomsCreateRoom({
+    required CreateRoomParams params,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_create_room_params(params, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_room_update,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixRoomsGetAllRoomsConstMeta,
-        argValues: [],
+        constMeta: kCrateMatrixRoomsCreateRoomConstMeta,
+        argValues: [params],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixRoomsGetAllRoomsConstMeta =>
-      const TaskConstMeta(debugName: "get_all_rooms", argNames: []);
+  TaskConstMeta get kCrateMatrixRoomsCreateRoomConstMeta =>
+      const TaskConstMeta(debugName: "create_room", argNames: ["params"]);
 
   @override
-  Future<String> crateApiInitGetMatrixConfig() {
+  Future<String> crateMatrixSpacesCreateSpace({
+    required CreateRoomParams params,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_create_room_params(params, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 17,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiInitGetMatrixConfigConstMeta,
-        argValues: [],
+        constMeta: kCrateMatrixSpacesCreateSpaceConstMeta,
+        argValues: [params],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetMatrixConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_matrix_config", argNames: []);
+  TaskConstMeta get kCrateMatrixSpacesCreateSpaceConstMeta =>
+      const TaskConstMeta(debugName: "create_space", argNames: ["params"]);
 
   @override
-  Future<List<Message>> crateMatrixTimelinesGetOlderMessages({
+  Future<String> crateMatrixInvitesDeclineInvite({
     required String roomId,
-    required int count,
+    String? reason,
+    required bool ignoreInviter,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(roomId, serializer);
-          sse_encode_u_16(count, serializer);
+          sse_encode_opt_String(reason, serializer);
+          sse_encode_bool(ignoreInviter, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_message,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixTimelinesGetOlderMessagesConstMeta,
-        argValues: [roomId, count],
+        constMeta: kCrateMatrixInvitesDeclineInviteConstMeta,
+        argValues: [roomId, reason, ignoreInviter],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixTimelinesGetOlderMessagesConstMeta =>
+  TaskConstMeta get kCrateMatrixInvitesDeclineInviteConstMeta =>
       const TaskConstMeta(
-        debugName: "get_older_messages",
-        argNames: ["roomId", "count"],
+        debugName: "decline_invite",
+        argNames: ["roomId", "reason", "ignoreInviter"],
       );
 
   @override
-  Future<String> crateApiInitGetSyncOperationsStatus() {
+  Future<void> crateMatrixKnockingDeclineKnockRequest({
+    required String roomId,
+    required String userId,
+    String? reason,
+    required bool ban,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          sse_encode_bool(ban, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiInitGetSyncOperationsStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateMatrixKnockingDeclineKnockRequestConstMeta,
+        argValues: [roomId, userId, reason, ban],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetSyncOperationsStatusConstMeta =>
+  TaskConstMeta get kCrateMatrixKnockingDeclineKnockRequestConstMeta =>
       const TaskConstMeta(
-        debugName: "get_sync_operations_status",
-        argNames: [],
+        debugName: "decline_knock_request",
+        argNames: ["roomId", "userId", "reason", "ban"],
       );
 
   @override
-  Future<List<Message>> crateMatrixTimelinesGetTimelineItemsByRoomId({
+  Future<void> crateMatrixRoomSettingsEnableRoomEncryption({
     required String roomId,
   }) {
     return handler.executeNormal(
@@ -570,29 +1259,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_message,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixTimelinesGetTimelineItemsByRoomIdConstMeta,
+        constMeta: kCrateMatrixRoomSettingsEnableRoomEncryptionConstMeta,
         argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixTimelinesGetTimelineItemsByRoomIdConstMeta =>
+  TaskConstMeta get kCrateMatrixRoomSettingsEnableRoomEncryptionConstMeta =>
       const TaskConstMeta(
-        debugName: "get_timeline_items_by_room_id",
+        debugName: "enable_room_encryption",
         argNames: ["roomId"],
       );
 
   @override
-  Future<void> crateApiInitInitApp() {
+  Future<List<RoomUpdate>> crateMatrixRoomsGetAllRooms() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -600,93 +1289,86 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 21,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_room_update,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitInitAppConstMeta,
+        constMeta: kCrateMatrixRoomsGetAllRoomsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateMatrixRoomsGetAllRoomsConstMeta =>
+      const TaskConstMeta(debugName: "get_all_rooms", argNames: []);
 
   @override
-  Stream<LogEntry> crateApiLoggerInitLogger() {
-    final logStream = RustStreamSink<LogEntry>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_log_entry_Sse(logStream, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 14,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiLoggerInitLoggerConstMeta,
-          argValues: [logStream],
-          apiImpl: this,
+  Future<List<String>> crateMatrixIgnoredUsersGetIgnoredUsers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
         ),
+        constMeta: kCrateMatrixIgnoredUsersGetIgnoredUsersConstMeta,
+        argValues: [],
+        apiImpl: this,
       ),
     );
-    return logStream.stream;
   }
 
-  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta =>
-      const TaskConstMeta(debugName: "init_logger", argNames: ["logStream"]);
+  TaskConstMeta get kCrateMatrixIgnoredUsersGetIgnoredUsersConstMeta =>
+      const TaskConstMeta(debugName: "get_ignored_users", argNames: []);
 
   @override
-  Future<void> crateApiPlatformInitPlatform({
-    required TracingConfiguration config,
-    required bool useLightweightTokioRuntime,
+  Future<InviteDetails> crateMatrixInvitesGetInviteDetails({
+    required String roomId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_tracing_configuration(config, serializer);
-          sse_encode_bool(useLightweightTokioRuntime, serializer);
+          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_client_error,
+          decodeSuccessData: sse_decode_invite_details,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiPlatformInitPlatformConstMeta,
-        argValues: [config, useLightweightTokioRuntime],
+        constMeta: kCrateMatrixInvitesGetInviteDetailsConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPlatformInitPlatformConstMeta =>
+  TaskConstMeta get kCrateMatrixInvitesGetInviteDetailsConstMeta =>
       const TaskConstMeta(
-        debugName: "init_platform",
-        argNames: ["config", "useLightweightTokioRuntime"],
+        debugName: "get_invite_details",
+        argNames: ["roomId"],
       );
 
   @override
-  Future<bool> crateMatrixAuthenticationIsClientAuthenticated() {
+  Future<List<InviteDetails>> crateMatrixInvitesGetInvites() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -694,470 +1376,601 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_invite_details,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixAuthenticationIsClientAuthenticatedConstMeta,
+        constMeta: kCrateMatrixInvitesGetInvitesConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixAuthenticationIsClientAuthenticatedConstMeta =>
-      const TaskConstMeta(debugName: "is_client_authenticated", argNames: []);
+  TaskConstMeta get kCrateMatrixInvitesGetInvitesConstMeta =>
+      const TaskConstMeta(debugName: "get_invites", argNames: []);
 
   @override
-  Future<String> crateMatrixRoomsJoinRoom({required String roomId}) {
+  Future<List<RoomUpdate>> crateMatrixSpacesGetJoinedSpaces() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_room_update,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateMatrixRoomsJoinRoomConstMeta,
-        argValues: [roomId],
+        constMeta: kCrateMatrixSpacesGetJoinedSpacesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixRoomsJoinRoomConstMeta =>
-      const TaskConstMeta(debugName: "join_room", argNames: ["roomId"]);
+  TaskConstMeta get kCrateMatrixSpacesGetJoinedSpacesConstMeta =>
+      const TaskConstMeta(debugName: "get_joined_spaces", argNames: []);
 
   @override
-  Future<String> crateMatrixRoomsLeaveRoom({required String roomId}) {
+  Future<String> crateApiInitGetMatrixConfig() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateMatrixRoomsLeaveRoomConstMeta,
-        argValues: [roomId],
+        constMeta: kCrateApiInitGetMatrixConfigConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixRoomsLeaveRoomConstMeta =>
-      const TaskConstMeta(debugName: "leave_room", argNames: ["roomId"]);
+  TaskConstMeta get kCrateApiInitGetMatrixConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_matrix_config", argNames: []);
 
   @override
-  Future<void> crateApiLoggerLogDebug({required String message}) {
+  Future<bool> crateMatrixTimelinesGetOlderMessages({
+    required String roomId,
+    required int count,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(roomId, serializer);
+          sse_encode_u_16(count, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLoggerLogDebugConstMeta,
-        argValues: [message],
+        constMeta: kCrateMatrixTimelinesGetOlderMessagesConstMeta,
+        argValues: [roomId, count],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerLogDebugConstMeta =>
-      const TaskConstMeta(debugName: "log_debug", argNames: ["message"]);
+  TaskConstMeta get kCrateMatrixTimelinesGetOlderMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_older_messages",
+        argNames: ["roomId", "count"],
+      );
 
   @override
-  Future<void> crateApiLoggerLogError({required String message}) {
+  Future<String> crateMatrixDirectMessagesGetOrCreateDm({
+    required String userId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(userId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLoggerLogErrorConstMeta,
-        argValues: [message],
+        constMeta: kCrateMatrixDirectMessagesGetOrCreateDmConstMeta,
+        argValues: [userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerLogErrorConstMeta =>
-      const TaskConstMeta(debugName: "log_error", argNames: ["message"]);
+  TaskConstMeta get kCrateMatrixDirectMessagesGetOrCreateDmConstMeta =>
+      const TaskConstMeta(debugName: "get_or_create_dm", argNames: ["userId"]);
 
   @override
-  Future<void> crateApiTracingLogEvent({
-    required String file,
-    int? line,
-    required LogLevel level,
-    required String target,
-    required String message,
-  }) {
+  Future<User> crateMatrixProfileGetOwnProfile() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(file, serializer);
-          sse_encode_opt_box_autoadd_u_32(line, serializer);
-          sse_encode_log_level(level, serializer);
-          sse_encode_String(target, serializer);
-          sse_encode_String(message, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_user,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiTracingLogEventConstMeta,
-        argValues: [file, line, level, target, message],
+        constMeta: kCrateMatrixProfileGetOwnProfileConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTracingLogEventConstMeta => const TaskConstMeta(
-    debugName: "log_event",
-    argNames: ["file", "line", "level", "target", "message"],
-  );
+  TaskConstMeta get kCrateMatrixProfileGetOwnProfileConstMeta =>
+      const TaskConstMeta(debugName: "get_own_profile", argNames: []);
 
   @override
-  Future<void> crateApiLoggerLogInfo({required String message}) {
+  Future<ReadReceipt?> crateMatrixReceiptsGetOwnReadReceipt({
+    required String roomId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 30,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_opt_box_autoadd_read_receipt,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLoggerLogInfoConstMeta,
-        argValues: [message],
+        constMeta: kCrateMatrixReceiptsGetOwnReadReceiptConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerLogInfoConstMeta =>
-      const TaskConstMeta(debugName: "log_info", argNames: ["message"]);
+  TaskConstMeta get kCrateMatrixReceiptsGetOwnReadReceiptConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_own_read_receipt",
+        argNames: ["roomId"],
+      );
 
   @override
-  Future<void> crateApiLoggerLogWarn({required String message}) {
+  Future<RoomAliases> crateMatrixDirectoryGetRoomAliases({
+    required String roomId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 31,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_room_aliases,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiLoggerLogWarnConstMeta,
-        argValues: [message],
+        constMeta: kCrateMatrixDirectoryGetRoomAliasesConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerLogWarnConstMeta =>
-      const TaskConstMeta(debugName: "log_warn", argNames: ["message"]);
+  TaskConstMeta get kCrateMatrixDirectoryGetRoomAliasesConstMeta =>
+      const TaskConstMeta(debugName: "get_room_aliases", argNames: ["roomId"]);
 
   @override
-  Future<bool> crateMatrixAuthenticationLogin({
-    required String username,
-    required String password,
+  Future<RoomMembersPage> crateMatrixMembersGetRoomMembers({
+    required String roomId,
+    required int offset,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_String(password, serializer);
+          sse_encode_String(roomId, serializer);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 32,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_room_members_page,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixAuthenticationLoginConstMeta,
-        argValues: [username, password],
+        constMeta: kCrateMatrixMembersGetRoomMembersConstMeta,
+        argValues: [roomId, offset, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixAuthenticationLoginConstMeta =>
+  TaskConstMeta get kCrateMatrixMembersGetRoomMembersConstMeta =>
       const TaskConstMeta(
-        debugName: "login",
-        argNames: ["username", "password"],
+        debugName: "get_room_members",
+        argNames: ["roomId", "offset", "limit"],
       );
 
   @override
-  Future<bool> crateMatrixAuthenticationLogout() {
+  Future<RoomPermissions> crateMatrixModerationGetRoomPermissions({
+    required String roomId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 33,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_room_permissions,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixAuthenticationLogoutConstMeta,
-        argValues: [],
+        constMeta: kCrateMatrixModerationGetRoomPermissionsConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixAuthenticationLogoutConstMeta =>
-      const TaskConstMeta(debugName: "logout", argNames: []);
+  TaskConstMeta get kCrateMatrixModerationGetRoomPermissionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_room_permissions",
+        argNames: ["roomId"],
+      );
 
   @override
-  Future<bool> crateMatrixAuthenticationRegister({
-    required String username,
-    required String password,
+  Future<RoomPowerLevelsInfo> crateMatrixModerationGetRoomPowerLevels({
+    required String roomId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_String(password, serializer);
+          sse_encode_String(roomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 34,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_room_power_levels_info,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixAuthenticationRegisterConstMeta,
-        argValues: [username, password],
+        constMeta: kCrateMatrixModerationGetRoomPowerLevelsConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixAuthenticationRegisterConstMeta =>
+  TaskConstMeta get kCrateMatrixModerationGetRoomPowerLevelsConstMeta =>
       const TaskConstMeta(
-        debugName: "register",
-        argNames: ["username", "password"],
+        debugName: "get_room_power_levels",
+        argNames: ["roomId"],
       );
 
   @override
-  Future<void> crateApiPlatformReloadTracingFileWriter({
-    required TracingFileConfiguration configuration,
+  Future<RoomPreviewInfo> crateMatrixJoiningGetRoomPreview({
+    required String target,
+    required List<String> viaServers,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_tracing_file_configuration(
-            configuration,
-            serializer,
-          );
+          sse_encode_String(target, serializer);
+          sse_encode_list_String(viaServers, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_client_error,
+          decodeSuccessData: sse_decode_room_preview_info,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiPlatformReloadTracingFileWriterConstMeta,
-        argValues: [configuration],
+        constMeta: kCrateMatrixJoiningGetRoomPreviewConstMeta,
+        argValues: [target, viaServers],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPlatformReloadTracingFileWriterConstMeta =>
+  TaskConstMeta get kCrateMatrixJoiningGetRoomPreviewConstMeta =>
       const TaskConstMeta(
-        debugName: "reload_tracing_file_writer",
-        argNames: ["configuration"],
+        debugName: "get_room_preview",
+        argNames: ["target", "viaServers"],
       );
 
   @override
-  Future<UserSearchResult> crateMatrixUserSerachSearchUsers({
-    required String query,
+  Future<SpaceHierarchyPage> crateMatrixSpacesGetSpaceHierarchy({
+    required String spaceId,
+    String? from,
+    int? limit,
+    int? maxDepth,
+    required bool suggestedOnly,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
+          sse_encode_String(spaceId, serializer);
+          sse_encode_opt_String(from, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxDepth, serializer);
+          sse_encode_bool(suggestedOnly, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_user_search_result,
+          decodeSuccessData: sse_decode_space_hierarchy_page,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixUserSerachSearchUsersConstMeta,
-        argValues: [query],
+        constMeta: kCrateMatrixSpacesGetSpaceHierarchyConstMeta,
+        argValues: [spaceId, from, limit, maxDepth, suggestedOnly],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixUserSerachSearchUsersConstMeta =>
-      const TaskConstMeta(debugName: "search_users", argNames: ["query"]);
+  TaskConstMeta get kCrateMatrixSpacesGetSpaceHierarchyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_space_hierarchy",
+        argNames: ["spaceId", "from", "limit", "maxDepth", "suggestedOnly"],
+      );
 
   @override
-  Future<String> crateMatrixRoomsSendMessage({
+  Future<String> crateApiInitGetSyncOperationsStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitGetSyncOperationsStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitGetSyncOperationsStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sync_operations_status",
+        argNames: [],
+      );
+
+  @override
+  Future<List<Message>> crateMatrixTimelinesGetTimelineItemsByRoomId({
     required String roomId,
-    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(roomId, serializer);
-          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_message,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateMatrixRoomsSendMessageConstMeta,
-        argValues: [roomId, content],
+        constMeta: kCrateMatrixTimelinesGetTimelineItemsByRoomIdConstMeta,
+        argValues: [roomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixRoomsSendMessageConstMeta =>
+  TaskConstMeta get kCrateMatrixTimelinesGetTimelineItemsByRoomIdConstMeta =>
       const TaskConstMeta(
-        debugName: "send_message",
-        argNames: ["roomId", "content"],
+        debugName: "get_timeline_items_by_room_id",
+        argNames: ["roomId"],
       );
 
   @override
-  Future<bool> crateMatrixSyncServiceStartSyncService() {
+  Future<UserPresence> crateMatrixPresenceGetUserPresence({
+    required String userId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(userId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_user_presence,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateMatrixSyncServiceStartSyncServiceConstMeta,
+        constMeta: kCrateMatrixPresenceGetUserPresenceConstMeta,
+        argValues: [userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixPresenceGetUserPresenceConstMeta =>
+      const TaskConstMeta(debugName: "get_user_presence", argNames: ["userId"]);
+
+  @override
+  Future<User> crateMatrixProfileGetUserProfile({required String userId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixProfileGetUserProfileConstMeta,
+        argValues: [userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixProfileGetUserProfileConstMeta =>
+      const TaskConstMeta(debugName: "get_user_profile", argNames: ["userId"]);
+
+  @override
+  Future<void> crateMatrixIgnoredUsersIgnoreUser({required String userId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixIgnoredUsersIgnoreUserConstMeta,
+        argValues: [userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixIgnoredUsersIgnoreUserConstMeta =>
+      const TaskConstMeta(debugName: "ignore_user", argNames: ["userId"]);
+
+  @override
+  Future<void> crateApiInitInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitInitAppConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateMatrixSyncServiceStartSyncServiceConstMeta =>
-      const TaskConstMeta(debugName: "start_sync_service", argNames: []);
+  TaskConstMeta get kCrateApiInitInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
 
   @override
-  Stream<RoomUpdate> crateMatrixRoomsSubscribeToAllRoomUpdates() {
-    final stream = RustStreamSink<RoomUpdate>();
+  Stream<LogEntry> crateApiLoggerInitLogger() {
+    final logStream = RustStreamSink<LogEntry>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_room_update_Sse(stream, serializer);
+            sse_encode_StreamSink_log_entry_Sse(logStream, serializer);
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 31,
+              funcId: 43,
               port: port_,
             );
           },
@@ -1165,61 +1978,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             decodeSuccessData: sse_decode_unit,
             decodeErrorData: null,
           ),
-          constMeta: kCrateMatrixRoomsSubscribeToAllRoomUpdatesConstMeta,
-          argValues: [stream],
+          constMeta: kCrateApiLoggerInitLoggerConstMeta,
+          argValues: [logStream],
           apiImpl: this,
         ),
       ),
     );
-    return stream.stream;
+    return logStream.stream;
   }
 
-  TaskConstMeta get kCrateMatrixRoomsSubscribeToAllRoomUpdatesConstMeta =>
+  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta =>
+      const TaskConstMeta(debugName: "init_logger", argNames: ["logStream"]);
+
+  @override
+  Future<void> crateApiPlatformInitPlatform({
+    required TracingConfiguration config,
+    required bool useLightweightTokioRuntime,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_tracing_configuration(config, serializer);
+          sse_encode_bool(useLightweightTokioRuntime, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta: kCrateApiPlatformInitPlatformConstMeta,
+        argValues: [config, useLightweightTokioRuntime],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlatformInitPlatformConstMeta =>
       const TaskConstMeta(
-        debugName: "subscribe_to_all_room_updates",
-        argNames: ["stream"],
+        debugName: "init_platform",
+        argNames: ["config", "useLightweightTokioRuntime"],
       );
 
   @override
-  Stream<MessageUpdate> crateMatrixTimelinesSubscribeToTimelineUpdates({
+  Future<void> crateMatrixModerationInviteUser({
     required String roomId,
+    required String userId,
   }) {
-    final stream = RustStreamSink<MessageUpdate>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_message_update_Sse(stream, serializer);
-            sse_encode_String(roomId, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 32,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateMatrixTimelinesSubscribeToTimelineUpdatesConstMeta,
-          argValues: [stream, roomId],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
+        constMeta: kCrateMatrixModerationInviteUserConstMeta,
+        argValues: [roomId, userId],
+        apiImpl: this,
       ),
     );
-    return stream.stream;
   }
 
-  TaskConstMeta get kCrateMatrixTimelinesSubscribeToTimelineUpdatesConstMeta =>
+  TaskConstMeta get kCrateMatrixModerationInviteUserConstMeta =>
       const TaskConstMeta(
-        debugName: "subscribe_to_timeline_updates",
-        argNames: ["stream", "roomId"],
+        debugName: "invite_user",
+        argNames: ["roomId", "userId"],
       );
 
   @override
-  Future<String> crateApiInitTestServerConnectivity() {
+  Future<bool> crateMatrixAuthenticationIsClientAuthenticated() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1227,1439 +2069,6844 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiInitTestServerConnectivityConstMeta,
+        constMeta: kCrateMatrixAuthenticationIsClientAuthenticatedConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitTestServerConnectivityConstMeta =>
-      const TaskConstMeta(debugName: "test_server_connectivity", argNames: []);
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Certificate =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Certificate =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_FieldsFormatterForFiles =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_FieldsFormatterForFiles =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles;
+  TaskConstMeta get kCrateMatrixAuthenticationIsClientAuthenticatedConstMeta =>
+      const TaskConstMeta(debugName: "is_client_authenticated", argNames: []);
+
+  @override
+  Future<bool> crateMatrixDirectoryIsRoomPublished({required String roomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectoryIsRoomPublishedConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectoryIsRoomPublishedConstMeta =>
+      const TaskConstMeta(debugName: "is_room_published", argNames: ["roomId"]);
+
+  @override
+  Future<String> crateMatrixRoomsJoinRoom({required String roomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomsJoinRoomConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsJoinRoomConstMeta =>
+      const TaskConstMeta(debugName: "join_room", argNames: ["roomId"]);
+
+  @override
+  Future<String> crateMatrixJoiningJoinRoomByIdOrAlias({
+    required String target,
+    required List<String> viaServers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(target, serializer);
+          sse_encode_list_String(viaServers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixJoiningJoinRoomByIdOrAliasConstMeta,
+        argValues: [target, viaServers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixJoiningJoinRoomByIdOrAliasConstMeta =>
+      const TaskConstMeta(
+        debugName: "join_room_by_id_or_alias",
+        argNames: ["target", "viaServers"],
+      );
+
+  @override
+  Future<void> crateMatrixModerationKickUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixModerationKickUserConstMeta,
+        argValues: [roomId, userId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixModerationKickUserConstMeta =>
+      const TaskConstMeta(
+        debugName: "kick_user",
+        argNames: ["roomId", "userId", "reason"],
+      );
+
+  @override
+  Future<String> crateMatrixKnockingKnockRoom({
+    required String target,
+    String? reason,
+    required List<String> viaServers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(target, serializer);
+          sse_encode_opt_String(reason, serializer);
+          sse_encode_list_String(viaServers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixKnockingKnockRoomConstMeta,
+        argValues: [target, reason, viaServers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixKnockingKnockRoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "knock_room",
+        argNames: ["target", "reason", "viaServers"],
+      );
+
+  @override
+  Future<String> crateMatrixRoomsLeaveRoom({required String roomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomsLeaveRoomConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsLeaveRoomConstMeta =>
+      const TaskConstMeta(debugName: "leave_room", argNames: ["roomId"]);
+
+  @override
+  Future<void> crateApiLoggerLogDebug({required String message}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerLogDebugConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerLogDebugConstMeta =>
+      const TaskConstMeta(debugName: "log_debug", argNames: ["message"]);
+
+  @override
+  Future<void> crateApiLoggerLogError({required String message}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerLogErrorConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerLogErrorConstMeta =>
+      const TaskConstMeta(debugName: "log_error", argNames: ["message"]);
+
+  @override
+  Future<void> crateApiTracingLogEvent({
+    required String file,
+    int? line,
+    required LogLevel level,
+    required String target,
+    required String message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(file, serializer);
+          sse_encode_opt_box_autoadd_u_32(line, serializer);
+          sse_encode_log_level(level, serializer);
+          sse_encode_String(target, serializer);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTracingLogEventConstMeta,
+        argValues: [file, line, level, target, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTracingLogEventConstMeta => const TaskConstMeta(
+    debugName: "log_event",
+    argNames: ["file", "line", "level", "target", "message"],
+  );
+
+  @override
+  Future<void> crateApiLoggerLogInfo({required String message}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerLogInfoConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerLogInfoConstMeta =>
+      const TaskConstMeta(debugName: "log_info", argNames: ["message"]);
+
+  @override
+  Future<void> crateApiLoggerLogWarn({required String message}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerLogWarnConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerLogWarnConstMeta =>
+      const TaskConstMeta(debugName: "log_warn", argNames: ["message"]);
+
+  @override
+  Future<bool> crateMatrixAuthenticationLogin({
+    required String username,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixAuthenticationLoginConstMeta,
+        argValues: [username, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixAuthenticationLoginConstMeta =>
+      const TaskConstMeta(
+        debugName: "login",
+        argNames: ["username", "password"],
+      );
+
+  @override
+  Future<bool> crateMatrixAuthenticationLogout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixAuthenticationLogoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixAuthenticationLogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: []);
+
+  @override
+  Future<void> crateMatrixKnockingMarkKnockRequestsAsSeen({
+    required String roomId,
+    required List<String> userIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_list_String(userIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixKnockingMarkKnockRequestsAsSeenConstMeta,
+        argValues: [roomId, userIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixKnockingMarkKnockRequestsAsSeenConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_knock_requests_as_seen",
+        argNames: ["roomId", "userIds"],
+      );
+
+  @override
+  Future<void> crateMatrixReceiptsMarkRoomAsRead({
+    required String roomId,
+    required ReceiptKind kind,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_receipt_kind(kind, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReceiptsMarkRoomAsReadConstMeta,
+        argValues: [roomId, kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReceiptsMarkRoomAsReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_room_as_read",
+        argNames: ["roomId", "kind"],
+      );
+
+  @override
+  Future<BigInt> crateMatrixPresenceNewPresenceSubscriptionId() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixPresenceNewPresenceSubscriptionIdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixPresenceNewPresenceSubscriptionIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_presence_subscription_id",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateMatrixRoomListNewRoomListSubscriptionId() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixRoomListNewRoomListSubscriptionIdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomListNewRoomListSubscriptionIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_room_list_subscription_id",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateMatrixTimelinesNewTimelineSubscriptionId() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixTimelinesNewTimelineSubscriptionIdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesNewTimelineSubscriptionIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_timeline_subscription_id",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateMatrixTimelinesPaginateEventTimelineBackwards({
+    required String roomId,
+    required int count,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_u_16(count, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTimelinesPaginateEventTimelineBackwardsConstMeta,
+        argValues: [roomId, count],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesPaginateEventTimelineBackwardsConstMeta =>
+      const TaskConstMeta(
+        debugName: "paginate_event_timeline_backwards",
+        argNames: ["roomId", "count"],
+      );
+
+  @override
+  Future<bool> crateMatrixTimelinesPaginateEventTimelineForwards({
+    required String roomId,
+    required int count,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_u_16(count, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTimelinesPaginateEventTimelineForwardsConstMeta,
+        argValues: [roomId, count],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesPaginateEventTimelineForwardsConstMeta =>
+      const TaskConstMeta(
+        debugName: "paginate_event_timeline_forwards",
+        argNames: ["roomId", "count"],
+      );
+
+  @override
+  Future<void> crateMatrixTimelinesPreloadTimelines({
+    required List<String> roomIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(roomIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTimelinesPreloadTimelinesConstMeta,
+        argValues: [roomIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesPreloadTimelinesConstMeta =>
+      const TaskConstMeta(
+        debugName: "preload_timelines",
+        argNames: ["roomIds"],
+      );
+
+  @override
+  Future<bool> crateMatrixAuthenticationRegister({
+    required String username,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixAuthenticationRegisterConstMeta,
+        argValues: [username, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixAuthenticationRegisterConstMeta =>
+      const TaskConstMeta(
+        debugName: "register",
+        argNames: ["username", "password"],
+      );
+
+  @override
+  Future<void> crateApiPlatformReloadTracingFileWriter({
+    required TracingFileConfiguration configuration,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_tracing_file_configuration(
+            configuration,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        ),
+        constMeta: kCrateApiPlatformReloadTracingFileWriterConstMeta,
+        argValues: [configuration],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlatformReloadTracingFileWriterConstMeta =>
+      const TaskConstMeta(
+        debugName: "reload_tracing_file_writer",
+        argNames: ["configuration"],
+      );
+
+  @override
+  Future<void> crateMatrixProfileRemoveAvatar() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixProfileRemoveAvatarConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixProfileRemoveAvatarConstMeta =>
+      const TaskConstMeta(debugName: "remove_avatar", argNames: []);
+
+  @override
+  Future<bool> crateMatrixDirectoryRemoveRoomAlias({
+    required String roomId,
+    required String alias,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectoryRemoveRoomAliasConstMeta,
+        argValues: [roomId, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectoryRemoveRoomAliasConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_room_alias",
+        argNames: ["roomId", "alias"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomSettingsRemoveRoomAvatar({
+    required String roomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsRemoveRoomAvatarConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsRemoveRoomAvatarConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_room_avatar",
+        argNames: ["roomId"],
+      );
+
+  @override
+  Future<void> crateMatrixSpacesRemoveRoomFromSpace({
+    required String spaceId,
+    required String roomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(spaceId, serializer);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixSpacesRemoveRoomFromSpaceConstMeta,
+        argValues: [spaceId, roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixSpacesRemoveRoomFromSpaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_room_from_space",
+        argNames: ["spaceId", "roomId"],
+      );
+
+  @override
+  Future<void> crateMatrixTagsRemoveRoomTag({
+    required String roomId,
+    required String tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTagsRemoveRoomTagConstMeta,
+        argValues: [roomId, tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTagsRemoveRoomTagConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_room_tag",
+        argNames: ["roomId", "tag"],
+      );
+
+  @override
+  Future<void> crateMatrixReportingReportEvent({
+    required String roomId,
+    required String eventId,
+    String? reason,
+    int? score,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(eventId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          sse_encode_opt_box_autoadd_i_8(score, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReportingReportEventConstMeta,
+        argValues: [roomId, eventId, reason, score],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReportingReportEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_event",
+        argNames: ["roomId", "eventId", "reason", "score"],
+      );
+
+  @override
+  Future<void> crateMatrixReportingReportRoom({
+    required String roomId,
+    String? reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReportingReportRoomConstMeta,
+        argValues: [roomId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReportingReportRoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_room",
+        argNames: ["roomId", "reason"],
+      );
+
+  @override
+  Future<void> crateMatrixReportingReportUser({
+    required String userId,
+    required String reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(userId, serializer);
+          sse_encode_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReportingReportUserConstMeta,
+        argValues: [userId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReportingReportUserConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_user",
+        argNames: ["userId", "reason"],
+      );
+
+  @override
+  Future<void> crateMatrixTimelinesReturnToLiveTimeline({
+    required String roomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTimelinesReturnToLiveTimelineConstMeta,
+        argValues: [roomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesReturnToLiveTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "return_to_live_timeline",
+        argNames: ["roomId"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomListRoomListLoadNextPage({
+    required BigInt subscriptionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(subscriptionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomListRoomListLoadNextPageConstMeta,
+        argValues: [subscriptionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomListRoomListLoadNextPageConstMeta =>
+      const TaskConstMeta(
+        debugName: "room_list_load_next_page",
+        argNames: ["subscriptionId"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomListRoomListResetToOnePage({
+    required BigInt subscriptionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(subscriptionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomListRoomListResetToOnePageConstMeta,
+        argValues: [subscriptionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomListRoomListResetToOnePageConstMeta =>
+      const TaskConstMeta(
+        debugName: "room_list_reset_to_one_page",
+        argNames: ["subscriptionId"],
+      );
+
+  @override
+  Future<PublicRoomsPage> crateMatrixDirectorySearchPublicRooms({
+    String? searchTerm,
+    String? server,
+    int? limit,
+    String? since,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(searchTerm, serializer);
+          sse_encode_opt_String(server, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_String(since, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_public_rooms_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectorySearchPublicRoomsConstMeta,
+        argValues: [searchTerm, server, limit, since],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectorySearchPublicRoomsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_public_rooms",
+        argNames: ["searchTerm", "server", "limit", "since"],
+      );
+
+  @override
+  Future<RoomMembersPage> crateMatrixMembersSearchRoomMembers({
+    required String roomId,
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_room_members_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixMembersSearchRoomMembersConstMeta,
+        argValues: [roomId, query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixMembersSearchRoomMembersConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_room_members",
+        argNames: ["roomId", "query", "limit"],
+      );
+
+  @override
+  Future<UserSearchResult> crateMatrixUserSerachSearchUsers({
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user_search_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixUserSerachSearchUsersConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixUserSerachSearchUsersConstMeta =>
+      const TaskConstMeta(debugName: "search_users", argNames: ["query"]);
+
+  @override
+  Future<String> crateMatrixRoomsSendMessage({
+    required String roomId,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomsSendMessageConstMeta,
+        argValues: [roomId, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsSendMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_message",
+        argNames: ["roomId", "content"],
+      );
+
+  @override
+  Future<void> crateMatrixReceiptsSendReadReceipt({
+    required String roomId,
+    required String eventId,
+    required ReceiptKind kind,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(eventId, serializer);
+          sse_encode_receipt_kind(kind, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReceiptsSendReadReceiptConstMeta,
+        argValues: [roomId, eventId, kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReceiptsSendReadReceiptConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_read_receipt",
+        argNames: ["roomId", "eventId", "kind"],
+      );
+
+  @override
+  Future<void> crateMatrixModerationSetActionPowerLevel({
+    required String roomId,
+    required PowerLevelAction action,
+    required PlatformInt64 powerLevel,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_box_autoadd_power_level_action(action, serializer);
+          sse_encode_i_64(powerLevel, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixModerationSetActionPowerLevelConstMeta,
+        argValues: [roomId, action, powerLevel],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixModerationSetActionPowerLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_action_power_level",
+        argNames: ["roomId", "action", "powerLevel"],
+      );
+
+  @override
+  Future<String> crateMatrixProfileSetAvatar({
+    required Uint8List data,
+    required String mimeType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(data, serializer);
+          sse_encode_String(mimeType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixProfileSetAvatarConstMeta,
+        argValues: [data, mimeType],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixProfileSetAvatarConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_avatar",
+        argNames: ["data", "mimeType"],
+      );
+
+  @override
+  Future<void> crateMatrixDirectorySetCanonicalAlias({
+    required String roomId,
+    String? alias,
+    required List<String> altAliases,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_opt_String(alias, serializer);
+          sse_encode_list_String(altAliases, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectorySetCanonicalAliasConstMeta,
+        argValues: [roomId, alias, altAliases],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectorySetCanonicalAliasConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_canonical_alias",
+        argNames: ["roomId", "alias", "altAliases"],
+      );
+
+  @override
+  Future<void> crateMatrixProfileSetDisplayName({String? displayName}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(displayName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixProfileSetDisplayNameConstMeta,
+        argValues: [displayName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixProfileSetDisplayNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_display_name",
+        argNames: ["displayName"],
+      );
+
+  @override
+  Future<void> crateMatrixPresenceSetOwnPresence({
+    required PresenceKind presence,
+    String? statusMessage,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_presence_kind(presence, serializer);
+          sse_encode_opt_String(statusMessage, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixPresenceSetOwnPresenceConstMeta,
+        argValues: [presence, statusMessage],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixPresenceSetOwnPresenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_own_presence",
+        argNames: ["presence", "statusMessage"],
+      );
+
+  @override
+  Future<void> crateMatrixReceiptsSetReadMarkers({
+    required String roomId,
+    String? fullyReadEventId,
+    String? readReceiptEventId,
+    required bool privateReadReceipt,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_opt_String(fullyReadEventId, serializer);
+          sse_encode_opt_String(readReceiptEventId, serializer);
+          sse_encode_bool(privateReadReceipt, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReceiptsSetReadMarkersConstMeta,
+        argValues: [
+          roomId,
+          fullyReadEventId,
+          readReceiptEventId,
+          privateReadReceipt,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReceiptsSetReadMarkersConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_read_markers",
+        argNames: [
+          "roomId",
+          "fullyReadEventId",
+          "readReceiptEventId",
+          "privateReadReceipt",
+        ],
+      );
+
+  @override
+  Future<void> crateMatrixRoomSettingsSetRoomAvatar({
+    required String roomId,
+    required Uint8List data,
+    required String mimeType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_list_prim_u_8_strict(data, serializer);
+          sse_encode_String(mimeType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsSetRoomAvatarConstMeta,
+        argValues: [roomId, data, mimeType],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsSetRoomAvatarConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_avatar",
+        argNames: ["roomId", "data", "mimeType"],
+      );
+
+  @override
+  Future<void> crateMatrixTagsSetRoomFavourite({
+    required String roomId,
+    required bool isFavourite,
+    double? order,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(isFavourite, serializer);
+          sse_encode_opt_box_autoadd_f_64(order, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTagsSetRoomFavouriteConstMeta,
+        argValues: [roomId, isFavourite, order],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTagsSetRoomFavouriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_favourite",
+        argNames: ["roomId", "isFavourite", "order"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomSettingsSetRoomHistoryVisibility({
+    required String roomId,
+    required RoomHistoryVisibility historyVisibility,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_room_history_visibility(historyVisibility, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsSetRoomHistoryVisibilityConstMeta,
+        argValues: [roomId, historyVisibility],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsSetRoomHistoryVisibilityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_history_visibility",
+        argNames: ["roomId", "historyVisibility"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomSettingsSetRoomJoinRule({
+    required String roomId,
+    required RoomJoinRule joinRule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_box_autoadd_room_join_rule(joinRule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsSetRoomJoinRuleConstMeta,
+        argValues: [roomId, joinRule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsSetRoomJoinRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_join_rule",
+        argNames: ["roomId", "joinRule"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomListSetRoomListFilter({
+    required BigInt subscriptionId,
+    required RoomListFilterKind filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(subscriptionId, serializer);
+          sse_encode_box_autoadd_room_list_filter_kind(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomListSetRoomListFilterConstMeta,
+        argValues: [subscriptionId, filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomListSetRoomListFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_list_filter",
+        argNames: ["subscriptionId", "filter"],
+      );
+
+  @override
+  Future<void> crateMatrixTagsSetRoomLowPriority({
+    required String roomId,
+    required bool isLowPriority,
+    double? order,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(isLowPriority, serializer);
+          sse_encode_opt_box_autoadd_f_64(order, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTagsSetRoomLowPriorityConstMeta,
+        argValues: [roomId, isLowPriority, order],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTagsSetRoomLowPriorityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_low_priority",
+        argNames: ["roomId", "isLowPriority", "order"],
+      );
+
+  @override
+  Future<void> crateMatrixReceiptsSetRoomMarkedUnread({
+    required String roomId,
+    required bool unread,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(unread, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixReceiptsSetRoomMarkedUnreadConstMeta,
+        argValues: [roomId, unread],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixReceiptsSetRoomMarkedUnreadConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_marked_unread",
+        argNames: ["roomId", "unread"],
+      );
+
+  @override
+  Future<void> crateMatrixRoomSettingsSetRoomName({
+    required String roomId,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsSetRoomNameConstMeta,
+        argValues: [roomId, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsSetRoomNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_name",
+        argNames: ["roomId", "name"],
+      );
+
+  @override
+  Future<void> crateMatrixDirectorySetRoomPublished({
+    required String roomId,
+    required bool published,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(published, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixDirectorySetRoomPublishedConstMeta,
+        argValues: [roomId, published],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixDirectorySetRoomPublishedConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_published",
+        argNames: ["roomId", "published"],
+      );
+
+  @override
+  Future<void> crateMatrixTagsSetRoomTag({
+    required String roomId,
+    required String tag,
+    double? order,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(tag, serializer);
+          sse_encode_opt_box_autoadd_f_64(order, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTagsSetRoomTagConstMeta,
+        argValues: [roomId, tag, order],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTagsSetRoomTagConstMeta => const TaskConstMeta(
+    debugName: "set_room_tag",
+    argNames: ["roomId", "tag", "order"],
+  );
+
+  @override
+  Future<void> crateMatrixRoomSettingsSetRoomTopic({
+    required String roomId,
+    required String topic,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(topic, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsSetRoomTopicConstMeta,
+        argValues: [roomId, topic],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsSetRoomTopicConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_room_topic",
+        argNames: ["roomId", "topic"],
+      );
+
+  @override
+  Future<void> crateMatrixTypingSetTyping({
+    required String roomId,
+    required bool typing,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_bool(typing, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTypingSetTypingConstMeta,
+        argValues: [roomId, typing],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTypingSetTypingConstMeta => const TaskConstMeta(
+    debugName: "set_typing",
+    argNames: ["roomId", "typing"],
+  );
+
+  @override
+  Future<void> crateMatrixModerationSetUserPowerLevel({
+    required String roomId,
+    required String userId,
+    required PlatformInt64 powerLevel,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          sse_encode_i_64(powerLevel, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixModerationSetUserPowerLevelConstMeta,
+        argValues: [roomId, userId, powerLevel],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixModerationSetUserPowerLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_user_power_level",
+        argNames: ["roomId", "userId", "powerLevel"],
+      );
+
+  @override
+  Future<bool> crateMatrixSyncServiceStartSyncService() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixSyncServiceStartSyncServiceConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixSyncServiceStartSyncServiceConstMeta =>
+      const TaskConstMeta(debugName: "start_sync_service", argNames: []);
+
+  @override
+  Stream<List<RoomListUpdate>> crateMatrixRoomsSubscribeToAllRoomUpdates() {
+    final stream = RustStreamSink<List<RoomListUpdate>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_room_list_update_Sse(stream, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 106,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateMatrixRoomsSubscribeToAllRoomUpdatesConstMeta,
+          argValues: [stream],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixRoomsSubscribeToAllRoomUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_all_room_updates",
+        argNames: ["stream"],
+      );
+
+  @override
+  Stream<List<MessageUpdate>>
+  crateMatrixTimelinesSubscribeToEventTimelineUpdates({
+    required String roomId,
+    required String eventId,
+    int? numContextEvents,
+    BigInt? throttleMs,
+  }) {
+    final stream = RustStreamSink<List<MessageUpdate>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_message_update_Sse(stream, serializer);
+            sse_encode_String(roomId, serializer);
+            sse_encode_String(eventId, serializer);
+            sse_encode_opt_box_autoadd_u_16(numContextEvents, serializer);
+            sse_encode_opt_box_autoadd_u_64(throttleMs, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 107,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta:
+              kCrateMatrixTimelinesSubscribeToEventTimelineUpdatesConstMeta,
+          argValues: [stream, roomId, eventId, numContextEvents, throttleMs],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesSubscribeToEventTimelineUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_event_timeline_updates",
+        argNames: [
+          "stream",
+          "roomId",
+          "eventId",
+          "numContextEvents",
+          "throttleMs",
+        ],
+      );
+
+  @override
+  Stream<List<KnockRequestInfo>> crateMatrixKnockingSubscribeToKnockRequests({
+    required String roomId,
+  }) {
+    final stream = RustStreamSink<List<KnockRequestInfo>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_knock_request_info_Sse(
+              stream,
+              serializer,
+            );
+            sse_encode_String(roomId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 108,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixKnockingSubscribeToKnockRequestsConstMeta,
+          argValues: [stream, roomId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixKnockingSubscribeToKnockRequestsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_knock_requests",
+        argNames: ["stream", "roomId"],
+      );
+
+  @override
+  Stream<PaginationStatus> crateMatrixTimelinesSubscribeToPaginationStatus({
+    required String roomId,
+  }) {
+    final stream = RustStreamSink<PaginationStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_pagination_status_Sse(stream, serializer);
+            sse_encode_String(roomId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 109,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixTimelinesSubscribeToPaginationStatusConstMeta,
+          argValues: [stream, roomId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesSubscribeToPaginationStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_pagination_status",
+        argNames: ["stream", "roomId"],
+      );
+
+  @override
+  Stream<UserPresence> crateMatrixPresenceSubscribeToPresence({
+    required List<String> userIds,
+    required BigInt subscriptionId,
+  }) {
+    final stream = RustStreamSink<UserPresence>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_user_presence_Sse(stream, serializer);
+            sse_encode_list_String(userIds, serializer);
+            sse_encode_u_64(subscriptionId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 110,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixPresenceSubscribeToPresenceConstMeta,
+          argValues: [stream, userIds, subscriptionId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixPresenceSubscribeToPresenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_presence",
+        argNames: ["stream", "userIds", "subscriptionId"],
+      );
+
+  @override
+  Stream<List<ReadReceipt>> crateMatrixReceiptsSubscribeToReadReceipts({
+    required String roomId,
+  }) {
+    final stream = RustStreamSink<List<ReadReceipt>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_read_receipt_Sse(stream, serializer);
+            sse_encode_String(roomId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 111,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixReceiptsSubscribeToReadReceiptsConstMeta,
+          argValues: [stream, roomId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixReceiptsSubscribeToReadReceiptsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_read_receipts",
+        argNames: ["stream", "roomId"],
+      );
+
+  @override
+  Stream<List<RoomListUpdate>> crateMatrixRoomListSubscribeToRoomList({
+    required BigInt subscriptionId,
+    required RoomListFilterKind filter,
+    int? pageSize,
+  }) {
+    final stream = RustStreamSink<List<RoomListUpdate>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_room_list_update_Sse(stream, serializer);
+            sse_encode_u_64(subscriptionId, serializer);
+            sse_encode_box_autoadd_room_list_filter_kind(filter, serializer);
+            sse_encode_opt_box_autoadd_u_32(pageSize, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 112,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixRoomListSubscribeToRoomListConstMeta,
+          argValues: [stream, subscriptionId, filter, pageSize],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixRoomListSubscribeToRoomListConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_room_list",
+        argNames: ["stream", "subscriptionId", "filter", "pageSize"],
+      );
+
+  @override
+  Stream<List<RoomMembersUpdate>> crateMatrixMembersSubscribeToRoomMembers({
+    required String roomId,
+  }) {
+    final stream = RustStreamSink<List<RoomMembersUpdate>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_room_members_update_Sse(
+              stream,
+              serializer,
+            );
+            sse_encode_String(roomId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 113,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixMembersSubscribeToRoomMembersConstMeta,
+          argValues: [stream, roomId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixMembersSubscribeToRoomMembersConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_room_members",
+        argNames: ["stream", "roomId"],
+      );
+
+  @override
+  Stream<List<MessageUpdate>> crateMatrixTimelinesSubscribeToTimelineUpdates({
+    required String roomId,
+    required BigInt subscriptionId,
+    BigInt? throttleMs,
+  }) {
+    final stream = RustStreamSink<List<MessageUpdate>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_message_update_Sse(stream, serializer);
+            sse_encode_String(roomId, serializer);
+            sse_encode_u_64(subscriptionId, serializer);
+            sse_encode_opt_box_autoadd_u_64(throttleMs, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 114,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixTimelinesSubscribeToTimelineUpdatesConstMeta,
+          argValues: [stream, roomId, subscriptionId, throttleMs],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesSubscribeToTimelineUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_timeline_updates",
+        argNames: ["stream", "roomId", "subscriptionId", "throttleMs"],
+      );
+
+  @override
+  Stream<List<User>> crateMatrixTypingSubscribeToTypingUsers({
+    required String roomId,
+  }) {
+    final stream = RustStreamSink<List<User>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_list_user_Sse(stream, serializer);
+            sse_encode_String(roomId, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 115,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateMatrixTypingSubscribeToTypingUsersConstMeta,
+          argValues: [stream, roomId],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return stream.stream;
+  }
+
+  TaskConstMeta get kCrateMatrixTypingSubscribeToTypingUsersConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_to_typing_users",
+        argNames: ["stream", "roomId"],
+      );
+
+  @override
+  Future<String> crateApiInitTestServerConnectivity() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitTestServerConnectivityConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitTestServerConnectivityConstMeta =>
+      const TaskConstMeta(debugName: "test_server_connectivity", argNames: []);
+
+  @override
+  Future<void> crateMatrixModerationUnbanUser({
+    required String roomId,
+    required String userId,
+    String? reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(userId, serializer);
+          sse_encode_opt_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixModerationUnbanUserConstMeta,
+        argValues: [roomId, userId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixModerationUnbanUserConstMeta =>
+      const TaskConstMeta(
+        debugName: "unban_user",
+        argNames: ["roomId", "userId", "reason"],
+      );
+
+  @override
+  Future<void> crateMatrixIgnoredUsersUnignoreUser({required String userId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixIgnoredUsersUnignoreUserConstMeta,
+        argValues: [userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixIgnoredUsersUnignoreUserConstMeta =>
+      const TaskConstMeta(debugName: "unignore_user", argNames: ["userId"]);
+
+  @override
+  Future<void> crateMatrixPresenceUnsubscribeFromPresence({
+    required BigInt subscriptionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(subscriptionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateMatrixPresenceUnsubscribeFromPresenceConstMeta,
+        argValues: [subscriptionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixPresenceUnsubscribeFromPresenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsubscribe_from_presence",
+        argNames: ["subscriptionId"],
+      );
+
+  @override
+  Future<void> crateMatrixTimelinesUnsubscribeFromTimelineUpdates({
+    required String roomId,
+    required BigInt subscriptionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_u_64(subscriptionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixTimelinesUnsubscribeFromTimelineUpdatesConstMeta,
+        argValues: [roomId, subscriptionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixTimelinesUnsubscribeFromTimelineUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsubscribe_from_timeline_updates",
+        argNames: ["roomId", "subscriptionId"],
+      );
+
+  @override
+  Future<String> crateMatrixRoomSettingsUpgradeRoom({
+    required String roomId,
+    required String newVersion,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(roomId, serializer);
+          sse_encode_String(newVersion, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateMatrixRoomSettingsUpgradeRoomConstMeta,
+        argValues: [roomId, newVersion],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateMatrixRoomSettingsUpgradeRoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "upgrade_room",
+        argNames: ["roomId", "newVersion"],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Certificate =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Certificate =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_FieldsFormatterForFiles =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_FieldsFormatterForFiles =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Certificate
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CertificateImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  FieldsFormatterForFiles
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FieldsFormatterForFilesImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  Certificate
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CertificateImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  FieldsFormatterForFiles
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FieldsFormatterForFilesImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  RustStreamSink<List<KnockRequestInfo>>
+  dco_decode_StreamSink_list_knock_request_info_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<MessageUpdate>>
+  dco_decode_StreamSink_list_message_update_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<ReadReceipt>> dco_decode_StreamSink_list_read_receipt_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<RoomListUpdate>>
+  dco_decode_StreamSink_list_room_list_update_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<RoomMembersUpdate>>
+  dco_decode_StreamSink_list_room_members_update_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<User>> dco_decode_StreamSink_list_user_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PaginationStatus> dco_decode_StreamSink_pagination_status_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<UserPresence> dco_decode_StreamSink_user_presence_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ClientConfig dco_decode_box_autoadd_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_client_config(raw);
+  }
+
+  @protected
+  CreateRoomParams dco_decode_box_autoadd_create_room_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_create_room_params(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  Message dco_decode_box_autoadd_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_message(raw);
+  }
+
+  @protected
+  PowerLevelAction dco_decode_box_autoadd_power_level_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_power_level_action(raw);
+  }
+
+  @protected
+  PowerLevelOverrides dco_decode_box_autoadd_power_level_overrides(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_power_level_overrides(raw);
+  }
+
+  @protected
+  ReadReceipt dco_decode_box_autoadd_read_receipt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_read_receipt(raw);
+  }
+
+  @protected
+  RoomHistoryVisibility dco_decode_box_autoadd_room_history_visibility(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_history_visibility(raw);
+  }
+
+  @protected
+  RoomJoinRule dco_decode_box_autoadd_room_join_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_join_rule(raw);
+  }
+
+  @protected
+  RoomListFilterKind dco_decode_box_autoadd_room_list_filter_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_list_filter_kind(raw);
+  }
+
+  @protected
+  RoomSuccessor dco_decode_box_autoadd_room_successor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_successor(raw);
+  }
+
+  @protected
+  TracingConfiguration dco_decode_box_autoadd_tracing_configuration(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tracing_configuration(raw);
+  }
+
+  @protected
+  TracingFileConfiguration dco_decode_box_autoadd_tracing_file_configuration(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tracing_file_configuration(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  UpdateType dco_decode_box_autoadd_update_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_update_type(raw);
+  }
+
+  @protected
+  User dco_decode_box_autoadd_user(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_user(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  ClientConfig dco_decode_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ClientConfig(
+      sessionPath: dco_decode_String(arr[0]),
+      homeserverUrl: dco_decode_String(arr[1]),
+      rootCertificates:
+          dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+            arr[2],
+          ),
+      proxy: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  ClientError dco_decode_client_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ClientError_Generic(
+          msg: dco_decode_String(raw[1]),
+          details: dco_decode_opt_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CreateRoomParams dco_decode_create_room_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return CreateRoomParams(
+      name: dco_decode_opt_String(arr[0]),
+      topic: dco_decode_opt_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+      alias: dco_decode_opt_String(arr[3]),
+      visibility: dco_decode_room_visibility(arr[4]),
+      isEncrypted: dco_decode_bool(arr[5]),
+      historyVisibility: dco_decode_opt_box_autoadd_room_history_visibility(
+        arr[6],
+      ),
+      guestAccess: dco_decode_opt_box_autoadd_bool(arr[7]),
+      powerLevelOverrides: dco_decode_opt_box_autoadd_power_level_overrides(
+        arr[8],
+      ),
+      roomVersion: dco_decode_opt_String(arr[9]),
+      spaceParentId: dco_decode_opt_String(arr[10]),
+      invite: dco_decode_list_String(arr[11]),
+      isDirect: dco_decode_bool(arr[12]),
+      isSpace: dco_decode_bool(arr[13]),
+    );
+  }
+
+  @protected
+  EventPowerLevel dco_decode_event_power_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventPowerLevel(
+      eventType: dco_decode_String(arr[0]),
+      powerLevel: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  int dco_decode_i_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  InviteDetails dco_decode_invite_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return InviteDetails(
+      roomId: dco_decode_String(arr[0]),
+      roomName: dco_decode_opt_String(arr[1]),
+      topic: dco_decode_opt_String(arr[2]),
+      avatarUrl: dco_decode_opt_String(arr[3]),
+      memberCount: dco_decode_u_64(arr[4]),
+      isDm: dco_decode_bool(arr[5]),
+      inviter: dco_decode_opt_box_autoadd_user(arr[6]),
+    );
+  }
+
+  @protected
+  KnockRequestInfo dco_decode_knock_request_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return KnockRequestInfo(
+      eventId: dco_decode_String(arr[0]),
+      userId: dco_decode_String(arr[1]),
+      displayName: dco_decode_opt_String(arr[2]),
+      avatarUrl: dco_decode_opt_String(arr[3]),
+      reason: dco_decode_opt_String(arr[4]),
+      timestamp: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      isSeen: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  List<Certificate>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate,
+        )
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<EventPowerLevel> dco_decode_list_event_power_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_power_level).toList();
+  }
+
+  @protected
+  List<InviteDetails> dco_decode_list_invite_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_invite_details).toList();
+  }
+
+  @protected
+  List<KnockRequestInfo> dco_decode_list_knock_request_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_knock_request_info).toList();
+  }
+
+  @protected
+  List<Message> dco_decode_list_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message).toList();
+  }
+
+  @protected
+  List<MessageUpdate> dco_decode_list_message_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_update).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<PublicRoom> dco_decode_list_public_room(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_public_room).toList();
+  }
+
+  @protected
+  List<ReadReceipt> dco_decode_list_read_receipt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_read_receipt).toList();
+  }
+
+  @protected
+  List<RoomListFilterKind> dco_decode_list_room_list_filter_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_room_list_filter_kind)
+        .toList();
+  }
+
+  @protected
+  List<RoomListUpdate> dco_decode_list_room_list_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_list_update).toList();
+  }
+
+  @protected
+  List<RoomMemberInfo> dco_decode_list_room_member_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_member_info).toList();
+  }
+
+  @protected
+  List<RoomMembersUpdate> dco_decode_list_room_members_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_members_update).toList();
+  }
+
+  @protected
+  List<RoomTag> dco_decode_list_room_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_tag).toList();
+  }
+
+  @protected
+  List<RoomUpdate> dco_decode_list_room_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_update).toList();
+  }
+
+  @protected
+  List<SpaceHierarchyRoom> dco_decode_list_space_hierarchy_room(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_space_hierarchy_room).toList();
+  }
+
+  @protected
+  List<TraceLogPacks> dco_decode_list_trace_log_packs(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trace_log_packs).toList();
+  }
+
+  @protected
+  List<User> dco_decode_list_user(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_user).toList();
+  }
+
+  @protected
+  List<UserPowerLevel> dco_decode_list_user_power_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_user_power_level).toList();
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogEntry(
+      level: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+      timestamp: dco_decode_i_64(arr[2]),
+      tag: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  MemberMembership dco_decode_member_membership(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MemberMembership.values[raw as int];
+  }
+
+  @protected
+  MemberRole dco_decode_member_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MemberRole.values[raw as int];
+  }
+
+  @protected
+  Message dco_decode_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Message(
+      eventId: dco_decode_String(arr[0]),
+      sender: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      timestamp: dco_decode_u_64(arr[3]),
+      messageType: dco_decode_message_type(arr[4]),
+      readReceipts: dco_decode_list_read_receipt(arr[5]),
+    );
+  }
+
+  @protected
+  MessageType dco_decode_message_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MessageType_Message();
+      case 1:
+        return MessageType_DateDivider();
+      case 2:
+        return MessageType_ReadMarker(
+          ownReceiptEventId: dco_decode_opt_String(raw[1]),
+        );
+      case 3:
+        return MessageType_TimelineStart();
+      case 4:
+        return MessageType_Tombstone(
+          replacementRoomId: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return MessageType_Predecessor(
+          predecessorRoomId: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MessageUpdate dco_decode_message_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MessageUpdate(
+      messageUpdateType: dco_decode_message_update_type(arr[0]),
+      messages: dco_decode_opt_list_message(arr[1]),
+      index: dco_decode_opt_box_autoadd_usize(arr[2]),
+      length: dco_decode_opt_box_autoadd_usize(arr[3]),
+    );
+  }
+
+  @protected
+  MessageUpdateType dco_decode_message_update_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MessageUpdateType.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_8(raw);
+  }
+
+  @protected
+  Message? dco_decode_opt_box_autoadd_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_message(raw);
+  }
+
+  @protected
+  PowerLevelOverrides? dco_decode_opt_box_autoadd_power_level_overrides(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_power_level_overrides(raw);
+  }
+
+  @protected
+  ReadReceipt? dco_decode_opt_box_autoadd_read_receipt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_read_receipt(raw);
+  }
+
+  @protected
+  RoomHistoryVisibility? dco_decode_opt_box_autoadd_room_history_visibility(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_room_history_visibility(raw);
+  }
+
+  @protected
+  RoomJoinRule? dco_decode_opt_box_autoadd_room_join_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_room_join_rule(raw);
+  }
+
+  @protected
+  RoomSuccessor? dco_decode_opt_box_autoadd_room_successor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_room_successor(raw);
+  }
+
+  @protected
+  TracingFileConfiguration?
+  dco_decode_opt_box_autoadd_tracing_file_configuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_tracing_file_configuration(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  UpdateType? dco_decode_opt_box_autoadd_update_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_update_type(raw);
+  }
+
+  @protected
+  User? dco_decode_opt_box_autoadd_user(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_user(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  List<Certificate>?
+  dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+          raw,
+        );
+  }
+
+  @protected
+  List<Message>? dco_decode_opt_list_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_message(raw);
+  }
+
+  @protected
+  List<RoomUpdate>? dco_decode_opt_list_room_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_room_update(raw);
+  }
+
+  @protected
+  PaginationStatus dco_decode_pagination_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaginationStatus.values[raw as int];
+  }
+
+  @protected
+  PowerLevelAction dco_decode_power_level_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PowerLevelAction_Ban();
+      case 1:
+        return PowerLevelAction_Invite();
+      case 2:
+        return PowerLevelAction_Kick();
+      case 3:
+        return PowerLevelAction_Redact();
+      case 4:
+        return PowerLevelAction_EventsDefault();
+      case 5:
+        return PowerLevelAction_StateDefault();
+      case 6:
+        return PowerLevelAction_UsersDefault();
+      case 7:
+        return PowerLevelAction_Event(eventType: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PowerLevelOverrides dco_decode_power_level_overrides(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PowerLevelOverrides(
+      ban: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      invite: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      kick: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      redact: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      eventsDefault: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      stateDefault: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      usersDefault: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      events: dco_decode_list_event_power_level(arr[7]),
+      users: dco_decode_list_user_power_level(arr[8]),
+    );
+  }
+
+  @protected
+  PresenceKind dco_decode_presence_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PresenceKind.values[raw as int];
+  }
+
+  @protected
+  PublicRoom dco_decode_public_room(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PublicRoom(
+      roomId: dco_decode_String(arr[0]),
+      canonicalAlias: dco_decode_opt_String(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+      topic: dco_decode_opt_String(arr[3]),
+      avatarUrl: dco_decode_opt_String(arr[4]),
+      numJoinedMembers: dco_decode_u_64(arr[5]),
+      worldReadable: dco_decode_bool(arr[6]),
+      guestCanJoin: dco_decode_bool(arr[7]),
+      isSpace: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  PublicRoomsPage dco_decode_public_rooms_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PublicRoomsPage(
+      rooms: dco_decode_list_public_room(arr[0]),
+      nextBatch: dco_decode_opt_String(arr[1]),
+      prevBatch: dco_decode_opt_String(arr[2]),
+      totalRoomCountEstimate: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  ReadReceipt dco_decode_read_receipt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReadReceipt(
+      userId: dco_decode_String(arr[0]),
+      eventId: dco_decode_String(arr[1]),
+      timestamp: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  ReceiptKind dco_decode_receipt_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReceiptKind.values[raw as int];
+  }
+
+  @protected
+  RoomAliases dco_decode_room_aliases(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomAliases(
+      canonicalAlias: dco_decode_opt_String(arr[0]),
+      altAliases: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  RoomHistoryVisibility dco_decode_room_history_visibility(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomHistoryVisibility.values[raw as int];
+  }
+
+  @protected
+  RoomJoinRule dco_decode_room_join_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RoomJoinRule_Public();
+      case 1:
+        return RoomJoinRule_Invite();
+      case 2:
+        return RoomJoinRule_Knock();
+      case 3:
+        return RoomJoinRule_Restricted(
+          allowedRoomIds: dco_decode_list_String(raw[1]),
+        );
+      case 4:
+        return RoomJoinRule_KnockRestricted(
+          allowedRoomIds: dco_decode_list_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RoomListFilterCategory dco_decode_room_list_filter_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomListFilterCategory.values[raw as int];
+  }
+
+  @protected
+  RoomListFilterKind dco_decode_room_list_filter_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RoomListFilterKind_All(
+          filters: dco_decode_list_room_list_filter_kind(raw[1]),
+        );
+      case 1:
+        return RoomListFilterKind_Any(
+          filters: dco_decode_list_room_list_filter_kind(raw[1]),
+        );
+      case 2:
+        return RoomListFilterKind_NonSpace();
+      case 3:
+        return RoomListFilterKind_NonLeft();
+      case 4:
+        return RoomListFilterKind_Joined();
+      case 5:
+        return RoomListFilterKind_Unread();
+      case 6:
+        return RoomListFilterKind_Favourite();
+      case 7:
+        return RoomListFilterKind_LowPriority();
+      case 8:
+        return RoomListFilterKind_NonLowPriority();
+      case 9:
+        return RoomListFilterKind_Invite();
+      case 10:
+        return RoomListFilterKind_Category(
+          expect: dco_decode_room_list_filter_category(raw[1]),
+        );
+      case 11:
+        return RoomListFilterKind_None();
+      case 12:
+        return RoomListFilterKind_NormalizedMatchRoomName(
+          pattern: dco_decode_String(raw[1]),
+        );
+      case 13:
+        return RoomListFilterKind_FuzzyMatchRoomName(
+          pattern: dco_decode_String(raw[1]),
+        );
+      case 14:
+        return RoomListFilterKind_DeduplicateVersions();
+      case 15:
+        return RoomListFilterKind_Tag(name: dco_decode_String(raw[1]));
+      case 16:
+        return RoomListFilterKind_Space(spaceId: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RoomListUpdate dco_decode_room_list_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RoomListUpdate(
+      updateType: dco_decode_room_list_update_type(arr[0]),
+      rooms: dco_decode_opt_list_room_update(arr[1]),
+      index: dco_decode_opt_box_autoadd_usize(arr[2]),
+      length: dco_decode_opt_box_autoadd_usize(arr[3]),
+    );
+  }
+
+  @protected
+  RoomListUpdateType dco_decode_room_list_update_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomListUpdateType.values[raw as int];
+  }
+
+  @protected
+  RoomMemberInfo dco_decode_room_member_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RoomMemberInfo(
+      userId: dco_decode_String(arr[0]),
+      displayName: dco_decode_opt_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+      membership: dco_decode_member_membership(arr[3]),
+      powerLevel: dco_decode_i_64(arr[4]),
+      role: dco_decode_member_role(arr[5]),
+    );
+  }
+
+  @protected
+  RoomMembersPage dco_decode_room_members_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomMembersPage(
+      members: dco_decode_list_room_member_info(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  RoomMembersUpdate dco_decode_room_members_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RoomMembersUpdate_Reset(
+          members: dco_decode_list_room_member_info(raw[1]),
+        );
+      case 1:
+        return RoomMembersUpdate_Upsert(
+          member: dco_decode_room_member_info(raw[1]),
+        );
+      case 2:
+        return RoomMembersUpdate_Remove(userId: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RoomPermissions dco_decode_room_permissions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return RoomPermissions(
+      ownPowerLevel: dco_decode_i_64(arr[0]),
+      canSendMessage: dco_decode_bool(arr[1]),
+      canRedactOwn: dco_decode_bool(arr[2]),
+      canRedactOthers: dco_decode_bool(arr[3]),
+      canInvite: dco_decode_bool(arr[4]),
+      canKick: dco_decode_bool(arr[5]),
+      canBan: dco_decode_bool(arr[6]),
+      canChangeSettings: dco_decode_bool(arr[7]),
+      canChangePowerLevels: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  RoomPowerLevelsInfo dco_decode_room_power_levels_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return RoomPowerLevelsInfo(
+      ban: dco_decode_i_64(arr[0]),
+      invite: dco_decode_i_64(arr[1]),
+      kick: dco_decode_i_64(arr[2]),
+      redact: dco_decode_i_64(arr[3]),
+      eventsDefault: dco_decode_i_64(arr[4]),
+      stateDefault: dco_decode_i_64(arr[5]),
+      usersDefault: dco_decode_i_64(arr[6]),
+      events: dco_decode_list_event_power_level(arr[7]),
+      users: dco_decode_list_user_power_level(arr[8]),
+    );
+  }
+
+  @protected
+  RoomPreviewInfo dco_decode_room_preview_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return RoomPreviewInfo(
+      roomId: dco_decode_String(arr[0]),
+      canonicalAlias: dco_decode_opt_String(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+      topic: dco_decode_opt_String(arr[3]),
+      avatarUrl: dco_decode_opt_String(arr[4]),
+      numJoinedMembers: dco_decode_u_64(arr[5]),
+      isSpace: dco_decode_bool(arr[6]),
+      isWorldReadable: dco_decode_opt_box_autoadd_bool(arr[7]),
+      isDirect: dco_decode_opt_box_autoadd_bool(arr[8]),
+      membership: dco_decode_opt_box_autoadd_update_type(arr[9]),
+    );
+  }
+
+  @protected
+  RoomSuccessor dco_decode_room_successor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomSuccessor(
+      roomId: dco_decode_String(arr[0]),
+      reason: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  RoomTag dco_decode_room_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomTag(
+      name: dco_decode_String(arr[0]),
+      order: dco_decode_opt_box_autoadd_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  RoomUpdate dco_decode_room_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return RoomUpdate(
+      roomId: dco_decode_String(arr[0]),
+      rawName: dco_decode_opt_String(arr[1]),
+      displayName: dco_decode_opt_String(arr[2]),
+      isDm: dco_decode_opt_box_autoadd_bool(arr[3]),
+      updateType: dco_decode_update_type(arr[4]),
+      unreadNotifications: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      unreadHighlight: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      unreadMentions: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      unreadMessages: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      isMarkedUnread: dco_decode_bool(arr[9]),
+      message: dco_decode_opt_box_autoadd_message(arr[10]),
+      tags: dco_decode_list_room_tag(arr[11]),
+      topic: dco_decode_opt_String(arr[12]),
+      avatarUrl: dco_decode_opt_String(arr[13]),
+      canonicalAlias: dco_decode_opt_String(arr[14]),
+      isEncrypted: dco_decode_bool(arr[15]),
+      historyVisibility: dco_decode_opt_box_autoadd_room_history_visibility(
+        arr[16],
+      ),
+      joinRule: dco_decode_opt_box_autoadd_room_join_rule(arr[17]),
+      successor: dco_decode_opt_box_autoadd_room_successor(arr[18]),
+      predecessorRoomId: dco_decode_opt_String(arr[19]),
+    );
+  }
+
+  @protected
+  RoomVisibility dco_decode_room_visibility(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomVisibility.values[raw as int];
+  }
+
+  @protected
+  SpaceHierarchyPage dco_decode_space_hierarchy_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SpaceHierarchyPage(
+      rooms: dco_decode_list_space_hierarchy_room(arr[0]),
+      nextBatch: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  SpaceHierarchyRoom dco_decode_space_hierarchy_room(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SpaceHierarchyRoom(
+      roomId: dco_decode_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      topic: dco_decode_opt_String(arr[2]),
+      avatarUrl: dco_decode_opt_String(arr[3]),
+      canonicalAlias: dco_decode_opt_String(arr[4]),
+      numJoinedMembers: dco_decode_u_64(arr[5]),
+      isSpace: dco_decode_bool(arr[6]),
+      suggested: dco_decode_bool(arr[7]),
+      children: dco_decode_list_String(arr[8]),
+      membership: dco_decode_opt_box_autoadd_update_type(arr[9]),
+    );
+  }
+
+  @protected
+  TraceLogPacks dco_decode_trace_log_packs(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TraceLogPacks.values[raw as int];
+  }
+
+  @protected
+  TracingConfiguration dco_decode_tracing_configuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TracingConfiguration(
+      logLevel: dco_decode_log_level(arr[0]),
+      traceLogPacks: dco_decode_list_trace_log_packs(arr[1]),
+      extraTargets: dco_decode_list_String(arr[2]),
+      writeToStdoutOrSystem: dco_decode_bool(arr[3]),
+      writeToFiles: dco_decode_opt_box_autoadd_tracing_file_configuration(
+        arr[4],
+      ),
+    );
+  }
+
+  @protected
+  TracingFileConfiguration dco_decode_tracing_file_configuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TracingFileConfiguration(
+      path: dco_decode_String(arr[0]),
+      filePrefix: dco_decode_String(arr[1]),
+      fileSuffix: dco_decode_opt_String(arr[2]),
+      maxFiles: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpdateType dco_decode_update_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UpdateType.values[raw as int];
+  }
+
+  @protected
+  User dco_decode_user(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return User(
+      userId: dco_decode_String(arr[0]),
+      displayName: dco_decode_opt_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  UserPowerLevel dco_decode_user_power_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UserPowerLevel(
+      userId: dco_decode_String(arr[0]),
+      powerLevel: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  UserPresence dco_decode_user_presence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return UserPresence(
+      userId: dco_decode_String(arr[0]),
+      presence: dco_decode_presence_kind(arr[1]),
+      lastActive: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      currentlyActive: dco_decode_opt_box_autoadd_bool(arr[3]),
+      statusMessage: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  UserSearchResult dco_decode_user_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UserSearchResult(
+      users: dco_decode_list_user(arr[0]),
+      limited: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Certificate
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CertificateImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  FieldsFormatterForFiles
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return FieldsFormatterForFilesImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Certificate
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CertificateImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  FieldsFormatterForFiles
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return FieldsFormatterForFilesImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<List<KnockRequestInfo>>
+  sse_decode_StreamSink_list_knock_request_info_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<MessageUpdate>>
+  sse_decode_StreamSink_list_message_update_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<ReadReceipt>> sse_decode_StreamSink_list_read_receipt_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<RoomListUpdate>>
+  sse_decode_StreamSink_list_room_list_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<RoomMembersUpdate>>
+  sse_decode_StreamSink_list_room_members_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<User>> sse_decode_StreamSink_list_user_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PaginationStatus> sse_decode_StreamSink_pagination_status_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<UserPresence> sse_decode_StreamSink_user_presence_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  ClientConfig sse_decode_box_autoadd_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_client_config(deserializer));
+  }
+
+  @protected
+  CreateRoomParams sse_decode_box_autoadd_create_room_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_create_room_params(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_8(deserializer));
+  }
+
+  @protected
+  Message sse_decode_box_autoadd_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_message(deserializer));
+  }
+
+  @protected
+  PowerLevelAction sse_decode_box_autoadd_power_level_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_power_level_action(deserializer));
+  }
+
+  @protected
+  PowerLevelOverrides sse_decode_box_autoadd_power_level_overrides(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_power_level_overrides(deserializer));
+  }
+
+  @protected
+  ReadReceipt sse_decode_box_autoadd_read_receipt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_read_receipt(deserializer));
+  }
+
+  @protected
+  RoomHistoryVisibility sse_decode_box_autoadd_room_history_visibility(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_history_visibility(deserializer));
+  }
+
+  @protected
+  RoomJoinRule sse_decode_box_autoadd_room_join_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_join_rule(deserializer));
+  }
+
+  @protected
+  RoomListFilterKind sse_decode_box_autoadd_room_list_filter_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_list_filter_kind(deserializer));
+  }
+
+  @protected
+  RoomSuccessor sse_decode_box_autoadd_room_successor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_successor(deserializer));
+  }
+
+  @protected
+  TracingConfiguration sse_decode_box_autoadd_tracing_configuration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tracing_configuration(deserializer));
+  }
+
+  @protected
+  TracingFileConfiguration sse_decode_box_autoadd_tracing_file_configuration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tracing_file_configuration(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  UpdateType sse_decode_box_autoadd_update_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_update_type(deserializer));
+  }
+
+  @protected
+  User sse_decode_box_autoadd_user(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_user(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  ClientConfig sse_decode_client_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionPath = sse_decode_String(deserializer);
+    var var_homeserverUrl = sse_decode_String(deserializer);
+    var var_rootCertificates =
+        sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+          deserializer,
+        );
+    var var_proxy = sse_decode_opt_String(deserializer);
+    return ClientConfig(
+      sessionPath: var_sessionPath,
+      homeserverUrl: var_homeserverUrl,
+      rootCertificates: var_rootCertificates,
+      proxy: var_proxy,
+    );
+  }
+
+  @protected
+  ClientError sse_decode_client_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_msg = sse_decode_String(deserializer);
+        var var_details = sse_decode_opt_String(deserializer);
+        return ClientError_Generic(msg: var_msg, details: var_details);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CreateRoomParams sse_decode_create_room_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_alias = sse_decode_opt_String(deserializer);
+    var var_visibility = sse_decode_room_visibility(deserializer);
+    var var_isEncrypted = sse_decode_bool(deserializer);
+    var var_historyVisibility =
+        sse_decode_opt_box_autoadd_room_history_visibility(deserializer);
+    var var_guestAccess = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_powerLevelOverrides =
+        sse_decode_opt_box_autoadd_power_level_overrides(deserializer);
+    var var_roomVersion = sse_decode_opt_String(deserializer);
+    var var_spaceParentId = sse_decode_opt_String(deserializer);
+    var var_invite = sse_decode_list_String(deserializer);
+    var var_isDirect = sse_decode_bool(deserializer);
+    var var_isSpace = sse_decode_bool(deserializer);
+    return CreateRoomParams(
+      name: var_name,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      alias: var_alias,
+      visibility: var_visibility,
+      isEncrypted: var_isEncrypted,
+      historyVisibility: var_historyVisibility,
+      guestAccess: var_guestAccess,
+      powerLevelOverrides: var_powerLevelOverrides,
+      roomVersion: var_roomVersion,
+      spaceParentId: var_spaceParentId,
+      invite: var_invite,
+      isDirect: var_isDirect,
+      isSpace: var_isSpace,
+    );
+  }
+
+  @protected
+  EventPowerLevel sse_decode_event_power_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventType = sse_decode_String(deserializer);
+    var var_powerLevel = sse_decode_i_64(deserializer);
+    return EventPowerLevel(
+      eventType: var_eventType,
+      powerLevel: var_powerLevel,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  int sse_decode_i_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt8();
+  }
+
+  @protected
+  InviteDetails sse_decode_invite_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_roomName = sse_decode_opt_String(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_memberCount = sse_decode_u_64(deserializer);
+    var var_isDm = sse_decode_bool(deserializer);
+    var var_inviter = sse_decode_opt_box_autoadd_user(deserializer);
+    return InviteDetails(
+      roomId: var_roomId,
+      roomName: var_roomName,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      memberCount: var_memberCount,
+      isDm: var_isDm,
+      inviter: var_inviter,
+    );
+  }
+
+  @protected
+  KnockRequestInfo sse_decode_knock_request_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_String(deserializer);
+    var var_userId = sse_decode_String(deserializer);
+    var var_displayName = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_reason = sse_decode_opt_String(deserializer);
+    var var_timestamp = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_isSeen = sse_decode_bool(deserializer);
+    return KnockRequestInfo(
+      eventId: var_eventId,
+      userId: var_userId,
+      displayName: var_displayName,
+      avatarUrl: var_avatarUrl,
+      reason: var_reason,
+      timestamp: var_timestamp,
+      isSeen: var_isSeen,
+    );
+  }
+
+  @protected
+  List<Certificate>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Certificate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventPowerLevel> sse_decode_list_event_power_level(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventPowerLevel>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_power_level(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InviteDetails> sse_decode_list_invite_details(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InviteDetails>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_invite_details(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KnockRequestInfo> sse_decode_list_knock_request_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KnockRequestInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_knock_request_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Message>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageUpdate> sse_decode_list_message_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<PublicRoom> sse_decode_list_public_room(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PublicRoom>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_public_room(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadReceipt> sse_decode_list_read_receipt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadReceipt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_read_receipt(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomListFilterKind> sse_decode_list_room_list_filter_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomListFilterKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_list_filter_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomListUpdate> sse_decode_list_room_list_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomListUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_list_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomMemberInfo> sse_decode_list_room_member_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomMemberInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_member_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomMembersUpdate> sse_decode_list_room_members_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomMembersUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_members_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomTag> sse_decode_list_room_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomUpdate> sse_decode_list_room_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RoomUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SpaceHierarchyRoom> sse_decode_list_space_hierarchy_room(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SpaceHierarchyRoom>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_space_hierarchy_room(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TraceLogPacks> sse_decode_list_trace_log_packs(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TraceLogPacks>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trace_log_packs(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<User> sse_decode_list_user(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <User>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_user(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UserPowerLevel> sse_decode_list_user_power_level(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UserPowerLevel>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_user_power_level(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_tag = sse_decode_String(deserializer);
+    return LogEntry(
+      level: var_level,
+      message: var_message,
+      timestamp: var_timestamp,
+      tag: var_tag,
+    );
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  MemberMembership sse_decode_member_membership(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MemberMembership.values[inner];
+  }
+
+  @protected
+  MemberRole sse_decode_member_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MemberRole.values[inner];
+  }
+
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_String(deserializer);
+    var var_sender = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_messageType = sse_decode_message_type(deserializer);
+    var var_readReceipts = sse_decode_list_read_receipt(deserializer);
+    return Message(
+      eventId: var_eventId,
+      sender: var_sender,
+      content: var_content,
+      timestamp: var_timestamp,
+      messageType: var_messageType,
+      readReceipts: var_readReceipts,
+    );
+  }
+
+  @protected
+  MessageType sse_decode_message_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return MessageType_Message();
+      case 1:
+        return MessageType_DateDivider();
+      case 2:
+        var var_ownReceiptEventId = sse_decode_opt_String(deserializer);
+        return MessageType_ReadMarker(ownReceiptEventId: var_ownReceiptEventId);
+      case 3:
+        return MessageType_TimelineStart();
+      case 4:
+        var var_replacementRoomId = sse_decode_String(deserializer);
+        return MessageType_Tombstone(replacementRoomId: var_replacementRoomId);
+      case 5:
+        var var_predecessorRoomId = sse_decode_String(deserializer);
+        return MessageType_Predecessor(
+          predecessorRoomId: var_predecessorRoomId,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MessageUpdate sse_decode_message_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageUpdateType = sse_decode_message_update_type(deserializer);
+    var var_messages = sse_decode_opt_list_message(deserializer);
+    var var_index = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_length = sse_decode_opt_box_autoadd_usize(deserializer);
+    return MessageUpdate(
+      messageUpdateType: var_messageUpdateType,
+      messages: var_messages,
+      index: var_index,
+      length: var_length,
+    );
+  }
+
+  @protected
+  MessageUpdateType sse_decode_message_update_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MessageUpdateType.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  int? sse_decode_opt_box_autoadd_i_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_8(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Certificate
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    dynamic raw,
+  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PowerLevelOverrides? sse_decode_opt_box_autoadd_power_level_overrides(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CertificateImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_power_level_overrides(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  FieldsFormatterForFiles
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    dynamic raw,
+  ReadReceipt? sse_decode_opt_box_autoadd_read_receipt(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FieldsFormatterForFilesImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_read_receipt(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Certificate
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    dynamic raw,
+  RoomHistoryVisibility? sse_decode_opt_box_autoadd_room_history_visibility(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CertificateImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_room_history_visibility(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  FieldsFormatterForFiles
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    dynamic raw,
+  RoomJoinRule? sse_decode_opt_box_autoadd_room_join_rule(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FieldsFormatterForFilesImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_room_join_rule(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  RoomSuccessor? sse_decode_opt_box_autoadd_room_successor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_room_successor(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RustStreamSink<MessageUpdate> dco_decode_StreamSink_message_update_Sse(
-    dynamic raw,
+  TracingFileConfiguration?
+  sse_decode_opt_box_autoadd_tracing_file_configuration(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_tracing_file_configuration(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RustStreamSink<RoomUpdate> dco_decode_StreamSink_room_update_Sse(
-    dynamic raw,
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  UpdateType? sse_decode_opt_box_autoadd_update_type(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_update_type(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  User? sse_decode_opt_box_autoadd_user(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_user(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  List<Certificate>?
+  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<Message>? sse_decode_opt_list_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<RoomUpdate>? sse_decode_opt_list_room_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_room_update(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PaginationStatus sse_decode_pagination_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaginationStatus.values[inner];
+  }
+
+  @protected
+  PowerLevelAction sse_decode_power_level_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return PowerLevelAction_Ban();
+      case 1:
+        return PowerLevelAction_Invite();
+      case 2:
+        return PowerLevelAction_Kick();
+      case 3:
+        return PowerLevelAction_Redact();
+      case 4:
+        return PowerLevelAction_EventsDefault();
+      case 5:
+        return PowerLevelAction_StateDefault();
+      case 6:
+        return PowerLevelAction_UsersDefault();
+      case 7:
+        var var_eventType = sse_decode_String(deserializer);
+        return PowerLevelAction_Event(eventType: var_eventType);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PowerLevelOverrides sse_decode_power_level_overrides(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ban = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_invite = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_kick = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_redact = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_eventsDefault = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_stateDefault = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_usersDefault = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_events = sse_decode_list_event_power_level(deserializer);
+    var var_users = sse_decode_list_user_power_level(deserializer);
+    return PowerLevelOverrides(
+      ban: var_ban,
+      invite: var_invite,
+      kick: var_kick,
+      redact: var_redact,
+      eventsDefault: var_eventsDefault,
+      stateDefault: var_stateDefault,
+      usersDefault: var_usersDefault,
+      events: var_events,
+      users: var_users,
+    );
+  }
+
+  @protected
+  PresenceKind sse_decode_presence_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PresenceKind.values[inner];
+  }
+
+  @protected
+  PublicRoom sse_decode_public_room(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_canonicalAlias = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_numJoinedMembers = sse_decode_u_64(deserializer);
+    var var_worldReadable = sse_decode_bool(deserializer);
+    var var_guestCanJoin = sse_decode_bool(deserializer);
+    var var_isSpace = sse_decode_bool(deserializer);
+    return PublicRoom(
+      roomId: var_roomId,
+      canonicalAlias: var_canonicalAlias,
+      name: var_name,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      numJoinedMembers: var_numJoinedMembers,
+      worldReadable: var_worldReadable,
+      guestCanJoin: var_guestCanJoin,
+      isSpace: var_isSpace,
+    );
+  }
+
+  @protected
+  PublicRoomsPage sse_decode_public_rooms_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rooms = sse_decode_list_public_room(deserializer);
+    var var_nextBatch = sse_decode_opt_String(deserializer);
+    var var_prevBatch = sse_decode_opt_String(deserializer);
+    var var_totalRoomCountEstimate =
+        sse_decode_opt_box_autoadd_u_64(deserializer);
+    return PublicRoomsPage(
+      rooms: var_rooms,
+      nextBatch: var_nextBatch,
+      prevBatch: var_prevBatch,
+      totalRoomCountEstimate: var_totalRoomCountEstimate,
+    );
+  }
+
+  @protected
+  ReadReceipt sse_decode_read_receipt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_eventId = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ReadReceipt(
+      userId: var_userId,
+      eventId: var_eventId,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  ReceiptKind sse_decode_receipt_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReceiptKind.values[inner];
+  }
+
+  @protected
+  RoomAliases sse_decode_room_aliases(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_canonicalAlias = sse_decode_opt_String(deserializer);
+    var var_altAliases = sse_decode_list_String(deserializer);
+    return RoomAliases(
+      canonicalAlias: var_canonicalAlias,
+      altAliases: var_altAliases,
+    );
   }
 
   @protected
-  ClientConfig dco_decode_box_autoadd_client_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_client_config(raw);
+  RoomHistoryVisibility sse_decode_room_history_visibility(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RoomHistoryVisibility.values[inner];
   }
 
   @protected
-  Message dco_decode_box_autoadd_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_message(raw);
+  RoomJoinRule sse_decode_room_join_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return RoomJoinRule_Public();
+      case 1:
+        return RoomJoinRule_Invite();
+      case 2:
+        return RoomJoinRule_Knock();
+      case 3:
+        var var_allowedRoomIds = sse_decode_list_String(deserializer);
+        return RoomJoinRule_Restricted(allowedRoomIds: var_allowedRoomIds);
+      case 4:
+        var var_allowedRoomIds = sse_decode_list_String(deserializer);
+        return RoomJoinRule_KnockRestricted(allowedRoomIds: var_allowedRoomIds);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  TracingConfiguration dco_decode_box_autoadd_tracing_configuration(
-    dynamic raw,
+  RoomListFilterCategory sse_decode_room_list_filter_category(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_tracing_configuration(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RoomListFilterCategory.values[inner];
   }
 
   @protected
-  TracingFileConfiguration dco_decode_box_autoadd_tracing_file_configuration(
-    dynamic raw,
+  RoomListFilterKind sse_decode_room_list_filter_kind(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_tracing_file_configuration(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_filters = sse_decode_list_room_list_filter_kind(deserializer);
+        return RoomListFilterKind_All(filters: var_filters);
+      case 1:
+        var var_filters = sse_decode_list_room_list_filter_kind(deserializer);
+        return RoomListFilterKind_Any(filters: var_filters);
+      case 2:
+        return RoomListFilterKind_NonSpace();
+      case 3:
+        return RoomListFilterKind_NonLeft();
+      case 4:
+        return RoomListFilterKind_Joined();
+      case 5:
+        return RoomListFilterKind_Unread();
+      case 6:
+        return RoomListFilterKind_Favourite();
+      case 7:
+        return RoomListFilterKind_LowPriority();
+      case 8:
+        return RoomListFilterKind_NonLowPriority();
+      case 9:
+        return RoomListFilterKind_Invite();
+      case 10:
+        var var_expect = sse_decode_room_list_filter_category(deserializer);
+        return RoomListFilterKind_Category(expect: var_expect);
+      case 11:
+        return RoomListFilterKind_None();
+      case 12:
+        var var_pattern = sse_decode_String(deserializer);
+        return RoomListFilterKind_NormalizedMatchRoomName(pattern: var_pattern);
+      case 13:
+        var var_pattern = sse_decode_String(deserializer);
+        return RoomListFilterKind_FuzzyMatchRoomName(pattern: var_pattern);
+      case 14:
+        return RoomListFilterKind_DeduplicateVersions();
+      case 15:
+        var var_name = sse_decode_String(deserializer);
+        return RoomListFilterKind_Tag(name: var_name);
+      case 16:
+        var var_spaceId = sse_decode_String(deserializer);
+        return RoomListFilterKind_Space(spaceId: var_spaceId);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  RoomListUpdate sse_decode_room_list_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_updateType = sse_decode_room_list_update_type(deserializer);
+    var var_rooms = sse_decode_opt_list_room_update(deserializer);
+    var var_index = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_length = sse_decode_opt_box_autoadd_usize(deserializer);
+    return RoomListUpdate(
+      updateType: var_updateType,
+      rooms: var_rooms,
+      index: var_index,
+      length: var_length,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  RoomListUpdateType sse_decode_room_list_update_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RoomListUpdateType.values[inner];
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
+  RoomMemberInfo sse_decode_room_member_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_displayName = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_membership = sse_decode_member_membership(deserializer);
+    var var_powerLevel = sse_decode_i_64(deserializer);
+    var var_role = sse_decode_member_role(deserializer);
+    return RoomMemberInfo(
+      userId: var_userId,
+      displayName: var_displayName,
+      avatarUrl: var_avatarUrl,
+      membership: var_membership,
+      powerLevel: var_powerLevel,
+      role: var_role,
+    );
   }
 
   @protected
-  ClientConfig dco_decode_client_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ClientConfig(
-      sessionPath: dco_decode_String(arr[0]),
-      homeserverUrl: dco_decode_String(arr[1]),
-      rootCertificates:
-          dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-            arr[2],
-          ),
-      proxy: dco_decode_opt_String(arr[3]),
-    );
+  RoomMembersPage sse_decode_room_members_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_members = sse_decode_list_room_member_info(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return RoomMembersPage(members: var_members, total: var_total);
   }
 
   @protected
-  ClientError dco_decode_client_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
+  RoomMembersUpdate sse_decode_room_members_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
       case 0:
-        return ClientError_Generic(
-          msg: dco_decode_String(raw[1]),
-          details: dco_decode_opt_String(raw[2]),
-        );
+        var var_members = sse_decode_list_room_member_info(deserializer);
+        return RoomMembersUpdate_Reset(members: var_members);
+      case 1:
+        var var_member = sse_decode_room_member_info(deserializer);
+        return RoomMembersUpdate_Upsert(member: var_member);
+      case 2:
+        var var_userId = sse_decode_String(deserializer);
+        return RoomMembersUpdate_Remove(userId: var_userId);
       default:
-        throw Exception("unreachable");
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  RoomPermissions sse_decode_room_permissions(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ownPowerLevel = sse_decode_i_64(deserializer);
+    var var_canSendMessage = sse_decode_bool(deserializer);
+    var var_canRedactOwn = sse_decode_bool(deserializer);
+    var var_canRedactOthers = sse_decode_bool(deserializer);
+    var var_canInvite = sse_decode_bool(deserializer);
+    var var_canKick = sse_decode_bool(deserializer);
+    var var_canBan = sse_decode_bool(deserializer);
+    var var_canChangeSettings = sse_decode_bool(deserializer);
+    var var_canChangePowerLevels = sse_decode_bool(deserializer);
+    return RoomPermissions(
+      ownPowerLevel: var_ownPowerLevel,
+      canSendMessage: var_canSendMessage,
+      canRedactOwn: var_canRedactOwn,
+      canRedactOthers: var_canRedactOthers,
+      canInvite: var_canInvite,
+      canKick: var_canKick,
+      canBan: var_canBan,
+      canChangeSettings: var_canChangeSettings,
+      canChangePowerLevels: var_canChangePowerLevels,
+    );
   }
 
   @protected
-  List<Certificate>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    dynamic raw,
+  RoomPowerLevelsInfo sse_decode_room_power_levels_info(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate,
-        )
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ban = sse_decode_i_64(deserializer);
+    var var_invite = sse_decode_i_64(deserializer);
+    var var_kick = sse_decode_i_64(deserializer);
+    var var_redact = sse_decode_i_64(deserializer);
+    var var_eventsDefault = sse_decode_i_64(deserializer);
+    var var_stateDefault = sse_decode_i_64(deserializer);
+    var var_usersDefault = sse_decode_i_64(deserializer);
+    var var_events = sse_decode_list_event_power_level(deserializer);
+    var var_users = sse_decode_list_user_power_level(deserializer);
+    return RoomPowerLevelsInfo(
+      ban: var_ban,
+      invite: var_invite,
+      kick: var_kick,
+      redact: var_redact,
+      eventsDefault: var_eventsDefault,
+      stateDefault: var_stateDefault,
+      usersDefault: var_usersDefault,
+      events: var_events,
+      users: var_users,
+    );
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  RoomPreviewInfo sse_decode_room_preview_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_canonicalAlias = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_numJoinedMembers = sse_decode_u_64(deserializer);
+    var var_isSpace = sse_decode_bool(deserializer);
+    var var_isWorldReadable = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_isDirect = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_membership = sse_decode_opt_box_autoadd_update_type(deserializer);
+    return RoomPreviewInfo(
+      roomId: var_roomId,
+      canonicalAlias: var_canonicalAlias,
+      name: var_name,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      numJoinedMembers: var_numJoinedMembers,
+      isSpace: var_isSpace,
+      isWorldReadable: var_isWorldReadable,
+      isDirect: var_isDirect,
+      membership: var_membership,
+    );
   }
 
   @protected
-  List<Message> dco_decode_list_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_message).toList();
+  RoomSuccessor sse_decode_room_successor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_reason = sse_decode_opt_String(deserializer);
+    return RoomSuccessor(roomId: var_roomId, reason: var_reason);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  RoomTag sse_decode_room_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_order = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return RoomTag(name: var_name, order: var_order);
   }
 
   @protected
-  List<RoomUpdate> dco_decode_list_room_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_room_update).toList();
+  RoomUpdate sse_decode_room_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_rawName = sse_decode_opt_String(deserializer);
+    var var_displayName = sse_decode_opt_String(deserializer);
+    var var_isDm = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_updateType = sse_decode_update_type(deserializer);
+    var var_unreadNotifications = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_unreadHighlight = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_unreadMentions = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_unreadMessages = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_isMarkedUnread = sse_decode_bool(deserializer);
+    var var_message = sse_decode_opt_box_autoadd_message(deserializer);
+    var var_tags = sse_decode_list_room_tag(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_canonicalAlias = sse_decode_opt_String(deserializer);
+    var var_isEncrypted = sse_decode_bool(deserializer);
+    var var_historyVisibility =
+        sse_decode_opt_box_autoadd_room_history_visibility(deserializer);
+    var var_joinRule = sse_decode_opt_box_autoadd_room_join_rule(deserializer);
+    var var_successor = sse_decode_opt_box_autoadd_room_successor(deserializer);
+    var var_predecessorRoomId = sse_decode_opt_String(deserializer);
+    return RoomUpdate(
+      roomId: var_roomId,
+      rawName: var_rawName,
+      displayName: var_displayName,
+      isDm: var_isDm,
+      updateType: var_updateType,
+      unreadNotifications: var_unreadNotifications,
+      unreadHighlight: var_unreadHighlight,
+      unreadMentions: var_unreadMentions,
+      unreadMessages: var_unreadMessages,
+      isMarkedUnread: var_isMarkedUnread,
+      message: var_message,
+      tags: var_tags,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      canonicalAlias: var_canonicalAlias,
+      isEncrypted: var_isEncrypted,
+      historyVisibility: var_historyVisibility,
+      joinRule: var_joinRule,
+      successor: var_successor,
+      predecessorRoomId: var_predecessorRoomId,
+    );
   }
 
   @protected
-  List<TraceLogPacks> dco_decode_list_trace_log_packs(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_trace_log_packs).toList();
+  RoomVisibility sse_decode_room_visibility(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RoomVisibility.values[inner];
   }
 
   @protected
-  List<User> dco_decode_list_user(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_user).toList();
+  SpaceHierarchyPage sse_decode_space_hierarchy_page(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rooms = sse_decode_list_space_hierarchy_room(deserializer);
+    var var_nextBatch = sse_decode_opt_String(deserializer);
+    return SpaceHierarchyPage(rooms: var_rooms, nextBatch: var_nextBatch);
   }
 
   @protected
-  LogEntry dco_decode_log_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return LogEntry(
-      level: dco_decode_String(arr[0]),
-      message: dco_decode_String(arr[1]),
-      timestamp: dco_decode_i_64(arr[2]),
-      tag: dco_decode_String(arr[3]),
+  SpaceHierarchyRoom sse_decode_space_hierarchy_room(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_roomId = sse_decode_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_topic = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    var var_canonicalAlias = sse_decode_opt_String(deserializer);
+    var var_numJoinedMembers = sse_decode_u_64(deserializer);
+    var var_isSpace = sse_decode_bool(deserializer);
+    var var_suggested = sse_decode_bool(deserializer);
+    var var_children = sse_decode_list_String(deserializer);
+    var var_membership = sse_decode_opt_box_autoadd_update_type(deserializer);
+    return SpaceHierarchyRoom(
+      roomId: var_roomId,
+      name: var_name,
+      topic: var_topic,
+      avatarUrl: var_avatarUrl,
+      canonicalAlias: var_canonicalAlias,
+      numJoinedMembers: var_numJoinedMembers,
+      isSpace: var_isSpace,
+      suggested: var_suggested,
+      children: var_children,
+      membership: var_membership,
     );
   }
 
   @protected
-  LogLevel dco_decode_log_level(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LogLevel.values[raw as int];
+  TraceLogPacks sse_decode_trace_log_packs(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TraceLogPacks.values[inner];
   }
 
   @protected
-  Message dco_decode_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return Message(
-      eventId: dco_decode_String(arr[0]),
-      sender: dco_decode_String(arr[1]),
-      content: dco_decode_String(arr[2]),
-      timestamp: dco_decode_u_64(arr[3]),
-      messageType: dco_decode_message_type(arr[4]),
+  TracingConfiguration sse_decode_tracing_configuration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_logLevel = sse_decode_log_level(deserializer);
+    var var_traceLogPacks = sse_decode_list_trace_log_packs(deserializer);
+    var var_extraTargets = sse_decode_list_String(deserializer);
+    var var_writeToStdoutOrSystem = sse_decode_bool(deserializer);
+    var var_writeToFiles =
+        sse_decode_opt_box_autoadd_tracing_file_configuration(deserializer);
+    return TracingConfiguration(
+      logLevel: var_logLevel,
+      traceLogPacks: var_traceLogPacks,
+      extraTargets: var_extraTargets,
+      writeToStdoutOrSystem: var_writeToStdoutOrSystem,
+      writeToFiles: var_writeToFiles,
     );
   }
 
   @protected
-  MessageType dco_decode_message_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MessageType.values[raw as int];
-  }
-
-  @protected
-  MessageUpdate dco_decode_message_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return MessageUpdate(
-      messageUpdateType: dco_decode_message_update_type(arr[0]),
-      messages: dco_decode_opt_list_message(arr[1]),
-      index: dco_decode_opt_box_autoadd_usize(arr[2]),
-      length: dco_decode_opt_box_autoadd_usize(arr[3]),
+  TracingFileConfiguration sse_decode_tracing_file_configuration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_filePrefix = sse_decode_String(deserializer);
+    var var_fileSuffix = sse_decode_opt_String(deserializer);
+    var var_maxFiles = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return TracingFileConfiguration(
+      path: var_path,
+      filePrefix: var_filePrefix,
+      fileSuffix: var_fileSuffix,
+      maxFiles: var_maxFiles,
     );
   }
 
   @protected
-  MessageUpdateType dco_decode_message_update_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MessageUpdateType.values[raw as int];
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  Message? dco_decode_opt_box_autoadd_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_message(raw);
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  TracingFileConfiguration?
-  dco_decode_opt_box_autoadd_tracing_file_configuration(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_tracing_file_configuration(raw);
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  UpdateType sse_decode_update_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UpdateType.values[inner];
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  User sse_decode_user(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_displayName = sse_decode_opt_String(deserializer);
+    var var_avatarUrl = sse_decode_opt_String(deserializer);
+    return User(
+      userId: var_userId,
+      displayName: var_displayName,
+      avatarUrl: var_avatarUrl,
+    );
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  UserPowerLevel sse_decode_user_power_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_powerLevel = sse_decode_i_64(deserializer);
+    return UserPowerLevel(userId: var_userId, powerLevel: var_powerLevel);
   }
 
   @protected
-  List<Certificate>?
-  dco_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-          raw,
-        );
+  UserPresence sse_decode_user_presence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_presence = sse_decode_presence_kind(deserializer);
+    var var_lastActive = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_currentlyActive = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_statusMessage = sse_decode_opt_String(deserializer);
+    return UserPresence(
+      userId: var_userId,
+      presence: var_presence,
+      lastActive: var_lastActive,
+      currentlyActive: var_currentlyActive,
+      statusMessage: var_statusMessage,
+    );
   }
 
   @protected
-  List<Message>? dco_decode_opt_list_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_message(raw);
+  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_users = sse_decode_list_user(deserializer);
+    var var_limited = sse_decode_bool(deserializer);
+    return UserSearchResult(users: var_users, limited: var_limited);
   }
 
   @protected
-  RoomUpdate dco_decode_room_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return RoomUpdate(
-      roomId: dco_decode_String(arr[0]),
-      rawName: dco_decode_opt_String(arr[1]),
-      displayName: dco_decode_opt_String(arr[2]),
-      isDm: dco_decode_opt_box_autoadd_bool(arr[3]),
-      updateType: dco_decode_update_type(arr[4]),
-      unreadNotifications: dco_decode_opt_box_autoadd_u_64(arr[5]),
-      unreadHighlight: dco_decode_opt_box_autoadd_u_64(arr[6]),
-      unreadMentions: dco_decode_opt_box_autoadd_u_64(arr[7]),
-      unreadMessages: dco_decode_opt_box_autoadd_u_64(arr[8]),
-      message: dco_decode_opt_box_autoadd_message(arr[9]),
-    );
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  TraceLogPacks dco_decode_trace_log_packs(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TraceLogPacks.values[raw as int];
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  TracingConfiguration dco_decode_tracing_configuration(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return TracingConfiguration(
-      logLevel: dco_decode_log_level(arr[0]),
-      traceLogPacks: dco_decode_list_trace_log_packs(arr[1]),
-      extraTargets: dco_decode_list_String(arr[2]),
-      writeToStdoutOrSystem: dco_decode_bool(arr[3]),
-      writeToFiles: dco_decode_opt_box_autoadd_tracing_file_configuration(
-        arr[4],
-      ),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    Certificate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CertificateImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  TracingFileConfiguration dco_decode_tracing_file_configuration(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return TracingFileConfiguration(
-      path: dco_decode_String(arr[0]),
-      filePrefix: dco_decode_String(arr[1]),
-      fileSuffix: dco_decode_opt_String(arr[2]),
-      maxFiles: dco_decode_opt_box_autoadd_u_64(arr[3]),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    FieldsFormatterForFiles self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as FieldsFormatterForFilesImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    Certificate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CertificateImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    FieldsFormatterForFiles self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as FieldsFormatterForFilesImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_list_knock_request_info_Sse(
+    RustStreamSink<List<KnockRequestInfo>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_knock_request_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_StreamSink_list_message_update_Sse(
+    RustStreamSink<List<MessageUpdate>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  UpdateType dco_decode_update_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UpdateType.values[raw as int];
+  void sse_encode_StreamSink_list_read_receipt_Sse(
+    RustStreamSink<List<ReadReceipt>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_read_receipt,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  User dco_decode_user(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return User(
-      userId: dco_decode_String(arr[0]),
-      displayName: dco_decode_opt_String(arr[1]),
-      avatarUrl: dco_decode_opt_String(arr[2]),
+  void sse_encode_StreamSink_list_room_list_update_Sse(
+    RustStreamSink<List<RoomListUpdate>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_room_list_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  UserSearchResult dco_decode_user_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return UserSearchResult(
-      users: dco_decode_list_user(arr[0]),
-      limited: dco_decode_bool(arr[1]),
+  void sse_encode_StreamSink_list_room_members_update_Sse(
+    RustStreamSink<List<RoomMembersUpdate>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_room_members_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_StreamSink_list_user_Sse(
+    RustStreamSink<List<User>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_user,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  Certificate
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    SseDeserializer deserializer,
+  void sse_encode_StreamSink_pagination_status_Sse(
+    RustStreamSink<PaginationStatus> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return CertificateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pagination_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  FieldsFormatterForFiles
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    SseDeserializer deserializer,
+  void sse_encode_StreamSink_user_presence_Sse(
+    RustStreamSink<UserPresence> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return FieldsFormatterForFilesImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user_presence,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  Certificate
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return CertificateImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  FieldsFormatterForFiles
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return FieldsFormatterForFilesImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  RustStreamSink<MessageUpdate> sse_decode_StreamSink_message_update_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_client_config(
+    ClientConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_client_config(self, serializer);
   }
 
   @protected
-  RustStreamSink<RoomUpdate> sse_decode_StreamSink_room_update_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_create_room_params(
+    CreateRoomParams self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_create_room_params(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
+    sse_encode_i_8(self, serializer);
   }
 
   @protected
-  ClientConfig sse_decode_box_autoadd_client_config(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_message(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_power_level_action(
+    PowerLevelAction self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_client_config(deserializer));
+    sse_encode_power_level_action(self, serializer);
   }
 
   @protected
-  Message sse_decode_box_autoadd_message(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_power_level_overrides(
+    PowerLevelOverrides self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_message(deserializer));
+    sse_encode_power_level_overrides(self, serializer);
   }
 
   @protected
-  TracingConfiguration sse_decode_box_autoadd_tracing_configuration(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_read_receipt(
+    ReadReceipt self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_tracing_configuration(deserializer));
+    sse_encode_read_receipt(self, serializer);
   }
 
   @protected
-  TracingFileConfiguration sse_decode_box_autoadd_tracing_file_configuration(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_room_history_visibility(
+    RoomHistoryVisibility self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_tracing_file_configuration(deserializer));
+    sse_encode_room_history_visibility(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_room_join_rule(
+    RoomJoinRule self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_room_join_rule(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_room_list_filter_kind(
+    RoomListFilterKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_room_list_filter_kind(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_room_successor(
+    RoomSuccessor self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
+    sse_encode_room_successor(self, serializer);
   }
 
   @protected
-  ClientConfig sse_decode_client_config(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_tracing_configuration(
+    TracingConfiguration self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sessionPath = sse_decode_String(deserializer);
-    var var_homeserverUrl = sse_decode_String(deserializer);
-    var var_rootCertificates =
-        sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-          deserializer,
-        );
-    var var_proxy = sse_decode_opt_String(deserializer);
-    return ClientConfig(
-      sessionPath: var_sessionPath,
-      homeserverUrl: var_homeserverUrl,
-      rootCertificates: var_rootCertificates,
-      proxy: var_proxy,
-    );
+    sse_encode_tracing_configuration(self, serializer);
   }
 
   @protected
-  ClientError sse_decode_client_error(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_tracing_file_configuration(
+    TracingFileConfiguration self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tracing_file_configuration(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_msg = sse_decode_String(deserializer);
-        var var_details = sse_decode_opt_String(deserializer);
-        return ClientError_Generic(msg: var_msg, details: var_details);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  List<Certificate>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_update_type(
+    UpdateType self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Certificate>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-          deserializer,
-        ),
-      );
-    }
-    return ans_;
+    sse_encode_update_type(self, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_user(User self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_user(self, serializer);
   }
 
   @protected
-  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Message>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_message(deserializer));
-    }
-    return ans_;
+    sse_encode_usize(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_client_config(ClientConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_String(self.sessionPath, serializer);
+    sse_encode_String(self.homeserverUrl, serializer);
+    sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+      self.rootCertificates,
+      serializer,
+    );
+    sse_encode_opt_String(self.proxy, serializer);
   }
 
   @protected
-  List<RoomUpdate> sse_decode_list_room_update(SseDeserializer deserializer) {
+  void sse_encode_client_error(ClientError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RoomUpdate>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_room_update(deserializer));
+    switch (self) {
+      case ClientError_Generic(msg: final msg, details: final details):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(msg, serializer);
+        sse_encode_opt_String(details, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<TraceLogPacks> sse_decode_list_trace_log_packs(
-    SseDeserializer deserializer,
+  void sse_encode_create_room_params(
+    CreateRoomParams self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TraceLogPacks>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_trace_log_packs(deserializer));
-    }
-    return ans_;
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_opt_String(self.alias, serializer);
+    sse_encode_room_visibility(self.visibility, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+    sse_encode_opt_box_autoadd_room_history_visibility(
+      self.historyVisibility,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_bool(self.guestAccess, serializer);
+    sse_encode_opt_box_autoadd_power_level_overrides(
+      self.powerLevelOverrides,
+      serializer,
+    );
+    sse_encode_opt_String(self.roomVersion, serializer);
+    sse_encode_opt_String(self.spaceParentId, serializer);
+    sse_encode_list_String(self.invite, serializer);
+    sse_encode_bool(self.isDirect, serializer);
+    sse_encode_bool(self.isSpace, serializer);
   }
 
   @protected
-  List<User> sse_decode_list_user(SseDeserializer deserializer) {
+  void sse_encode_event_power_level(
+    EventPowerLevel self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <User>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_user(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.eventType, serializer);
+    sse_encode_i_64(self.powerLevel, serializer);
   }
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_level = sse_decode_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_timestamp = sse_decode_i_64(deserializer);
-    var var_tag = sse_decode_String(deserializer);
-    return LogEntry(
-      level: var_level,
-      message: var_message,
-      timestamp: var_timestamp,
-      tag: var_tag,
-    );
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LogLevel.values[inner];
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  Message sse_decode_message(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_eventId = sse_decode_String(deserializer);
-    var var_sender = sse_decode_String(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_timestamp = sse_decode_u_64(deserializer);
-    var var_messageType = sse_decode_message_type(deserializer);
-    return Message(
-      eventId: var_eventId,
-      sender: var_sender,
-      content: var_content,
-      timestamp: var_timestamp,
-      messageType: var_messageType,
-    );
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  MessageType sse_decode_message_type(SseDeserializer deserializer) {
+  void sse_encode_i_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MessageType.values[inner];
+    serializer.buffer.putInt8(self);
   }
 
   @protected
-  MessageUpdate sse_decode_message_update(SseDeserializer deserializer) {
+  void sse_encode_invite_details(InviteDetails self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_messageUpdateType = sse_decode_message_update_type(deserializer);
-    var var_messages = sse_decode_opt_list_message(deserializer);
-    var var_index = sse_decode_opt_box_autoadd_usize(deserializer);
-    var var_length = sse_decode_opt_box_autoadd_usize(deserializer);
-    return MessageUpdate(
-      messageUpdateType: var_messageUpdateType,
-      messages: var_messages,
-      index: var_index,
-      length: var_length,
-    );
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.roomName, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_u_64(self.memberCount, serializer);
+    sse_encode_bool(self.isDm, serializer);
+    sse_encode_opt_box_autoadd_user(self.inviter, serializer);
   }
 
   @protected
-  MessageUpdateType sse_decode_message_update_type(
-    SseDeserializer deserializer,
+  void sse_encode_knock_request_info(
+    KnockRequestInfo self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MessageUpdateType.values[inner];
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_String(self.userId, serializer);
+    sse_encode_opt_String(self.displayName, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_opt_String(self.reason, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timestamp, serializer);
+    sse_encode_bool(self.isSeen, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    List<Certificate> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+        item,
+        serializer,
+      );
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
   }
 
   @protected
-  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer) {
+  void sse_encode_list_event_power_level(
+    List<EventPowerLevel> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_message(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_power_level(item, serializer);
     }
   }
 
   @protected
-  TracingFileConfiguration?
-  sse_decode_opt_box_autoadd_tracing_file_configuration(
-    SseDeserializer deserializer,
+  void sse_encode_list_invite_details(
+    List<InviteDetails> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_invite_details(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_tracing_file_configuration(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_knock_request_info(
+    List<KnockRequestInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_knock_request_info(item, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_message_update(
+    List<MessageUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_update(item, serializer);
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_public_room(
+    List<PublicRoom> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_public_room(item, serializer);
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_list_read_receipt(
+    List<ReadReceipt> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_read_receipt(item, serializer);
     }
   }
 
   @protected
-  List<Certificate>?
-  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    SseDeserializer deserializer,
+  void sse_encode_list_room_list_filter_kind(
+    List<RoomListFilterKind> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_list_filter_kind(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-        deserializer,
-      ));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_room_list_update(
+    List<RoomListUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_list_update(item, serializer);
     }
   }
 
   @protected
-  List<Message>? sse_decode_opt_list_message(SseDeserializer deserializer) {
+  void sse_encode_list_room_member_info(
+    List<RoomMemberInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_member_info(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_message(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_room_members_update(
+    List<RoomMembersUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_members_update(item, serializer);
     }
   }
 
   @protected
-  RoomUpdate sse_decode_room_update(SseDeserializer deserializer) {
+  void sse_encode_list_room_tag(List<RoomTag> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_roomId = sse_decode_String(deserializer);
-    var var_rawName = sse_decode_opt_String(deserializer);
-    var var_displayName = sse_decode_opt_String(deserializer);
-    var var_isDm = sse_decode_opt_box_autoadd_bool(deserializer);
-    var var_updateType = sse_decode_update_type(deserializer);
-    var var_unreadNotifications = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_unreadHighlight = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_unreadMentions = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_unreadMessages = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_message = sse_decode_opt_box_autoadd_message(deserializer);
-    return RoomUpdate(
-      roomId: var_roomId,
-      rawName: var_rawName,
-      displayName: var_displayName,
-      isDm: var_isDm,
-      updateType: var_updateType,
-      unreadNotifications: var_unreadNotifications,
-      unreadHighlight: var_unreadHighlight,
-      unreadMentions: var_unreadMentions,
-      unreadMessages: var_unreadMessages,
-      message: var_message,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_tag(item, serializer);
+    }
   }
 
   @protected
-  TraceLogPacks sse_decode_trace_log_packs(SseDeserializer deserializer) {
+  void sse_encode_list_room_update(
+    List<RoomUpdate> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return TraceLogPacks.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_update(item, serializer);
+    }
   }
 
   @protected
-  TracingConfiguration sse_decode_tracing_configuration(
-    SseDeserializer deserializer,
+  void sse_encode_list_space_hierarchy_room(
+    List<SpaceHierarchyRoom> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_logLevel = sse_decode_log_level(deserializer);
-    var var_traceLogPacks = sse_decode_list_trace_log_packs(deserializer);
-    var var_extraTargets = sse_decode_list_String(deserializer);
-    var var_writeToStdoutOrSystem = sse_decode_bool(deserializer);
-    var var_writeToFiles =
-        sse_decode_opt_box_autoadd_tracing_file_configuration(deserializer);
-    return TracingConfiguration(
-      logLevel: var_logLevel,
-      traceLogPacks: var_traceLogPacks,
-      extraTargets: var_extraTargets,
-      writeToStdoutOrSystem: var_writeToStdoutOrSystem,
-      writeToFiles: var_writeToFiles,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_space_hierarchy_room(item, serializer);
+    }
   }
 
   @protected
-  TracingFileConfiguration sse_decode_tracing_file_configuration(
-    SseDeserializer deserializer,
+  void sse_encode_list_trace_log_packs(
+    List<TraceLogPacks> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_filePrefix = sse_decode_String(deserializer);
-    var var_fileSuffix = sse_decode_opt_String(deserializer);
-    var var_maxFiles = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return TracingFileConfiguration(
-      path: var_path,
-      filePrefix: var_filePrefix,
-      fileSuffix: var_fileSuffix,
-      maxFiles: var_maxFiles,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trace_log_packs(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_list_user(List<User> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_user(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_user_power_level(
+    List<UserPowerLevel> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_user_power_level(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.level, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_String(self.tag, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_member_membership(
+    MemberMembership self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  UpdateType sse_decode_update_type(SseDeserializer deserializer) {
+  void sse_encode_member_role(MemberRole self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return UpdateType.values[inner];
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  User sse_decode_user(SseDeserializer deserializer) {
+  void sse_encode_message(Message self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_userId = sse_decode_String(deserializer);
-    var var_displayName = sse_decode_opt_String(deserializer);
-    var var_avatarUrl = sse_decode_opt_String(deserializer);
-    return User(
-      userId: var_userId,
-      displayName: var_displayName,
-      avatarUrl: var_avatarUrl,
-    );
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_String(self.sender, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_message_type(self.messageType, serializer);
+    sse_encode_list_read_receipt(self.readReceipts, serializer);
   }
 
   @protected
-  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer) {
+  void sse_encode_message_type(MessageType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_users = sse_decode_list_user(deserializer);
-    var var_limited = sse_decode_bool(deserializer);
-    return UserSearchResult(users: var_users, limited: var_limited);
+    switch (self) {
+      case MessageType_Message():
+        sse_encode_i_32(0, serializer);
+      case MessageType_DateDivider():
+        sse_encode_i_32(1, serializer);
+      case MessageType_ReadMarker(ownReceiptEventId: final ownReceiptEventId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_opt_String(ownReceiptEventId, serializer);
+      case MessageType_TimelineStart():
+        sse_encode_i_32(3, serializer);
+      case MessageType_Tombstone(replacementRoomId: final replacementRoomId):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(replacementRoomId, serializer);
+      case MessageType_Predecessor(predecessorRoomId: final predecessorRoomId):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(predecessorRoomId, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_message_update(MessageUpdate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_message_update_type(self.messageUpdateType, serializer);
+    sse_encode_opt_list_message(self.messages, serializer);
+    sse_encode_opt_box_autoadd_usize(self.index, serializer);
+    sse_encode_opt_box_autoadd_usize(self.length, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_message_update_type(
+    MessageUpdateType self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    Certificate self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CertificateImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    FieldsFormatterForFiles self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as FieldsFormatterForFilesImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    Certificate self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CertificateImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    FieldsFormatterForFiles self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as FieldsFormatterForFilesImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_log_entry_Sse(
-    RustStreamSink<LogEntry> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_i_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_log_entry,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_8(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_message_update_Sse(
-    RustStreamSink<MessageUpdate> self,
+  void sse_encode_opt_box_autoadd_message(
+    Message? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_message_update,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_message(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_room_update_Sse(
-    RustStreamSink<RoomUpdate> self,
+  void sse_encode_opt_box_autoadd_power_level_overrides(
+    PowerLevelOverrides? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_room_update,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_power_level_overrides(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_read_receipt(
+    ReadReceipt? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_read_receipt(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_client_config(
-    ClientConfig self,
+  void sse_encode_opt_box_autoadd_room_history_visibility(
+    RoomHistoryVisibility? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_client_config(self, serializer);
-  }
 
-  @protected
-  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_message(self, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_room_history_visibility(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_tracing_configuration(
-    TracingConfiguration self,
+  void sse_encode_opt_box_autoadd_room_join_rule(
+    RoomJoinRule? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_tracing_configuration(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_room_join_rule(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_tracing_file_configuration(
-    TracingFileConfiguration self,
+  void sse_encode_opt_box_autoadd_room_successor(
+    RoomSuccessor? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_tracing_file_configuration(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_room_successor(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_tracing_file_configuration(
+    TracingFileConfiguration? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_tracing_file_configuration(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_client_config(ClientConfig self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.sessionPath, serializer);
-    sse_encode_String(self.homeserverUrl, serializer);
-    sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-      self.rootCertificates,
-      serializer,
-    );
-    sse_encode_opt_String(self.proxy, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_client_error(ClientError self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_update_type(
+    UpdateType? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ClientError_Generic(msg: final msg, details: final details):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(msg, serializer);
-        sse_encode_opt_String(details, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_update_type(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_user(User? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_user(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_usize(
+    BigInt? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
+    }
   }
 
   @protected
   void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    List<Certificate> self,
+  sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    List<Certificate>? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-        item,
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+        self,
         serializer,
       );
     }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_opt_list_message(
+    List<Message>? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_message(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
+  void sse_encode_opt_list_room_update(
+    List<RoomUpdate>? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_message(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_room_update(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_pagination_status(
+    PaginationStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_room_update(
-    List<RoomUpdate> self,
+  void sse_encode_power_level_action(
+    PowerLevelAction self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_room_update(item, serializer);
+    switch (self) {
+      case PowerLevelAction_Ban():
+        sse_encode_i_32(0, serializer);
+      case PowerLevelAction_Invite():
+        sse_encode_i_32(1, serializer);
+      case PowerLevelAction_Kick():
+        sse_encode_i_32(2, serializer);
+      case PowerLevelAction_Redact():
+        sse_encode_i_32(3, serializer);
+      case PowerLevelAction_EventsDefault():
+        sse_encode_i_32(4, serializer);
+      case PowerLevelAction_StateDefault():
+        sse_encode_i_32(5, serializer);
+      case PowerLevelAction_UsersDefault():
+        sse_encode_i_32(6, serializer);
+      case PowerLevelAction_Event(eventType: final eventType):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(eventType, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_trace_log_packs(
-    List<TraceLogPacks> self,
+  void sse_encode_power_level_overrides(
+    PowerLevelOverrides self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_trace_log_packs(item, serializer);
-    }
+    sse_encode_opt_box_autoadd_i_64(self.ban, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.invite, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.kick, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.redact, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.eventsDefault, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.stateDefault, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.usersDefault, serializer);
+    sse_encode_list_event_power_level(self.events, serializer);
+    sse_encode_list_user_power_level(self.users, serializer);
   }
 
   @protected
-  void sse_encode_list_user(List<User> self, SseSerializer serializer) {
+  void sse_encode_presence_kind(PresenceKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_user(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+  void sse_encode_public_room(PublicRoom self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.level, serializer);
-    sse_encode_String(self.message, serializer);
-    sse_encode_i_64(self.timestamp, serializer);
-    sse_encode_String(self.tag, serializer);
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.canonicalAlias, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_u_64(self.numJoinedMembers, serializer);
+    sse_encode_bool(self.worldReadable, serializer);
+    sse_encode_bool(self.guestCanJoin, serializer);
+    sse_encode_bool(self.isSpace, serializer);
   }
 
   @protected
-  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+  void sse_encode_public_rooms_page(
+    PublicRoomsPage self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_list_public_room(self.rooms, serializer);
+    sse_encode_opt_String(self.nextBatch, serializer);
+    sse_encode_opt_String(self.prevBatch, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalRoomCountEstimate, serializer);
   }
 
   @protected
-  void sse_encode_message(Message self, SseSerializer serializer) {
+  void sse_encode_read_receipt(ReadReceipt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
     sse_encode_String(self.eventId, serializer);
-    sse_encode_String(self.sender, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_u_64(self.timestamp, serializer);
-    sse_encode_message_type(self.messageType, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timestamp, serializer);
   }
 
   @protected
-  void sse_encode_message_type(MessageType self, SseSerializer serializer) {
+  void sse_encode_receipt_kind(ReceiptKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_message_update(MessageUpdate self, SseSerializer serializer) {
+  void sse_encode_room_aliases(RoomAliases self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_message_update_type(self.messageUpdateType, serializer);
-    sse_encode_opt_list_message(self.messages, serializer);
-    sse_encode_opt_box_autoadd_usize(self.index, serializer);
-    sse_encode_opt_box_autoadd_usize(self.length, serializer);
+    sse_encode_opt_String(self.canonicalAlias, serializer);
+    sse_encode_list_String(self.altAliases, serializer);
   }
 
   @protected
-  void sse_encode_message_update_type(
-    MessageUpdateType self,
+  void sse_encode_room_history_visibility(
+    RoomHistoryVisibility self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2667,112 +8914,212 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_room_join_rule(RoomJoinRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
+    switch (self) {
+      case RoomJoinRule_Public():
+        sse_encode_i_32(0, serializer);
+      case RoomJoinRule_Invite():
+        sse_encode_i_32(1, serializer);
+      case RoomJoinRule_Knock():
+        sse_encode_i_32(2, serializer);
+      case RoomJoinRule_Restricted(allowedRoomIds: final allowedRoomIds):
+        sse_encode_i_32(3, serializer);
+        sse_encode_list_String(allowedRoomIds, serializer);
+      case RoomJoinRule_KnockRestricted(allowedRoomIds: final allowedRoomIds):
+        sse_encode_i_32(4, serializer);
+        sse_encode_list_String(allowedRoomIds, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_room_list_filter_category(
+    RoomListFilterCategory self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_message(
-    Message? self,
+  void sse_encode_room_list_filter_kind(
+    RoomListFilterKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_message(self, serializer);
+    switch (self) {
+      case RoomListFilterKind_All(filters: final filters):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_room_list_filter_kind(filters, serializer);
+      case RoomListFilterKind_Any(filters: final filters):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_room_list_filter_kind(filters, serializer);
+      case RoomListFilterKind_NonSpace():
+        sse_encode_i_32(2, serializer);
+      case RoomListFilterKind_NonLeft():
+        sse_encode_i_32(3, serializer);
+      case RoomListFilterKind_Joined():
+        sse_encode_i_32(4, serializer);
+      case RoomListFilterKind_Unread():
+        sse_encode_i_32(5, serializer);
+      case RoomListFilterKind_Favourite():
+        sse_encode_i_32(6, serializer);
+      case RoomListFilterKind_LowPriority():
+        sse_encode_i_32(7, serializer);
+      case RoomListFilterKind_NonLowPriority():
+        sse_encode_i_32(8, serializer);
+      case RoomListFilterKind_Invite():
+        sse_encode_i_32(9, serializer);
+      case RoomListFilterKind_Category(expect: final expect):
+        sse_encode_i_32(10, serializer);
+        sse_encode_room_list_filter_category(expect, serializer);
+      case RoomListFilterKind_None():
+        sse_encode_i_32(11, serializer);
+      case RoomListFilterKind_NormalizedMatchRoomName(pattern: final pattern):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(pattern, serializer);
+      case RoomListFilterKind_FuzzyMatchRoomName(pattern: final pattern):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(pattern, serializer);
+      case RoomListFilterKind_DeduplicateVersions():
+        sse_encode_i_32(14, serializer);
+      case RoomListFilterKind_Tag(name: final name):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(name, serializer);
+      case RoomListFilterKind_Space(spaceId: final spaceId):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(spaceId, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_tracing_file_configuration(
-    TracingFileConfiguration? self,
+  void sse_encode_room_list_update(
+    RoomListUpdate self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_tracing_file_configuration(self, serializer);
-    }
+    sse_encode_room_list_update_type(self.updateType, serializer);
+    sse_encode_opt_list_room_update(self.rooms, serializer);
+    sse_encode_opt_box_autoadd_usize(self.index, serializer);
+    sse_encode_opt_box_autoadd_usize(self.length, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_room_list_update_type(
+    RoomListUpdateType self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+  void sse_encode_room_member_info(
+    RoomMemberInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
+    sse_encode_String(self.userId, serializer);
+    sse_encode_opt_String(self.displayName, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_member_membership(self.membership, serializer);
+    sse_encode_i_64(self.powerLevel, serializer);
+    sse_encode_member_role(self.role, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(
-    BigInt? self,
+  void sse_encode_room_members_page(
+    RoomMembersPage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_room_member_info(self.members, serializer);
+    sse_encode_u_32(self.total, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
+  @protected
+  void sse_encode_room_members_update(
+    RoomMembersUpdate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case RoomMembersUpdate_Reset(members: final members):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_room_member_info(members, serializer);
+      case RoomMembersUpdate_Upsert(member: final member):
+        sse_encode_i_32(1, serializer);
+        sse_encode_room_member_info(member, serializer);
+      case RoomMembersUpdate_Remove(userId: final userId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(userId, serializer);
     }
   }
 
   @protected
-  void
-  sse_encode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    List<Certificate>? self,
+  void sse_encode_room_permissions(
+    RoomPermissions self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.ownPowerLevel, serializer);
+    sse_encode_bool(self.canSendMessage, serializer);
+    sse_encode_bool(self.canRedactOwn, serializer);
+    sse_encode_bool(self.canRedactOthers, serializer);
+    sse_encode_bool(self.canInvite, serializer);
+    sse_encode_bool(self.canKick, serializer);
+    sse_encode_bool(self.canBan, serializer);
+    sse_encode_bool(self.canChangeSettings, serializer);
+    sse_encode_bool(self.canChangePowerLevels, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-        self,
-        serializer,
-      );
-    }
+  @protected
+  void sse_encode_room_power_levels_info(
+    RoomPowerLevelsInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.ban, serializer);
+    sse_encode_i_64(self.invite, serializer);
+    sse_encode_i_64(self.kick, serializer);
+    sse_encode_i_64(self.redact, serializer);
+    sse_encode_i_64(self.eventsDefault, serializer);
+    sse_encode_i_64(self.stateDefault, serializer);
+    sse_encode_i_64(self.usersDefault, serializer);
+    sse_encode_list_event_power_level(self.events, serializer);
+    sse_encode_list_user_power_level(self.users, serializer);
   }
 
   @protected
-  void sse_encode_opt_list_message(
-    List<Message>? self,
+  void sse_encode_room_preview_info(
+    RoomPreviewInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.canonicalAlias, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_u_64(self.numJoinedMembers, serializer);
+    sse_encode_bool(self.isSpace, serializer);
+    sse_encode_opt_box_autoadd_bool(self.isWorldReadable, serializer);
+    sse_encode_opt_box_autoadd_bool(self.isDirect, serializer);
+    sse_encode_opt_box_autoadd_update_type(self.membership, serializer);
+  }
+
+  @protected
+  void sse_encode_room_successor(RoomSuccessor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.reason, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_message(self, serializer);
-    }
+  @protected
+  void sse_encode_room_tag(RoomTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.order, serializer);
   }
 
   @protected
@@ -2787,7 +9134,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.unreadHighlight, serializer);
     sse_encode_opt_box_autoadd_u_64(self.unreadMentions, serializer);
     sse_encode_opt_box_autoadd_u_64(self.unreadMessages, serializer);
+    sse_encode_bool(self.isMarkedUnread, serializer);
     sse_encode_opt_box_autoadd_message(self.message, serializer);
+    sse_encode_list_room_tag(self.tags, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_opt_String(self.canonicalAlias, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+    sse_encode_opt_box_autoadd_room_history_visibility(
+      self.historyVisibility,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_room_join_rule(self.joinRule, serializer);
+    sse_encode_opt_box_autoadd_room_successor(self.successor, serializer);
+    sse_encode_opt_String(self.predecessorRoomId, serializer);
+  }
+
+  @protected
+  void sse_encode_room_visibility(
+    RoomVisibility self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_space_hierarchy_page(
+    SpaceHierarchyPage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_space_hierarchy_room(self.rooms, serializer);
+    sse_encode_opt_String(self.nextBatch, serializer);
+  }
+
+  @protected
+  void sse_encode_space_hierarchy_room(
+    SpaceHierarchyRoom self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_opt_String(self.canonicalAlias, serializer);
+    sse_encode_u_64(self.numJoinedMembers, serializer);
+    sse_encode_bool(self.isSpace, serializer);
+    sse_encode_bool(self.suggested, serializer);
+    sse_encode_list_String(self.children, serializer);
+    sse_encode_opt_box_autoadd_update_type(self.membership, serializer);
   }
 
   @protected
@@ -2870,6 +9267,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.avatarUrl, serializer);
   }
 
+  @protected
+  void sse_encode_user_power_level(
+    UserPowerLevel self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
+    sse_encode_i_64(self.powerLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_user_presence(UserPresence self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
+    sse_encode_presence_kind(self.presence, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastActive, serializer);
+    sse_encode_opt_box_autoadd_bool(self.currentlyActive, serializer);
+    sse_encode_opt_String(self.statusMessage, serializer);
+  }
+
   @protected
   void sse_encode_user_search_result(
     UserSearchResult self,
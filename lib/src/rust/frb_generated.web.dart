@@ -15,9 +15,26 @@ import 'dart:convert';
 import 'frb_generated.dart';
 import 'matrix/authentication.dart';
 import 'matrix/client.dart';
+import 'matrix/direct_messages.dart';
+import 'matrix/directory.dart';
+import 'matrix/ignored_users.dart';
+import 'matrix/invites.dart';
+import 'matrix/joining.dart';
+import 'matrix/knocking.dart';
+import 'matrix/members.dart';
+import 'matrix/moderation.dart';
+import 'matrix/presence.dart';
+import 'matrix/profile.dart';
+import 'matrix/receipts.dart';
+import 'matrix/reporting.dart';
+import 'matrix/room_list.dart';
+import 'matrix/room_settings.dart';
 import 'matrix/rooms.dart';
+import 'matrix/spaces.dart';
 import 'matrix/sync_service.dart';
+import 'matrix/tags.dart';
 import 'matrix/timelines.dart';
+import 'matrix/typing.dart';
 import 'matrix/user_serach.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'third_party/reqwest/tls.dart';
@@ -65,16 +82,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<List<KnockRequestInfo>>
+  dco_decode_StreamSink_list_knock_request_info_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<MessageUpdate>>
+  dco_decode_StreamSink_list_message_update_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<ReadReceipt>> dco_decode_StreamSink_list_read_receipt_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<List<RoomListUpdate>>
+  dco_decode_StreamSink_list_room_list_update_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<RoomMembersUpdate>>
+  dco_decode_StreamSink_list_room_members_update_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<User>> dco_decode_StreamSink_list_user_Sse(dynamic raw);
+
   @protected
   RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
 
   @protected
-  RustStreamSink<MessageUpdate> dco_decode_StreamSink_message_update_Sse(
+  RustStreamSink<PaginationStatus> dco_decode_StreamSink_pagination_status_Sse(
     dynamic raw,
   );
 
   @protected
-  RustStreamSink<RoomUpdate> dco_decode_StreamSink_room_update_Sse(dynamic raw);
+  RustStreamSink<UserPresence> dco_decode_StreamSink_user_presence_Sse(
+    dynamic raw,
+  );
 
   @protected
   String dco_decode_String(dynamic raw);
@@ -88,9 +131,44 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClientConfig dco_decode_box_autoadd_client_config(dynamic raw);
 
+  @protected
+  CreateRoomParams dco_decode_box_autoadd_create_room_params(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_8(dynamic raw);
+
   @protected
   Message dco_decode_box_autoadd_message(dynamic raw);
 
+  @protected
+  PowerLevelAction dco_decode_box_autoadd_power_level_action(dynamic raw);
+
+  @protected
+  PowerLevelOverrides dco_decode_box_autoadd_power_level_overrides(dynamic raw);
+
+  @protected
+  ReadReceipt dco_decode_box_autoadd_read_receipt(dynamic raw);
+
+  @protected
+  RoomHistoryVisibility dco_decode_box_autoadd_room_history_visibility(
+    dynamic raw,
+  );
+
+  @protected
+  RoomJoinRule dco_decode_box_autoadd_room_join_rule(dynamic raw);
+
+  @protected
+  RoomListFilterKind dco_decode_box_autoadd_room_list_filter_kind(dynamic raw);
+
+  @protected
+  RoomSuccessor dco_decode_box_autoadd_room_successor(dynamic raw);
+
   @protected
   TracingConfiguration dco_decode_box_autoadd_tracing_configuration(
     dynamic raw,
@@ -101,12 +179,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UpdateType dco_decode_box_autoadd_update_type(dynamic raw);
+
+  @protected
+  User dco_decode_box_autoadd_user(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
@@ -116,12 +203,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClientError dco_decode_client_error(dynamic raw);
 
+  @protected
+  CreateRoomParams dco_decode_create_room_params(dynamic raw);
+
+  @protected
+  EventPowerLevel dco_decode_event_power_level(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  int dco_decode_i_8(dynamic raw);
+
+  @protected
+  InviteDetails dco_decode_invite_details(dynamic raw);
+
+  @protected
+  KnockRequestInfo dco_decode_knock_request_info(dynamic raw);
+
   @protected
   List<Certificate>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
@@ -131,27 +236,72 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<EventPowerLevel> dco_decode_list_event_power_level(dynamic raw);
+
+  @protected
+  List<InviteDetails> dco_decode_list_invite_details(dynamic raw);
+
+  @protected
+  List<KnockRequestInfo> dco_decode_list_knock_request_info(dynamic raw);
+
   @protected
   List<Message> dco_decode_list_message(dynamic raw);
 
+  @protected
+  List<MessageUpdate> dco_decode_list_message_update(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<PublicRoom> dco_decode_list_public_room(dynamic raw);
+
+  @protected
+  List<ReadReceipt> dco_decode_list_read_receipt(dynamic raw);
+
+  @protected
+  List<RoomListFilterKind> dco_decode_list_room_list_filter_kind(dynamic raw);
+
+  @protected
+  List<RoomListUpdate> dco_decode_list_room_list_update(dynamic raw);
+
+  @protected
+  List<RoomMemberInfo> dco_decode_list_room_member_info(dynamic raw);
+
+  @protected
+  List<RoomMembersUpdate> dco_decode_list_room_members_update(dynamic raw);
+
+  @protected
+  List<RoomTag> dco_decode_list_room_tag(dynamic raw);
+
   @protected
   List<RoomUpdate> dco_decode_list_room_update(dynamic raw);
 
+  @protected
+  List<SpaceHierarchyRoom> dco_decode_list_space_hierarchy_room(dynamic raw);
+
   @protected
   List<TraceLogPacks> dco_decode_list_trace_log_packs(dynamic raw);
 
   @protected
   List<User> dco_decode_list_user(dynamic raw);
 
+  @protected
+  List<UserPowerLevel> dco_decode_list_user_power_level(dynamic raw);
+
   @protected
   LogEntry dco_decode_log_entry(dynamic raw);
 
   @protected
   LogLevel dco_decode_log_level(dynamic raw);
 
+  @protected
+  MemberMembership dco_decode_member_membership(dynamic raw);
+
+  @protected
+  MemberRole dco_decode_member_role(dynamic raw);
+
   @protected
   Message dco_decode_message(dynamic raw);
 
@@ -170,19 +320,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_8(dynamic raw);
+
   @protected
   Message? dco_decode_opt_box_autoadd_message(dynamic raw);
 
+  @protected
+  PowerLevelOverrides? dco_decode_opt_box_autoadd_power_level_overrides(
+    dynamic raw,
+  );
+
+  @protected
+  ReadReceipt? dco_decode_opt_box_autoadd_read_receipt(dynamic raw);
+
+  @protected
+  RoomHistoryVisibility? dco_decode_opt_box_autoadd_room_history_visibility(
+    dynamic raw,
+  );
+
+  @protected
+  RoomJoinRule? dco_decode_opt_box_autoadd_room_join_rule(dynamic raw);
+
+  @protected
+  RoomSuccessor? dco_decode_opt_box_autoadd_room_successor(dynamic raw);
+
   @protected
   TracingFileConfiguration?
   dco_decode_opt_box_autoadd_tracing_file_configuration(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UpdateType? dco_decode_opt_box_autoadd_update_type(dynamic raw);
+
+  @protected
+  User? dco_decode_opt_box_autoadd_user(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
 
@@ -195,9 +382,90 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Message>? dco_decode_opt_list_message(dynamic raw);
 
+  @protected
+  List<RoomUpdate>? dco_decode_opt_list_room_update(dynamic raw);
+
+  @protected
+  PaginationStatus dco_decode_pagination_status(dynamic raw);
+
+  @protected
+  PowerLevelAction dco_decode_power_level_action(dynamic raw);
+
+  @protected
+  PowerLevelOverrides dco_decode_power_level_overrides(dynamic raw);
+
+  @protected
+  PresenceKind dco_decode_presence_kind(dynamic raw);
+
+  @protected
+  PublicRoom dco_decode_public_room(dynamic raw);
+
+  @protected
+  PublicRoomsPage dco_decode_public_rooms_page(dynamic raw);
+
+  @protected
+  ReadReceipt dco_decode_read_receipt(dynamic raw);
+
+  @protected
+  ReceiptKind dco_decode_receipt_kind(dynamic raw);
+
+  @protected
+  RoomAliases dco_decode_room_aliases(dynamic raw);
+
+  @protected
+  RoomHistoryVisibility dco_decode_room_history_visibility(dynamic raw);
+
+  @protected
+  RoomJoinRule dco_decode_room_join_rule(dynamic raw);
+
+  @protected
+  RoomListFilterCategory dco_decode_room_list_filter_category(dynamic raw);
+
+  @protected
+  RoomListFilterKind dco_decode_room_list_filter_kind(dynamic raw);
+
+  @protected
+  RoomListUpdate dco_decode_room_list_update(dynamic raw);
+
+  @protected
+  RoomListUpdateType dco_decode_room_list_update_type(dynamic raw);
+
+  @protected
+  RoomMemberInfo dco_decode_room_member_info(dynamic raw);
+
+  @protected
+  RoomMembersPage dco_decode_room_members_page(dynamic raw);
+
+  @protected
+  RoomMembersUpdate dco_decode_room_members_update(dynamic raw);
+
+  @protected
+  RoomPermissions dco_decode_room_permissions(dynamic raw);
+
+  @protected
+  RoomPowerLevelsInfo dco_decode_room_power_levels_info(dynamic raw);
+
+  @protected
+  RoomPreviewInfo dco_decode_room_preview_info(dynamic raw);
+
+  @protected
+  RoomSuccessor dco_decode_room_successor(dynamic raw);
+
+  @protected
+  RoomTag dco_decode_room_tag(dynamic raw);
+
   @protected
   RoomUpdate dco_decode_room_update(dynamic raw);
 
+  @protected
+  RoomVisibility dco_decode_room_visibility(dynamic raw);
+
+  @protected
+  SpaceHierarchyPage dco_decode_space_hierarchy_page(dynamic raw);
+
+  @protected
+  SpaceHierarchyRoom dco_decode_space_hierarchy_room(dynamic raw);
+
   @protected
   TraceLogPacks dco_decode_trace_log_packs(dynamic raw);
 
@@ -228,6 +496,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   User dco_decode_user(dynamic raw);
 
+  @protected
+  UserPowerLevel dco_decode_user_power_level(dynamic raw);
+
+  @protected
+  UserPresence dco_decode_user_presence(dynamic raw);
+
   @protected
   UserSearchResult dco_decode_user_search_result(dynamic raw);
 
@@ -262,150 +536,465 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+  RustStreamSink<List<KnockRequestInfo>>
+  sse_decode_StreamSink_list_knock_request_info_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  RustStreamSink<MessageUpdate> sse_decode_StreamSink_message_update_Sse(
+  RustStreamSink<List<MessageUpdate>>
+  sse_decode_StreamSink_list_message_update_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<List<ReadReceipt>> sse_decode_StreamSink_list_read_receipt_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  RustStreamSink<RoomUpdate> sse_decode_StreamSink_room_update_Sse(
+  RustStreamSink<List<RoomListUpdate>>
+  sse_decode_StreamSink_list_room_list_update_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<List<RoomMembersUpdate>>
+  sse_decode_StreamSink_list_room_members_update_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  RustStreamSink<List<User>> sse_decode_StreamSink_list_user_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+  RustStreamSink<PaginationStatus> sse_decode_StreamSink_pagination_status_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ClientConfig sse_decode_box_autoadd_client_config(
+  RustStreamSink<UserPresence> sse_decode_StreamSink_user_presence_Sse(
     SseDeserializer deserializer,
   );
 
   @protected
-  Message sse_decode_box_autoadd_message(SseDeserializer deserializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  TracingConfiguration sse_decode_box_autoadd_tracing_configuration(
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  ClientConfig sse_decode_box_autoadd_client_config(
     SseDeserializer deserializer,
   );
 
   @protected
-  TracingFileConfiguration sse_decode_box_autoadd_tracing_file_configuration(
+  CreateRoomParams sse_decode_box_autoadd_create_room_params(
     SseDeserializer deserializer,
   );
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_i_8(SseDeserializer deserializer);
 
   @protected
-  ClientConfig sse_decode_client_config(SseDeserializer deserializer);
+  Message sse_decode_box_autoadd_message(SseDeserializer deserializer);
 
   @protected
-  ClientError sse_decode_client_error(SseDeserializer deserializer);
+  PowerLevelAction sse_decode_box_autoadd_power_level_action(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  PowerLevelOverrides sse_decode_box_autoadd_power_level_overrides(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  ReadReceipt sse_decode_box_autoadd_read_receipt(SseDeserializer deserializer);
 
   @protected
-  List<Certificate>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+  RoomHistoryVisibility sse_decode_box_autoadd_room_history_visibility(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  RoomJoinRule sse_decode_box_autoadd_room_join_rule(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<Message> sse_decode_list_message(SseDeserializer deserializer);
+  RoomListFilterKind sse_decode_box_autoadd_room_list_filter_kind(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  RoomSuccessor sse_decode_box_autoadd_room_successor(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<RoomUpdate> sse_decode_list_room_update(SseDeserializer deserializer);
+  TracingConfiguration sse_decode_box_autoadd_tracing_configuration(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<TraceLogPacks> sse_decode_list_trace_log_packs(
+  TracingFileConfiguration sse_decode_box_autoadd_tracing_file_configuration(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<User> sse_decode_list_user(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  Message sse_decode_message(SseDeserializer deserializer);
+  UpdateType sse_decode_box_autoadd_update_type(SseDeserializer deserializer);
 
   @protected
-  MessageType sse_decode_message_type(SseDeserializer deserializer);
+  User sse_decode_box_autoadd_user(SseDeserializer deserializer);
 
   @protected
-  MessageUpdate sse_decode_message_update(SseDeserializer deserializer);
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
 
   @protected
-  MessageUpdateType sse_decode_message_update_type(
-    SseDeserializer deserializer,
-  );
+  ClientConfig sse_decode_client_config(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  ClientError sse_decode_client_error(SseDeserializer deserializer);
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+  CreateRoomParams sse_decode_create_room_params(SseDeserializer deserializer);
 
   @protected
-  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer);
+  EventPowerLevel sse_decode_event_power_level(SseDeserializer deserializer);
 
   @protected
-  TracingFileConfiguration?
-  sse_decode_opt_box_autoadd_tracing_file_configuration(
-    SseDeserializer deserializer,
-  );
+  double sse_decode_f_64(SseDeserializer deserializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+  int sse_decode_i_8(SseDeserializer deserializer);
 
   @protected
-  List<Certificate>?
-  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+  InviteDetails sse_decode_invite_details(SseDeserializer deserializer);
+
+  @protected
+  KnockRequestInfo sse_decode_knock_request_info(SseDeserializer deserializer);
+
+  @protected
+  List<Certificate>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<EventPowerLevel> sse_decode_list_event_power_level(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InviteDetails> sse_decode_list_invite_details(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<KnockRequestInfo> sse_decode_list_knock_request_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer);
+
+  @protected
+  List<MessageUpdate> sse_decode_list_message_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<PublicRoom> sse_decode_list_public_room(SseDeserializer deserializer);
+
+  @protected
+  List<ReadReceipt> sse_decode_list_read_receipt(SseDeserializer deserializer);
+
+  @protected
+  List<RoomListFilterKind> sse_decode_list_room_list_filter_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RoomListUpdate> sse_decode_list_room_list_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RoomMemberInfo> sse_decode_list_room_member_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RoomMembersUpdate> sse_decode_list_room_members_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RoomTag> sse_decode_list_room_tag(SseDeserializer deserializer);
+
+  @protected
+  List<RoomUpdate> sse_decode_list_room_update(SseDeserializer deserializer);
+
+  @protected
+  List<SpaceHierarchyRoom> sse_decode_list_space_hierarchy_room(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TraceLogPacks> sse_decode_list_trace_log_packs(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<User> sse_decode_list_user(SseDeserializer deserializer);
+
+  @protected
+  List<UserPowerLevel> sse_decode_list_user_power_level(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  MemberMembership sse_decode_member_membership(SseDeserializer deserializer);
+
+  @protected
+  MemberRole sse_decode_member_role(SseDeserializer deserializer);
+
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer);
+
+  @protected
+  MessageType sse_decode_message_type(SseDeserializer deserializer);
+
+  @protected
+  MessageUpdate sse_decode_message_update(SseDeserializer deserializer);
+
+  @protected
+  MessageUpdateType sse_decode_message_update_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_8(SseDeserializer deserializer);
+
+  @protected
+  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer);
+
+  @protected
+  PowerLevelOverrides? sse_decode_opt_box_autoadd_power_level_overrides(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReadReceipt? sse_decode_opt_box_autoadd_read_receipt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomHistoryVisibility? sse_decode_opt_box_autoadd_room_history_visibility(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomJoinRule? sse_decode_opt_box_autoadd_room_join_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomSuccessor? sse_decode_opt_box_autoadd_room_successor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TracingFileConfiguration?
+  sse_decode_opt_box_autoadd_tracing_file_configuration(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UpdateType? sse_decode_opt_box_autoadd_update_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  User? sse_decode_opt_box_autoadd_user(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  List<Certificate>?
+  sse_decode_opt_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
     SseDeserializer deserializer,
   );
 
   @protected
   List<Message>? sse_decode_opt_list_message(SseDeserializer deserializer);
 
+  @protected
+  List<RoomUpdate>? sse_decode_opt_list_room_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaginationStatus sse_decode_pagination_status(SseDeserializer deserializer);
+
+  @protected
+  PowerLevelAction sse_decode_power_level_action(SseDeserializer deserializer);
+
+  @protected
+  PowerLevelOverrides sse_decode_power_level_overrides(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceKind sse_decode_presence_kind(SseDeserializer deserializer);
+
+  @protected
+  PublicRoom sse_decode_public_room(SseDeserializer deserializer);
+
+  @protected
+  PublicRoomsPage sse_decode_public_rooms_page(SseDeserializer deserializer);
+
+  @protected
+  ReadReceipt sse_decode_read_receipt(SseDeserializer deserializer);
+
+  @protected
+  ReceiptKind sse_decode_receipt_kind(SseDeserializer deserializer);
+
+  @protected
+  RoomAliases sse_decode_room_aliases(SseDeserializer deserializer);
+
+  @protected
+  RoomHistoryVisibility sse_decode_room_history_visibility(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomJoinRule sse_decode_room_join_rule(SseDeserializer deserializer);
+
+  @protected
+  RoomListFilterCategory sse_decode_room_list_filter_category(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomListFilterKind sse_decode_room_list_filter_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomListUpdate sse_decode_room_list_update(SseDeserializer deserializer);
+
+  @protected
+  RoomListUpdateType sse_decode_room_list_update_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomMemberInfo sse_decode_room_member_info(SseDeserializer deserializer);
+
+  @protected
+  RoomMembersPage sse_decode_room_members_page(SseDeserializer deserializer);
+
+  @protected
+  RoomMembersUpdate sse_decode_room_members_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomPermissions sse_decode_room_permissions(SseDeserializer deserializer);
+
+  @protected
+  RoomPowerLevelsInfo sse_decode_room_power_levels_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RoomPreviewInfo sse_decode_room_preview_info(SseDeserializer deserializer);
+
+  @protected
+  RoomSuccessor sse_decode_room_successor(SseDeserializer deserializer);
+
+  @protected
+  RoomTag sse_decode_room_tag(SseDeserializer deserializer);
+
   @protected
   RoomUpdate sse_decode_room_update(SseDeserializer deserializer);
 
+  @protected
+  RoomVisibility sse_decode_room_visibility(SseDeserializer deserializer);
+
+  @protected
+  SpaceHierarchyPage sse_decode_space_hierarchy_page(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SpaceHierarchyRoom sse_decode_space_hierarchy_room(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TraceLogPacks sse_decode_trace_log_packs(SseDeserializer deserializer);
 
@@ -432,122 +1021,263 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UpdateType sse_decode_update_type(SseDeserializer deserializer);
+
+  @protected
+  User sse_decode_user(SseDeserializer deserializer);
+
+  @protected
+  UserPowerLevel sse_decode_user_power_level(SseDeserializer deserializer);
+
+  @protected
+  UserPresence sse_decode_user_presence(SseDeserializer deserializer);
+
+  @protected
+  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    Certificate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    FieldsFormatterForFiles self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
+    Certificate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
+    FieldsFormatterForFiles self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_knock_request_info_Sse(
+    RustStreamSink<List<KnockRequestInfo>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_message_update_Sse(
+    RustStreamSink<List<MessageUpdate>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_read_receipt_Sse(
+    RustStreamSink<List<ReadReceipt>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_room_list_update_Sse(
+    RustStreamSink<List<RoomListUpdate>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_room_members_update_Sse(
+    RustStreamSink<List<RoomMembersUpdate>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_user_Sse(
+    RustStreamSink<List<User>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_pagination_status_Sse(
+    RustStreamSink<PaginationStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_user_presence_Sse(
+    RustStreamSink<UserPresence> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_client_config(
+    ClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_create_room_params(
+    CreateRoomParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  UpdateType sse_decode_update_type(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  User sse_decode_user(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_i_8(int self, SseSerializer serializer);
 
   @protected
-  UserSearchResult sse_decode_user_search_result(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_power_level_action(
+    PowerLevelAction self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_box_autoadd_power_level_overrides(
+    PowerLevelOverrides self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    Certificate self,
+  void sse_encode_box_autoadd_read_receipt(
+    ReadReceipt self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    FieldsFormatterForFiles self,
+  void sse_encode_box_autoadd_room_history_visibility(
+    RoomHistoryVisibility self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCertificate(
-    Certificate self,
+  void sse_encode_box_autoadd_room_join_rule(
+    RoomJoinRule self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerFieldsFormatterForFiles(
-    FieldsFormatterForFiles self,
+  void sse_encode_box_autoadd_room_list_filter_kind(
+    RoomListFilterKind self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_log_entry_Sse(
-    RustStreamSink<LogEntry> self,
+  void sse_encode_box_autoadd_room_successor(
+    RoomSuccessor self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_message_update_Sse(
-    RustStreamSink<MessageUpdate> self,
+  void sse_encode_box_autoadd_tracing_configuration(
+    TracingConfiguration self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_room_update_Sse(
-    RustStreamSink<RoomUpdate> self,
+  void sse_encode_box_autoadd_tracing_file_configuration(
+    TracingFileConfiguration self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_client_config(
-    ClientConfig self,
+  void sse_encode_box_autoadd_update_type(
+    UpdateType self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer);
+  void sse_encode_box_autoadd_user(User self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_tracing_configuration(
-    TracingConfiguration self,
-    SseSerializer serializer,
-  );
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_tracing_file_configuration(
-    TracingFileConfiguration self,
+  void sse_encode_client_config(ClientConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_client_error(ClientError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_create_room_params(
+    CreateRoomParams self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_event_power_level(
+    EventPowerLevel self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_client_config(ClientConfig self, SseSerializer serializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  void sse_encode_client_error(ClientError self, SseSerializer serializer);
+  void sse_encode_i_8(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_invite_details(InviteDetails self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_knock_request_info(
+    KnockRequestInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
   void
@@ -559,21 +1289,90 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_event_power_level(
+    List<EventPowerLevel> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_invite_details(
+    List<InviteDetails> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_knock_request_info(
+    List<KnockRequestInfo> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_message(List<Message> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_message_update(
+    List<MessageUpdate> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_public_room(
+    List<PublicRoom> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_read_receipt(
+    List<ReadReceipt> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_list_filter_kind(
+    List<RoomListFilterKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_list_update(
+    List<RoomListUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_member_info(
+    List<RoomMemberInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_members_update(
+    List<RoomMembersUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_tag(List<RoomTag> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_room_update(
     List<RoomUpdate> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_space_hierarchy_room(
+    List<SpaceHierarchyRoom> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_trace_log_packs(
     List<TraceLogPacks> self,
@@ -583,12 +1382,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_user(List<User> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_user_power_level(
+    List<UserPowerLevel> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
 
   @protected
   void sse_encode_log_level(LogLevel self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_member_membership(
+    MemberMembership self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_member_role(MemberRole self, SseSerializer serializer);
+
   @protected
   void sse_encode_message(Message self, SseSerializer serializer);
 
@@ -610,24 +1424,78 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_8(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_message(
     Message? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_power_level_overrides(
+    PowerLevelOverrides? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_read_receipt(
+    ReadReceipt? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_room_history_visibility(
+    RoomHistoryVisibility? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_room_join_rule(
+    RoomJoinRule? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_room_successor(
+    RoomSuccessor? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_tracing_file_configuration(
     TracingFileConfiguration? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_update_type(
+    UpdateType? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_user(User? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
 
@@ -644,9 +1512,147 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_list_room_update(
+    List<RoomUpdate>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_pagination_status(
+    PaginationStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_power_level_action(
+    PowerLevelAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_power_level_overrides(
+    PowerLevelOverrides self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_presence_kind(PresenceKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_public_room(PublicRoom self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_public_rooms_page(
+    PublicRoomsPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_read_receipt(ReadReceipt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_receipt_kind(ReceiptKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_aliases(RoomAliases self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_history_visibility(
+    RoomHistoryVisibility self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_join_rule(RoomJoinRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_list_filter_category(
+    RoomListFilterCategory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_list_filter_kind(
+    RoomListFilterKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_list_update(
+    RoomListUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_list_update_type(
+    RoomListUpdateType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_member_info(
+    RoomMemberInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_members_page(
+    RoomMembersPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_members_update(
+    RoomMembersUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_permissions(
+    RoomPermissions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_power_levels_info(
+    RoomPowerLevelsInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_preview_info(
+    RoomPreviewInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_room_successor(RoomSuccessor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_tag(RoomTag self, SseSerializer serializer);
+
   @protected
   void sse_encode_room_update(RoomUpdate self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_room_visibility(
+    RoomVisibility self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_space_hierarchy_page(
+    SpaceHierarchyPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_space_hierarchy_room(
+    SpaceHierarchyRoom self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_trace_log_packs(TraceLogPacks self, SseSerializer serializer);
 
@@ -683,6 +1689,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_user(User self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_user_power_level(
+    UserPowerLevel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_user_presence(UserPresence self, SseSerializer serializer);
+
   @protected
   void sse_encode_user_search_result(
     UserSearchResult self,
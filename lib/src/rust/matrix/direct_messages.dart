@@ -0,0 +1,14 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `find_dm_room`, `is_direct_invite`, `join_and_mark_dm`
+
+/// Get the DM room with `user_id`, accepting a pending DM invite from them or
+/// creating a new DM if there is none. Returns the room ID.
+Future<String> getOrCreateDm({required String userId}) =>
+    RustLib.instance.api.crateMatrixDirectMessagesGetOrCreateDm(userId: userId);
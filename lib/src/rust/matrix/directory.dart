@@ -0,0 +1,167 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Browse the public room directory of our homeserver, or of `server` if set.
+///
+/// Pass the `next_batch` of a page as `since` to get the following one.
+Future<PublicRoomsPage> searchPublicRooms({
+  String? searchTerm,
+  String? server,
+  int? limit,
+  String? since,
+}) => RustLib.instance.api.crateMatrixDirectorySearchPublicRooms(
+  searchTerm: searchTerm,
+  server: server,
+  limit: limit,
+  since: since,
+);
+
+/// Publish a room in, or remove it from, our homeserver's room directory.
+Future<void> setRoomPublished({
+  required String roomId,
+  required bool published,
+}) => RustLib.instance.api.crateMatrixDirectorySetRoomPublished(
+  roomId: roomId,
+  published: published,
+);
+
+Future<bool> isRoomPublished({required String roomId}) =>
+    RustLib.instance.api.crateMatrixDirectoryIsRoomPublished(roomId: roomId);
+
+Future<RoomAliases> getRoomAliases({required String roomId}) =>
+    RustLib.instance.api.crateMatrixDirectoryGetRoomAliases(roomId: roomId);
+
+/// Point `alias` (e.g. `#room:example.org`) to a room in the directory of the
+/// alias' homeserver. Returns `false` if the alias is already taken.
+Future<bool> addRoomAlias({required String roomId, required String alias}) =>
+    RustLib.instance.api.crateMatrixDirectoryAddRoomAlias(
+      roomId: roomId,
+      alias: alias,
+    );
+
+/// Remove `alias` from the directory. Returns `false` if it didn't exist.
+Future<bool> removeRoomAlias({required String roomId, required String alias}) =>
+    RustLib.instance.api.crateMatrixDirectoryRemoveRoomAlias(
+      roomId: roomId,
+      alias: alias,
+    );
+
+/// Set the `m.room.canonical_alias` state of a room. The aliases must already
+/// point to the room, see [`add_room_alias`].
+Future<void> setCanonicalAlias({
+  required String roomId,
+  String? alias,
+  required List<String> altAliases,
+}) => RustLib.instance.api.crateMatrixDirectorySetCanonicalAlias(
+  roomId: roomId,
+  alias: alias,
+  altAliases: altAliases,
+);
+
+/// A room of a public room directory.
+class PublicRoom {
+  final String roomId;
+  final String? canonicalAlias;
+  final String? name;
+  final String? topic;
+  final String? avatarUrl;
+  final BigInt numJoinedMembers;
+  final bool worldReadable;
+  final bool guestCanJoin;
+  final bool isSpace;
+
+  const PublicRoom({
+    required this.roomId,
+    this.canonicalAlias,
+    this.name,
+    this.topic,
+    this.avatarUrl,
+    required this.numJoinedMembers,
+    required this.worldReadable,
+    required this.guestCanJoin,
+    required this.isSpace,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      canonicalAlias.hashCode ^
+      name.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      numJoinedMembers.hashCode ^
+      worldReadable.hashCode ^
+      guestCanJoin.hashCode ^
+      isSpace.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PublicRoom &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          canonicalAlias == other.canonicalAlias &&
+          name == other.name &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          numJoinedMembers == other.numJoinedMembers &&
+          worldReadable == other.worldReadable &&
+          guestCanJoin == other.guestCanJoin &&
+          isSpace == other.isSpace;
+}
+
+class PublicRoomsPage {
+  final List<PublicRoom> rooms;
+
+  /// Token to pass as `since` to get the next page, `None` on the last one.
+  final String? nextBatch;
+  final String? prevBatch;
+  final BigInt? totalRoomCountEstimate;
+
+  const PublicRoomsPage({
+    required this.rooms,
+    this.nextBatch,
+    this.prevBatch,
+    this.totalRoomCountEstimate,
+  });
+
+  @override
+  int get hashCode =>
+      rooms.hashCode ^
+      nextBatch.hashCode ^
+      prevBatch.hashCode ^
+      totalRoomCountEstimate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PublicRoomsPage &&
+          runtimeType == other.runtimeType &&
+          rooms == other.rooms &&
+          nextBatch == other.nextBatch &&
+          prevBatch == other.prevBatch &&
+          totalRoomCountEstimate == other.totalRoomCountEstimate;
+}
+
+class RoomAliases {
+  final String? canonicalAlias;
+  final List<String> altAliases;
+
+  const RoomAliases({this.canonicalAlias, required this.altAliases});
+
+  @override
+  int get hashCode => canonicalAlias.hashCode ^ altAliases.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomAliases &&
+          runtimeType == other.runtimeType &&
+          canonicalAlias == other.canonicalAlias &&
+          altAliases == other.altAliases;
+}
@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decline_ignored_invite`, `decline_ignored_invites`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `filter_ignored_users_events`, `is_user_ignored`, `spawn_ignored_users_task`
+
+/// Ignore a user: their messages are hidden and their invites declined.
+Future<void> ignoreUser({required String userId}) =>
+    RustLib.instance.api.crateMatrixIgnoredUsersIgnoreUser(userId: userId);
+
+Future<void> unignoreUser({required String userId}) =>
+    RustLib.instance.api.crateMatrixIgnoredUsersUnignoreUser(userId: userId);
+
+Future<List<String>> getIgnoredUsers() =>
+    RustLib.instance.api.crateMatrixIgnoredUsersGetIgnoredUsers();
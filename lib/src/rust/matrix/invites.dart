@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'user_serach.dart';
+
+// These functions are ignored because they are not marked as `pub`: `wait_until_left`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `get_invite_details_data`
+
+Future<InviteDetails> getInviteDetails({required String roomId}) =>
+    RustLib.instance.api.crateMatrixInvitesGetInviteDetails(roomId: roomId);
+
+/// List all the pending invites, for the invites inbox.
+Future<List<InviteDetails>> getInvites() =>
+    RustLib.instance.api.crateMatrixInvitesGetInvites();
+
+Future<String> acceptInvite({required String roomId}) =>
+    RustLib.instance.api.crateMatrixInvitesAcceptInvite(roomId: roomId);
+
+/// Decline an invite, optionally telling the inviter why and ignoring them so
+/// they can't invite us again.
+Future<String> declineInvite({
+  required String roomId,
+  String? reason,
+  required bool ignoreInviter,
+}) => RustLib.instance.api.crateMatrixInvitesDeclineInvite(
+  roomId: roomId,
+  reason: reason,
+  ignoreInviter: ignoreInviter,
+);
+
+/// Everything needed to show an invite in the invites inbox.
+class InviteDetails {
+  final String roomId;
+  final String? roomName;
+  final String? topic;
+  final String? avatarUrl;
+  final BigInt memberCount;
+  final bool isDm;
+  final User? inviter;
+
+  const InviteDetails({
+    required this.roomId,
+    this.roomName,
+    this.topic,
+    this.avatarUrl,
+    required this.memberCount,
+    required this.isDm,
+    this.inviter,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      roomName.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      memberCount.hashCode ^
+      isDm.hashCode ^
+      inviter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InviteDetails &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          roomName == other.roomName &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          memberCount == other.memberCount &&
+          isDm == other.isDm &&
+          inviter == other.inviter;
+}
@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'rooms.dart';
+
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `parse_room_target`
+
+/// Join a room by ID, alias or link (see [`parse_room_target`]), including
+/// rooms the client doesn't know yet. Returns the ID of the joined room.
+Future<String> joinRoomByIdOrAlias({
+  required String target,
+  required List<String> viaServers,
+}) => RustLib.instance.api.crateMatrixJoiningJoinRoomByIdOrAlias(
+  target: target,
+  viaServers: viaServers,
+);
+
+/// Preview a room by ID, alias or link before joining it.
+Future<RoomPreviewInfo> getRoomPreview({
+  required String target,
+  required List<String> viaServers,
+}) => RustLib.instance.api.crateMatrixJoiningGetRoomPreview(
+  target: target,
+  viaServers: viaServers,
+);
+
+/// What a room looks like before joining it.
+class RoomPreviewInfo {
+  final String roomId;
+  final String? canonicalAlias;
+  final String? name;
+  final String? topic;
+  final String? avatarUrl;
+  final BigInt numJoinedMembers;
+  final bool isSpace;
+  final bool? isWorldReadable;
+  final bool? isDirect;
+
+  /// Our membership in the room, if we know the room already.
+  final UpdateType? membership;
+
+  const RoomPreviewInfo({
+    required this.roomId,
+    this.canonicalAlias,
+    this.name,
+    this.topic,
+    this.avatarUrl,
+    required this.numJoinedMembers,
+    required this.isSpace,
+    this.isWorldReadable,
+    this.isDirect,
+    this.membership,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      canonicalAlias.hashCode ^
+      name.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      numJoinedMembers.hashCode ^
+      isSpace.hashCode ^
+      isWorldReadable.hashCode ^
+      isDirect.hashCode ^
+      membership.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomPreviewInfo &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          canonicalAlias == other.canonicalAlias &&
+          name == other.name &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          numJoinedMembers == other.numJoinedMembers &&
+          isSpace == other.isSpace &&
+          isWorldReadable == other.isWorldReadable &&
+          isDirect == other.isDirect &&
+          membership == other.membership;
+}
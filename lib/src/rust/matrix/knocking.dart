@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Ask to join a room by ID, alias or link. Returns the ID of the knocked
+/// room; it shows up with the `Knocked` update type until a moderator answers.
+Future<String> knockRoom({
+  required String target,
+  String? reason,
+  required List<String> viaServers,
+}) => RustLib.instance.api.crateMatrixKnockingKnockRoom(
+  target: target,
+  reason: reason,
+  viaServers: viaServers,
+);
+
+/// Stream the pending knock requests of a room; every item is the full list.
+///
+/// Only useful for members allowed to invite, others get an empty list.
+Stream<List<KnockRequestInfo>> subscribeToKnockRequests({
+  required String roomId,
+}) => RustLib.instance.api.crateMatrixKnockingSubscribeToKnockRequests(
+  roomId: roomId,
+);
+
+/// Accept a knock request by inviting its sender.
+Future<void> acceptKnockRequest({
+  required String roomId,
+  required String userId,
+}) => RustLib.instance.api.crateMatrixKnockingAcceptKnockRequest(
+  roomId: roomId,
+  userId: userId,
+);
+
+/// Decline a knock request, optionally banning its sender so they can't knock
+/// again.
+Future<void> declineKnockRequest({
+  required String roomId,
+  required String userId,
+  String? reason,
+  required bool ban,
+}) => RustLib.instance.api.crateMatrixKnockingDeclineKnockRequest(
+  roomId: roomId,
+  userId: userId,
+  reason: reason,
+  ban: ban,
+);
+
+/// Mark the knock requests of `user_ids` as seen, for the unseen badge.
+Future<void> markKnockRequestsAsSeen({
+  required String roomId,
+  required List<String> userIds,
+}) => RustLib.instance.api.crateMatrixKnockingMarkKnockRequestsAsSeen(
+  roomId: roomId,
+  userIds: userIds,
+);
+
+/// A pending request to join a room with the `knock` join rule.
+class KnockRequestInfo {
+  final String eventId;
+  final String userId;
+  final String? displayName;
+  final String? avatarUrl;
+  final String? reason;
+  final BigInt? timestamp;
+
+  /// Whether a moderator already marked the request as seen.
+  final bool isSeen;
+
+  const KnockRequestInfo({
+    required this.eventId,
+    required this.userId,
+    this.displayName,
+    this.avatarUrl,
+    this.reason,
+    this.timestamp,
+    required this.isSeen,
+  });
+
+  @override
+  int get hashCode =>
+      eventId.hashCode ^
+      userId.hashCode ^
+      displayName.hashCode ^
+      avatarUrl.hashCode ^
+      reason.hashCode ^
+      timestamp.hashCode ^
+      isSeen.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KnockRequestInfo &&
+          runtimeType == other.runtimeType &&
+          eventId == other.eventId &&
+          userId == other.userId &&
+          displayName == other.displayName &&
+          avatarUrl == other.avatarUrl &&
+          reason == other.reason &&
+          timestamp == other.timestamp &&
+          isSeen == other.isSeen;
+}
@@ -0,0 +1,137 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'members.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get_member_info`, `get_member_update`, `get_sorted_members`, `load_room_members`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `from_power_level`
+
+/// A page of the joined and invited members of a room, admins first then by
+/// name.
+///
+/// The first page (`offset` 0) fetches the full member list from the server if
+/// it wasn't yet, the following ones only read the store.
+Future<RoomMembersPage> getRoomMembers({
+  required String roomId,
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateMatrixMembersGetRoomMembers(
+  roomId: roomId,
+  offset: offset,
+  limit: limit,
+);
+
+/// Members whose user ID or display name contains `query`, case insensitive.
+///
+/// Searches the members in the store, see [`get_room_members`].
+Future<RoomMembersPage> searchRoomMembers({
+  required String roomId,
+  required String query,
+  required int limit,
+}) => RustLib.instance.api.crateMatrixMembersSearchRoomMembers(
+  roomId: roomId,
+  query: query,
+  limit: limit,
+);
+
+/// Stream the changes of the joined and invited members of a room.
+///
+/// The first item is a `Reset` with the sorted list from the store, followed
+/// by another `Reset` once the full list has been fetched. Afterwards, every
+/// item holds the members who joined, left or changed their profile; keeping
+/// the list sorted is up to the caller.
+Stream<List<RoomMembersUpdate>> subscribeToRoomMembers({
+  required String roomId,
+}) => RustLib.instance.api.crateMatrixMembersSubscribeToRoomMembers(
+  roomId: roomId,
+);
+
+enum MemberMembership { join, invite, knock, leave, ban }
+
+/// The role shown for a power level, using the usual 100/50 thresholds.
+enum MemberRole { administrator, moderator, user }
+
+class RoomMemberInfo {
+  final String userId;
+  final String? displayName;
+  final String? avatarUrl;
+  final MemberMembership membership;
+  final PlatformInt64 powerLevel;
+  final MemberRole role;
+
+  const RoomMemberInfo({
+    required this.userId,
+    this.displayName,
+    this.avatarUrl,
+    required this.membership,
+    required this.powerLevel,
+    required this.role,
+  });
+
+  @override
+  int get hashCode =>
+      userId.hashCode ^
+      displayName.hashCode ^
+      avatarUrl.hashCode ^
+      membership.hashCode ^
+      powerLevel.hashCode ^
+      role.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomMemberInfo &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          displayName == other.displayName &&
+          avatarUrl == other.avatarUrl &&
+          membership == other.membership &&
+          powerLevel == other.powerLevel &&
+          role == other.role;
+}
+
+class RoomMembersPage {
+  final List<RoomMemberInfo> members;
+
+  /// Number of members matching, across all pages.
+  final int total;
+
+  const RoomMembersPage({required this.members, required this.total});
+
+  @override
+  int get hashCode => members.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomMembersPage &&
+          runtimeType == other.runtimeType &&
+          members == other.members &&
+          total == other.total;
+}
+
+/// A change of the member list streamed by [`subscribe_to_room_members`].
+@freezed
+sealed class RoomMembersUpdate with _$RoomMembersUpdate {
+  const RoomMembersUpdate._();
+
+  /// The whole sorted list, sent first and again once the full list has
+  /// been fetched.
+  const factory RoomMembersUpdate.reset({
+    required List<RoomMemberInfo> members,
+  }) = RoomMembersUpdate_Reset;
+
+  /// Someone joined or was invited, or a member changed their profile.
+  const factory RoomMembersUpdate.upsert({required RoomMemberInfo member}) =
+      RoomMembersUpdate_Upsert;
+
+  /// Someone left, or was kicked or banned.
+  const factory RoomMembersUpdate.remove({required String userId}) =
+      RoomMembersUpdate_Remove;
+}
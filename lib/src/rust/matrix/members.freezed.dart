@@ -0,0 +1,384 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'members.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$RoomMembersUpdate {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomMembersUpdate);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomMembersUpdate()';
+}
+
+
+}
+
+/// @nodoc
+class $RoomMembersUpdateCopyWith<$Res>  {
+$RoomMembersUpdateCopyWith(RoomMembersUpdate _, $Res Function(RoomMembersUpdate) __);
+}
+
+
+/// Adds pattern-matching-related methods to [RoomMembersUpdate].
+extension RoomMembersUpdatePatterns on RoomMembersUpdate {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RoomMembersUpdate_Reset value)?  reset,TResult Function( RoomMembersUpdate_Upsert value)?  upsert,TResult Function( RoomMembersUpdate_Remove value)?  remove,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset() when reset != null:
+return reset(_that);case RoomMembersUpdate_Upsert() when upsert != null:
+return upsert(_that);case RoomMembersUpdate_Remove() when remove != null:
+return remove(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RoomMembersUpdate_Reset value)  reset,required TResult Function( RoomMembersUpdate_Upsert value)  upsert,required TResult Function( RoomMembersUpdate_Remove value)  remove,}){
+final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset():
+return reset(_that);case RoomMembersUpdate_Upsert():
+return upsert(_that);case RoomMembersUpdate_Remove():
+return remove(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RoomMembersUpdate_Reset value)?  reset,TResult? Function( RoomMembersUpdate_Upsert value)?  upsert,TResult? Function( RoomMembersUpdate_Remove value)?  remove,}){
+final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset() when reset != null:
+return reset(_that);case RoomMembersUpdate_Upsert() when upsert != null:
+return upsert(_that);case RoomMembersUpdate_Remove() when remove != null:
+return remove(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( List<RoomMemberInfo> members)?  reset,TResult Function( RoomMemberInfo member)?  upsert,TResult Function( String userId)?  remove,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset() when reset != null:
+return reset(_that.members);case RoomMembersUpdate_Upsert() when upsert != null:
+return upsert(_that.member);case RoomMembersUpdate_Remove() when remove != null:
+return remove(_that.userId);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( List<RoomMemberInfo> members)  reset,required TResult Function( RoomMemberInfo member)  upsert,required TResult Function( String userId)  remove,}) {final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset():
+return reset(_that.members);case RoomMembersUpdate_Upsert():
+return upsert(_that.member);case RoomMembersUpdate_Remove():
+return remove(_that.userId);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( List<RoomMemberInfo> members)?  reset,TResult? Function( RoomMemberInfo member)?  upsert,TResult? Function( String userId)?  remove,}) {final _that = this;
+switch (_that) {
+case RoomMembersUpdate_Reset() when reset != null:
+return reset(_that.members);case RoomMembersUpdate_Upsert() when upsert != null:
+return upsert(_that.member);case RoomMembersUpdate_Remove() when remove != null:
+return remove(_that.userId);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class RoomMembersUpdate_Reset extends RoomMembersUpdate {
+  const RoomMembersUpdate_Reset({required final  List<RoomMemberInfo> members}): _members = members,super._();
+  
+
+ final  List<RoomMemberInfo> _members;
+ List<RoomMemberInfo> get members {
+  if (_members is EqualUnmodifiableListView) return _members;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_members);
+}
+
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomMembersUpdate_ResetCopyWith<RoomMembersUpdate_Reset> get copyWith => _$RoomMembersUpdate_ResetCopyWithImpl<RoomMembersUpdate_Reset>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomMembersUpdate_Reset&&const DeepCollectionEquality().equals(other._members, _members));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_members));
+
+@override
+String toString() {
+  return 'RoomMembersUpdate.reset(members: $members)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomMembersUpdate_ResetCopyWith<$Res> implements $RoomMembersUpdateCopyWith<$Res> {
+  factory $RoomMembersUpdate_ResetCopyWith(RoomMembersUpdate_Reset value, $Res Function(RoomMembersUpdate_Reset) _then) = _$RoomMembersUpdate_ResetCopyWithImpl;
+@useResult
+$Res call({
+ List<RoomMemberInfo> members
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomMembersUpdate_ResetCopyWithImpl<$Res>
+    implements $RoomMembersUpdate_ResetCopyWith<$Res> {
+  _$RoomMembersUpdate_ResetCopyWithImpl(this._self, this._then);
+
+  final RoomMembersUpdate_Reset _self;
+  final $Res Function(RoomMembersUpdate_Reset) _then;
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? members = null,}) {
+  return _then(RoomMembersUpdate_Reset(
+members: null == members ? _self._members : members // ignore: cast_nullable_to_non_nullable
+as List<RoomMemberInfo>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomMembersUpdate_Upsert extends RoomMembersUpdate {
+  const RoomMembersUpdate_Upsert({required this.member}): super._();
+  
+
+ final  RoomMemberInfo member;
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomMembersUpdate_UpsertCopyWith<RoomMembersUpdate_Upsert> get copyWith => _$RoomMembersUpdate_UpsertCopyWithImpl<RoomMembersUpdate_Upsert>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomMembersUpdate_Upsert&&(identical(other.member, member) || other.member == member));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,member);
+
+@override
+String toString() {
+  return 'RoomMembersUpdate.upsert(member: $member)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomMembersUpdate_UpsertCopyWith<$Res> implements $RoomMembersUpdateCopyWith<$Res> {
+  factory $RoomMembersUpdate_UpsertCopyWith(RoomMembersUpdate_Upsert value, $Res Function(RoomMembersUpdate_Upsert) _then) = _$RoomMembersUpdate_UpsertCopyWithImpl;
+@useResult
+$Res call({
+ RoomMemberInfo member
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomMembersUpdate_UpsertCopyWithImpl<$Res>
+    implements $RoomMembersUpdate_UpsertCopyWith<$Res> {
+  _$RoomMembersUpdate_UpsertCopyWithImpl(this._self, this._then);
+
+  final RoomMembersUpdate_Upsert _self;
+  final $Res Function(RoomMembersUpdate_Upsert) _then;
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? member = null,}) {
+  return _then(RoomMembersUpdate_Upsert(
+member: null == member ? _self.member : member // ignore: cast_nullable_to_non_nullable
+as RoomMemberInfo,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomMembersUpdate_Remove extends RoomMembersUpdate {
+  const RoomMembersUpdate_Remove({required this.userId}): super._();
+  
+
+ final  String userId;
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomMembersUpdate_RemoveCopyWith<RoomMembersUpdate_Remove> get copyWith => _$RoomMembersUpdate_RemoveCopyWithImpl<RoomMembersUpdate_Remove>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomMembersUpdate_Remove&&(identical(other.userId, userId) || other.userId == userId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,userId);
+
+@override
+String toString() {
+  return 'RoomMembersUpdate.remove(userId: $userId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomMembersUpdate_RemoveCopyWith<$Res> implements $RoomMembersUpdateCopyWith<$Res> {
+  factory $RoomMembersUpdate_RemoveCopyWith(RoomMembersUpdate_Remove value, $Res Function(RoomMembersUpdate_Remove) _then) = _$RoomMembersUpdate_RemoveCopyWithImpl;
+@useResult
+$Res call({
+ String userId
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomMembersUpdate_RemoveCopyWithImpl<$Res>
+    implements $RoomMembersUpdate_RemoveCopyWith<$Res> {
+  _$RoomMembersUpdate_RemoveCopyWithImpl(this._self, this._then);
+
+  final RoomMembersUpdate_Remove _self;
+  final $Res Function(RoomMembersUpdate_Remove) _then;
+
+/// Create a copy of RoomMembersUpdate
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? userId = null,}) {
+  return _then(RoomMembersUpdate_Remove(
+userId: null == userId ? _self.userId : userId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
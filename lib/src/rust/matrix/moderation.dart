@@ -0,0 +1,207 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'rooms.dart';
+part 'moderation.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get_power_levels`, `send_power_levels`
+
+Future<void> inviteUser({required String roomId, required String userId}) =>
+    RustLib.instance.api.crateMatrixModerationInviteUser(
+      roomId: roomId,
+      userId: userId,
+    );
+
+Future<void> kickUser({
+  required String roomId,
+  required String userId,
+  String? reason,
+}) => RustLib.instance.api.crateMatrixModerationKickUser(
+  roomId: roomId,
+  userId: userId,
+  reason: reason,
+);
+
+Future<void> banUser({
+  required String roomId,
+  required String userId,
+  String? reason,
+}) => RustLib.instance.api.crateMatrixModerationBanUser(
+  roomId: roomId,
+  userId: userId,
+  reason: reason,
+);
+
+Future<void> unbanUser({
+  required String roomId,
+  required String userId,
+  String? reason,
+}) => RustLib.instance.api.crateMatrixModerationUnbanUser(
+  roomId: roomId,
+  userId: userId,
+  reason: reason,
+);
+
+Future<RoomPowerLevelsInfo> getRoomPowerLevels({required String roomId}) =>
+    RustLib.instance.api.crateMatrixModerationGetRoomPowerLevels(
+      roomId: roomId,
+    );
+
+/// Set the power level of `user_id`; setting it to the users default removes
+/// the user from the list.
+Future<void> setUserPowerLevel({
+  required String roomId,
+  required String userId,
+  required PlatformInt64 powerLevel,
+}) => RustLib.instance.api.crateMatrixModerationSetUserPowerLevel(
+  roomId: roomId,
+  userId: userId,
+  powerLevel: powerLevel,
+);
+
+/// Set the power level needed for `action`.
+Future<void> setActionPowerLevel({
+  required String roomId,
+  required PowerLevelAction action,
+  required PlatformInt64 powerLevel,
+}) => RustLib.instance.api.crateMatrixModerationSetActionPowerLevel(
+  roomId: roomId,
+  action: action,
+  powerLevel: powerLevel,
+);
+
+Future<RoomPermissions> getRoomPermissions({required String roomId}) =>
+    RustLib.instance.api.crateMatrixModerationGetRoomPermissions(
+      roomId: roomId,
+    );
+
+/// An action whose required power level can be changed.
+@freezed
+sealed class PowerLevelAction with _$PowerLevelAction {
+  const PowerLevelAction._();
+
+  const factory PowerLevelAction.ban() = PowerLevelAction_Ban;
+  const factory PowerLevelAction.invite() = PowerLevelAction_Invite;
+  const factory PowerLevelAction.kick() = PowerLevelAction_Kick;
+  const factory PowerLevelAction.redact() = PowerLevelAction_Redact;
+  const factory PowerLevelAction.eventsDefault() =
+      PowerLevelAction_EventsDefault;
+  const factory PowerLevelAction.stateDefault() = PowerLevelAction_StateDefault;
+  const factory PowerLevelAction.usersDefault() = PowerLevelAction_UsersDefault;
+
+  /// Sending a specific event type, e.g. `m.room.name`.
+  const factory PowerLevelAction.event({required String eventType}) =
+      PowerLevelAction_Event;
+}
+
+/// What the current user is allowed to do in a room, to hide the rest in the
+/// UI.
+class RoomPermissions {
+  final PlatformInt64 ownPowerLevel;
+  final bool canSendMessage;
+  final bool canRedactOwn;
+  final bool canRedactOthers;
+  final bool canInvite;
+  final bool canKick;
+  final bool canBan;
+
+  /// Change the name, topic and avatar.
+  final bool canChangeSettings;
+  final bool canChangePowerLevels;
+
+  const RoomPermissions({
+    required this.ownPowerLevel,
+    required this.canSendMessage,
+    required this.canRedactOwn,
+    required this.canRedactOthers,
+    required this.canInvite,
+    required this.canKick,
+    required this.canBan,
+    required this.canChangeSettings,
+    required this.canChangePowerLevels,
+  });
+
+  @override
+  int get hashCode =>
+      ownPowerLevel.hashCode ^
+      canSendMessage.hashCode ^
+      canRedactOwn.hashCode ^
+      canRedactOthers.hashCode ^
+      canInvite.hashCode ^
+      canKick.hashCode ^
+      canBan.hashCode ^
+      canChangeSettings.hashCode ^
+      canChangePowerLevels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomPermissions &&
+          runtimeType == other.runtimeType &&
+          ownPowerLevel == other.ownPowerLevel &&
+          canSendMessage == other.canSendMessage &&
+          canRedactOwn == other.canRedactOwn &&
+          canRedactOthers == other.canRedactOthers &&
+          canInvite == other.canInvite &&
+          canKick == other.canKick &&
+          canBan == other.canBan &&
+          canChangeSettings == other.canChangeSettings &&
+          canChangePowerLevels == other.canChangePowerLevels;
+}
+
+/// The power levels of a room, with the defaults filled in.
+class RoomPowerLevelsInfo {
+  final PlatformInt64 ban;
+  final PlatformInt64 invite;
+  final PlatformInt64 kick;
+  final PlatformInt64 redact;
+  final PlatformInt64 eventsDefault;
+  final PlatformInt64 stateDefault;
+  final PlatformInt64 usersDefault;
+  final List<EventPowerLevel> events;
+  final List<UserPowerLevel> users;
+
+  const RoomPowerLevelsInfo({
+    required this.ban,
+    required this.invite,
+    required this.kick,
+    required this.redact,
+    required this.eventsDefault,
+    required this.stateDefault,
+    required this.usersDefault,
+    required this.events,
+    required this.users,
+  });
+
+  @override
+  int get hashCode =>
+      ban.hashCode ^
+      invite.hashCode ^
+      kick.hashCode ^
+      redact.hashCode ^
+      eventsDefault.hashCode ^
+      stateDefault.hashCode ^
+      usersDefault.hashCode ^
+      events.hashCode ^
+      users.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomPowerLevelsInfo &&
+          runtimeType == other.runtimeType &&
+          ban == other.ban &&
+          invite == other.invite &&
+          kick == other.kick &&
+          redact == other.redact &&
+          eventsDefault == other.eventsDefault &&
+          stateDefault == other.stateDefault &&
+          usersDefault == other.usersDefault &&
+          events == other.events &&
+          users == other.users;
+}
@@ -0,0 +1,479 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'moderation.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$PowerLevelAction {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction()';
+}
+
+
+}
+
+/// @nodoc
+class $PowerLevelActionCopyWith<$Res>  {
+$PowerLevelActionCopyWith(PowerLevelAction _, $Res Function(PowerLevelAction) __);
+}
+
+
+/// Adds pattern-matching-related methods to [PowerLevelAction].
+extension PowerLevelActionPatterns on PowerLevelAction {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( PowerLevelAction_Ban value)?  ban,TResult Function( PowerLevelAction_Invite value)?  invite,TResult Function( PowerLevelAction_Kick value)?  kick,TResult Function( PowerLevelAction_Redact value)?  redact,TResult Function( PowerLevelAction_EventsDefault value)?  eventsDefault,TResult Function( PowerLevelAction_StateDefault value)?  stateDefault,TResult Function( PowerLevelAction_UsersDefault value)?  usersDefault,TResult Function( PowerLevelAction_Event value)?  event,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban() when ban != null:
+return ban(_that);case PowerLevelAction_Invite() when invite != null:
+return invite(_that);case PowerLevelAction_Kick() when kick != null:
+return kick(_that);case PowerLevelAction_Redact() when redact != null:
+return redact(_that);case PowerLevelAction_EventsDefault() when eventsDefault != null:
+return eventsDefault(_that);case PowerLevelAction_StateDefault() when stateDefault != null:
+return stateDefault(_that);case PowerLevelAction_UsersDefault() when usersDefault != null:
+return usersDefault(_that);case PowerLevelAction_Event() when event != null:
+return event(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( PowerLevelAction_Ban value)  ban,required TResult Function( PowerLevelAction_Invite value)  invite,required TResult Function( PowerLevelAction_Kick value)  kick,required TResult Function( PowerLevelAction_Redact value)  redact,required TResult Function( PowerLevelAction_EventsDefault value)  eventsDefault,required TResult Function( PowerLevelAction_StateDefault value)  stateDefault,required TResult Function( PowerLevelAction_UsersDefault value)  usersDefault,required TResult Function( PowerLevelAction_Event value)  event,}){
+final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban():
+return ban(_that);case PowerLevelAction_Invite():
+return invite(_that);case PowerLevelAction_Kick():
+return kick(_that);case PowerLevelAction_Redact():
+return redact(_that);case PowerLevelAction_EventsDefault():
+return eventsDefault(_that);case PowerLevelAction_StateDefault():
+return stateDefault(_that);case PowerLevelAction_UsersDefault():
+return usersDefault(_that);case PowerLevelAction_Event():
+return event(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( PowerLevelAction_Ban value)?  ban,TResult? Function( PowerLevelAction_Invite value)?  invite,TResult? Function( PowerLevelAction_Kick value)?  kick,TResult? Function( PowerLevelAction_Redact value)?  redact,TResult? Function( PowerLevelAction_EventsDefault value)?  eventsDefault,TResult? Function( PowerLevelAction_StateDefault value)?  stateDefault,TResult? Function( PowerLevelAction_UsersDefault value)?  usersDefault,TResult? Function( PowerLevelAction_Event value)?  event,}){
+final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban() when ban != null:
+return ban(_that);case PowerLevelAction_Invite() when invite != null:
+return invite(_that);case PowerLevelAction_Kick() when kick != null:
+return kick(_that);case PowerLevelAction_Redact() when redact != null:
+return redact(_that);case PowerLevelAction_EventsDefault() when eventsDefault != null:
+return eventsDefault(_that);case PowerLevelAction_StateDefault() when stateDefault != null:
+return stateDefault(_that);case PowerLevelAction_UsersDefault() when usersDefault != null:
+return usersDefault(_that);case PowerLevelAction_Event() when event != null:
+return event(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  ban,TResult Function()?  invite,TResult Function()?  kick,TResult Function()?  redact,TResult Function()?  eventsDefault,TResult Function()?  stateDefault,TResult Function()?  usersDefault,TResult Function( String eventType)?  event,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban() when ban != null:
+return ban();case PowerLevelAction_Invite() when invite != null:
+return invite();case PowerLevelAction_Kick() when kick != null:
+return kick();case PowerLevelAction_Redact() when redact != null:
+return redact();case PowerLevelAction_EventsDefault() when eventsDefault != null:
+return eventsDefault();case PowerLevelAction_StateDefault() when stateDefault != null:
+return stateDefault();case PowerLevelAction_UsersDefault() when usersDefault != null:
+return usersDefault();case PowerLevelAction_Event() when event != null:
+return event(_that.eventType);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  ban,required TResult Function()  invite,required TResult Function()  kick,required TResult Function()  redact,required TResult Function()  eventsDefault,required TResult Function()  stateDefault,required TResult Function()  usersDefault,required TResult Function( String eventType)  event,}) {final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban():
+return ban();case PowerLevelAction_Invite():
+return invite();case PowerLevelAction_Kick():
+return kick();case PowerLevelAction_Redact():
+return redact();case PowerLevelAction_EventsDefault():
+return eventsDefault();case PowerLevelAction_StateDefault():
+return stateDefault();case PowerLevelAction_UsersDefault():
+return usersDefault();case PowerLevelAction_Event():
+return event(_that.eventType);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  ban,TResult? Function()?  invite,TResult? Function()?  kick,TResult? Function()?  redact,TResult? Function()?  eventsDefault,TResult? Function()?  stateDefault,TResult? Function()?  usersDefault,TResult? Function( String eventType)?  event,}) {final _that = this;
+switch (_that) {
+case PowerLevelAction_Ban() when ban != null:
+return ban();case PowerLevelAction_Invite() when invite != null:
+return invite();case PowerLevelAction_Kick() when kick != null:
+return kick();case PowerLevelAction_Redact() when redact != null:
+return redact();case PowerLevelAction_EventsDefault() when eventsDefault != null:
+return eventsDefault();case PowerLevelAction_StateDefault() when stateDefault != null:
+return stateDefault();case PowerLevelAction_UsersDefault() when usersDefault != null:
+return usersDefault();case PowerLevelAction_Event() when event != null:
+return event(_that.eventType);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_Ban extends PowerLevelAction {
+  const PowerLevelAction_Ban(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_Ban);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.ban()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_Invite extends PowerLevelAction {
+  const PowerLevelAction_Invite(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_Invite);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.invite()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_Kick extends PowerLevelAction {
+  const PowerLevelAction_Kick(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_Kick);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.kick()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_Redact extends PowerLevelAction {
+  const PowerLevelAction_Redact(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_Redact);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.redact()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_EventsDefault extends PowerLevelAction {
+  const PowerLevelAction_EventsDefault(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_EventsDefault);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.eventsDefault()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_StateDefault extends PowerLevelAction {
+  const PowerLevelAction_StateDefault(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_StateDefault);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.stateDefault()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_UsersDefault extends PowerLevelAction {
+  const PowerLevelAction_UsersDefault(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_UsersDefault);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PowerLevelAction.usersDefault()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PowerLevelAction_Event extends PowerLevelAction {
+  const PowerLevelAction_Event({required this.eventType}): super._();
+  
+
+ final  String eventType;
+
+/// Create a copy of PowerLevelAction
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PowerLevelAction_EventCopyWith<PowerLevelAction_Event> get copyWith => _$PowerLevelAction_EventCopyWithImpl<PowerLevelAction_Event>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PowerLevelAction_Event&&(identical(other.eventType, eventType) || other.eventType == eventType));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,eventType);
+
+@override
+String toString() {
+  return 'PowerLevelAction.event(eventType: $eventType)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PowerLevelAction_EventCopyWith<$Res> implements $PowerLevelActionCopyWith<$Res> {
+  factory $PowerLevelAction_EventCopyWith(PowerLevelAction_Event value, $Res Function(PowerLevelAction_Event) _then) = _$PowerLevelAction_EventCopyWithImpl;
+@useResult
+$Res call({
+ String eventType
+});
+
+
+
+
+}
+/// @nodoc
+class _$PowerLevelAction_EventCopyWithImpl<$Res>
+    implements $PowerLevelAction_EventCopyWith<$Res> {
+  _$PowerLevelAction_EventCopyWithImpl(this._self, this._then);
+
+  final PowerLevelAction_Event _self;
+  final $Res Function(PowerLevelAction_Event) _then;
+
+/// Create a copy of PowerLevelAction
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? eventType = null,}) {
+  return _then(PowerLevelAction_Event(
+eventType: null == eventType ? _self.eventType : eventType // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
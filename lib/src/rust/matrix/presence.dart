@@ -0,0 +1,86 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `differs_from`, `fetch_presence`, `get_last_active`, `get_presence_kind`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+Future<UserPresence> getUserPresence({required String userId}) =>
+    RustLib.instance.api.crateMatrixPresenceGetUserPresence(userId: userId);
+
+/// Allocate the ID of a presence subscription, to pass to
+/// [`subscribe_to_presence`] and later to [`unsubscribe_from_presence`].
+Future<BigInt> newPresenceSubscriptionId() =>
+    RustLib.instance.api.crateMatrixPresenceNewPresenceSubscriptionId();
+
+/// Stream the presence of `user_ids`, e.g. our DM partners. Every item is the
+/// presence of one user, sent once at first and then when it changes.
+///
+/// The subscription polls the server until [`unsubscribe_from_presence`] is
+/// called with `subscription_id`, or until sending an update fails because
+/// the stream was closed on the Dart side.
+Stream<UserPresence> subscribeToPresence({
+  required List<String> userIds,
+  required BigInt subscriptionId,
+}) => RustLib.instance.api.crateMatrixPresenceSubscribeToPresence(
+  userIds: userIds,
+  subscriptionId: subscriptionId,
+);
+
+/// Stop a presence subscription started by [`subscribe_to_presence`].
+Future<void> unsubscribeFromPresence({required BigInt subscriptionId}) =>
+    RustLib.instance.api.crateMatrixPresenceUnsubscribeFromPresence(
+      subscriptionId: subscriptionId,
+    );
+
+/// Set our own presence and status message; `None` clears the message.
+Future<void> setOwnPresence({
+  required PresenceKind presence,
+  String? statusMessage,
+}) => RustLib.instance.api.crateMatrixPresenceSetOwnPresence(
+  presence: presence,
+  statusMessage: statusMessage,
+);
+
+enum PresenceKind { online, unavailable, offline }
+
+class UserPresence {
+  final String userId;
+  final PresenceKind presence;
+
+  /// When the user was last active, in milliseconds since the epoch.
+  final BigInt? lastActive;
+  final bool? currentlyActive;
+  final String? statusMessage;
+
+  const UserPresence({
+    required this.userId,
+    required this.presence,
+    this.lastActive,
+    this.currentlyActive,
+    this.statusMessage,
+  });
+
+  @override
+  int get hashCode =>
+      userId.hashCode ^
+      presence.hashCode ^
+      lastActive.hashCode ^
+      currentlyActive.hashCode ^
+      statusMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserPresence &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          presence == other.presence &&
+          lastActive == other.lastActive &&
+          currentlyActive == other.currentlyActive &&
+          statusMessage == other.statusMessage;
+}
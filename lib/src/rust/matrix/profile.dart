@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'user_serach.dart';
+
+/// Fetch the global profile of any user, not their per-room one.
+Future<User> getUserProfile({required String userId}) =>
+    RustLib.instance.api.crateMatrixProfileGetUserProfile(userId: userId);
+
+Future<User> getOwnProfile() =>
+    RustLib.instance.api.crateMatrixProfileGetOwnProfile();
+
+/// Set our display name, `None` removes it.
+Future<void> setDisplayName({String? displayName}) =>
+    RustLib.instance.api.crateMatrixProfileSetDisplayName(
+      displayName: displayName,
+    );
+
+/// Upload `data` and make it our avatar. `mime_type` is e.g. `image/png`.
+/// Returns the `mxc://` URI of the avatar.
+Future<String> setAvatar({required Uint8List data, required String mimeType}) =>
+    RustLib.instance.api.crateMatrixProfileSetAvatar(
+      data: data,
+      mimeType: mimeType,
+    );
+
+Future<void> removeAvatar() =>
+    RustLib.instance.api.crateMatrixProfileRemoveAvatar();
@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `from`
+
+/// Send a single receipt or read marker up to `event_id`.
+Future<void> sendReadReceipt({
+  required String roomId,
+  required String eventId,
+  required ReceiptKind kind,
+}) => RustLib.instance.api.crateMatrixReceiptsSendReadReceipt(
+  roomId: roomId,
+  eventId: eventId,
+  kind: kind,
+);
+
+/// Move the `m.fully_read` marker and the read receipt of a room in one
+/// request. Missing event IDs leave the corresponding marker untouched.
+Future<void> setReadMarkers({
+  required String roomId,
+  String? fullyReadEventId,
+  String? readReceiptEventId,
+  required bool privateReadReceipt,
+}) => RustLib.instance.api.crateMatrixReceiptsSetReadMarkers(
+  roomId: roomId,
+  fullyReadEventId: fullyReadEventId,
+  readReceiptEventId: readReceiptEventId,
+  privateReadReceipt: privateReadReceipt,
+);
+
+/// Mark the whole room as read: send a receipt for the latest event of its
+/// timeline and clear the "marked as unread" flag.
+///
+/// The unread counts in `RoomUpdate` follow through the room update streams.
+Future<void> markRoomAsRead({
+  required String roomId,
+  required ReceiptKind kind,
+}) => RustLib.instance.api.crateMatrixReceiptsMarkRoomAsRead(
+  roomId: roomId,
+  kind: kind,
+);
+
+/// Set or clear the `m.marked_unread` flag of a room.
+Future<void> setRoomMarkedUnread({
+  required String roomId,
+  required bool unread,
+}) => RustLib.instance.api.crateMatrixReceiptsSetRoomMarkedUnread(
+  roomId: roomId,
+  unread: unread,
+);
+
+/// Our own read receipt in a room, where the unread divider goes. Unlike the
+/// `ReadMarker` timeline item, it is known even when the event isn't loaded.
+Future<ReadReceipt?> getOwnReadReceipt({required String roomId}) =>
+    RustLib.instance.api.crateMatrixReceiptsGetOwnReadReceipt(roomId: roomId);
+
+/// Stream the read receipts of a room as they come in with the sync; every
+/// item holds the receipts that moved, ours included.
+///
+/// The receipts of loaded messages are also updated in `Message::read_receipts`
+/// through the timeline streams.
+Stream<List<ReadReceipt>> subscribeToReadReceipts({required String roomId}) =>
+    RustLib.instance.api.crateMatrixReceiptsSubscribeToReadReceipts(
+      roomId: roomId,
+    );
+
+/// Someone's public or private read receipt, i.e. how far they have read.
+class ReadReceipt {
+  final String userId;
+  final String eventId;
+  final BigInt? timestamp;
+
+  const ReadReceipt({
+    required this.userId,
+    required this.eventId,
+    this.timestamp,
+  });
+
+  @override
+  int get hashCode => userId.hashCode ^ eventId.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadReceipt &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          eventId == other.eventId &&
+          timestamp == other.timestamp;
+}
+
+enum ReceiptKind {
+  /// `m.read`, visible to the other members.
+  public,
+
+  /// `m.read.private`, only visible to us.
+  private,
+
+  /// `m.fully_read`, our own read marker.
+  fullyRead,
+}
@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Report an event, e.g. a message from the timeline, to the homeserver
+/// admins. `score` goes from -100 for the most offensive to 0 for inoffensive.
+Future<void> reportEvent({
+  required String roomId,
+  required String eventId,
+  String? reason,
+  int? score,
+}) => RustLib.instance.api.crateMatrixReportingReportEvent(
+  roomId: roomId,
+  eventId: eventId,
+  reason: reason,
+  score: score,
+);
+
+/// Report a whole room to the homeserver admins.
+Future<void> reportRoom({required String roomId, String? reason}) =>
+    RustLib.instance.api.crateMatrixReportingReportRoom(
+      roomId: roomId,
+      reason: reason,
+    );
+
+/// Report a user to the homeserver admins.
+Future<void> reportUser({required String userId, required String reason}) =>
+    RustLib.instance.api.crateMatrixReportingReportUser(
+      userId: userId,
+      reason: reason,
+    );
@@ -0,0 +1,149 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'rooms.dart';
+part 'room_list.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `into_filter`, `uses_room_infos`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RoomListSubscription`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `get_room_list_update_from_diff`, `refresh_room_list_filters`
+
+/// Allocate the ID of a room list subscription, to pass to
+/// [`subscribe_to_room_list`] and to the functions driving that list.
+Future<BigInt> newRoomListSubscriptionId() =>
+    RustLib.instance.api.crateMatrixRoomListNewRoomListSubscriptionId();
+
+/// Subscribe to the room list, filtered by `filter` and loaded `page_size`
+/// rooms at a time.
+///
+/// Rooms are sorted by recency (latest activity first, then by name), exactly
+/// like in the SDK. Each item of the stream is the batch of updates of one
+/// room list change. Use [`set_room_list_filter`], [`room_list_load_next_page`]
+/// and [`room_list_reset_to_one_page`] with the same `subscription_id` to
+/// drive the list afterwards.
+Stream<List<RoomListUpdate>> subscribeToRoomList({
+  required BigInt subscriptionId,
+  required RoomListFilterKind filter,
+  int? pageSize,
+}) => RustLib.instance.api.crateMatrixRoomListSubscribeToRoomList(
+  subscriptionId: subscriptionId,
+  filter: filter,
+  pageSize: pageSize,
+);
+
+/// Replace the filter of a subscribed room list.
+Future<void> setRoomListFilter({
+  required BigInt subscriptionId,
+  required RoomListFilterKind filter,
+}) => RustLib.instance.api.crateMatrixRoomListSetRoomListFilter(
+  subscriptionId: subscriptionId,
+  filter: filter,
+);
+
+/// Load one more page of rooms, for infinite scrolling.
+Future<void> roomListLoadNextPage({required BigInt subscriptionId}) =>
+    RustLib.instance.api.crateMatrixRoomListRoomListLoadNextPage(
+      subscriptionId: subscriptionId,
+    );
+
+/// Shrink the room list back to its first page.
+Future<void> roomListResetToOnePage({required BigInt subscriptionId}) =>
+    RustLib.instance.api.crateMatrixRoomListRoomListResetToOnePage(
+      subscriptionId: subscriptionId,
+    );
+
+enum RoomListFilterCategory { group, people }
+
+/// Filters applied to the room list, they can be combined with `All` and
+/// `Any`.
+@freezed
+sealed class RoomListFilterKind with _$RoomListFilterKind {
+  const RoomListFilterKind._();
+
+  const factory RoomListFilterKind.all({
+    required List<RoomListFilterKind> filters,
+  }) = RoomListFilterKind_All;
+  const factory RoomListFilterKind.any({
+    required List<RoomListFilterKind> filters,
+  }) = RoomListFilterKind_Any;
+  const factory RoomListFilterKind.nonSpace() = RoomListFilterKind_NonSpace;
+  const factory RoomListFilterKind.nonLeft() = RoomListFilterKind_NonLeft;
+  const factory RoomListFilterKind.joined() = RoomListFilterKind_Joined;
+  const factory RoomListFilterKind.unread() = RoomListFilterKind_Unread;
+  const factory RoomListFilterKind.favourite() = RoomListFilterKind_Favourite;
+  const factory RoomListFilterKind.lowPriority() =
+      RoomListFilterKind_LowPriority;
+  const factory RoomListFilterKind.nonLowPriority() =
+      RoomListFilterKind_NonLowPriority;
+  const factory RoomListFilterKind.invite() = RoomListFilterKind_Invite;
+  const factory RoomListFilterKind.category({
+    required RoomListFilterCategory expect,
+  }) = RoomListFilterKind_Category;
+  const factory RoomListFilterKind.none() = RoomListFilterKind_None;
+  const factory RoomListFilterKind.normalizedMatchRoomName({
+    required String pattern,
+  }) = RoomListFilterKind_NormalizedMatchRoomName;
+  const factory RoomListFilterKind.fuzzyMatchRoomName({
+    required String pattern,
+  }) = RoomListFilterKind_FuzzyMatchRoomName;
+  const factory RoomListFilterKind.deduplicateVersions() =
+      RoomListFilterKind_DeduplicateVersions;
+
+  /// Rooms carrying the given `m.tag`, e.g. a custom `u.work` tag.
+  const factory RoomListFilterKind.tag({required String name}) =
+      RoomListFilterKind_Tag;
+
+  /// Children of the given space, see `spaces::get_joined_spaces`.
+  const factory RoomListFilterKind.space({required String spaceId}) =
+      RoomListFilterKind_Space;
+}
+
+/// A change of the room list, mirroring the SDK's `VectorDiff`s so the indices
+/// match the SDK's ordering.
+class RoomListUpdate {
+  final RoomListUpdateType updateType;
+  final List<RoomUpdate>? rooms;
+  final BigInt? index;
+  final BigInt? length;
+
+  const RoomListUpdate({
+    required this.updateType,
+    this.rooms,
+    this.index,
+    this.length,
+  });
+
+  @override
+  int get hashCode =>
+      updateType.hashCode ^ rooms.hashCode ^ index.hashCode ^ length.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomListUpdate &&
+          runtimeType == other.runtimeType &&
+          updateType == other.updateType &&
+          rooms == other.rooms &&
+          index == other.index &&
+          length == other.length;
+}
+
+enum RoomListUpdateType {
+  reset,
+  truncate,
+  remove,
+  set_,
+  insert,
+  popBack,
+  popFront,
+  pushBack,
+  pushFront,
+  clear,
+  append,
+}
@@ -0,0 +1,1028 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'room_list.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$RoomListFilterKind {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind()';
+}
+
+
+}
+
+/// @nodoc
+class $RoomListFilterKindCopyWith<$Res>  {
+$RoomListFilterKindCopyWith(RoomListFilterKind _, $Res Function(RoomListFilterKind) __);
+}
+
+
+/// Adds pattern-matching-related methods to [RoomListFilterKind].
+extension RoomListFilterKindPatterns on RoomListFilterKind {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RoomListFilterKind_All value)?  all,TResult Function( RoomListFilterKind_Any value)?  any,TResult Function( RoomListFilterKind_NonSpace value)?  nonSpace,TResult Function( RoomListFilterKind_NonLeft value)?  nonLeft,TResult Function( RoomListFilterKind_Joined value)?  joined,TResult Function( RoomListFilterKind_Unread value)?  unread,TResult Function( RoomListFilterKind_Favourite value)?  favourite,TResult Function( RoomListFilterKind_LowPriority value)?  lowPriority,TResult Function( RoomListFilterKind_NonLowPriority value)?  nonLowPriority,TResult Function( RoomListFilterKind_Invite value)?  invite,TResult Function( RoomListFilterKind_Category value)?  category,TResult Function( RoomListFilterKind_None value)?  none,TResult Function( RoomListFilterKind_NormalizedMatchRoomName value)?  normalizedMatchRoomName,TResult Function( RoomListFilterKind_FuzzyMatchRoomName value)?  fuzzyMatchRoomName,TResult Function( RoomListFilterKind_DeduplicateVersions value)?  deduplicateVersions,TResult Function( RoomListFilterKind_Tag value)?  tag,TResult Function( RoomListFilterKind_Space value)?  space,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case RoomListFilterKind_All() when all != null:
+return all(_that);case RoomListFilterKind_Any() when any != null:
+return any(_that);case RoomListFilterKind_NonSpace() when nonSpace != null:
+return nonSpace(_that);case RoomListFilterKind_NonLeft() when nonLeft != null:
+return nonLeft(_that);case RoomListFilterKind_Joined() when joined != null:
+return joined(_that);case RoomListFilterKind_Unread() when unread != null:
+return unread(_that);case RoomListFilterKind_Favourite() when favourite != null:
+return favourite(_that);case RoomListFilterKind_LowPriority() when lowPriority != null:
+return lowPriority(_that);case RoomListFilterKind_NonLowPriority() when nonLowPriority != null:
+return nonLowPriority(_that);case RoomListFilterKind_Invite() when invite != null:
+return invite(_that);case RoomListFilterKind_Category() when category != null:
+return category(_that);case RoomListFilterKind_None() when none != null:
+return none(_that);case RoomListFilterKind_NormalizedMatchRoomName() when normalizedMatchRoomName != null:
+return normalizedMatchRoomName(_that);case RoomListFilterKind_FuzzyMatchRoomName() when fuzzyMatchRoomName != null:
+return fuzzyMatchRoomName(_that);case RoomListFilterKind_DeduplicateVersions() when deduplicateVersions != null:
+return deduplicateVersions(_that);case RoomListFilterKind_Tag() when tag != null:
+return tag(_that);case RoomListFilterKind_Space() when space != null:
+return space(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RoomListFilterKind_All value)  all,required TResult Function( RoomListFilterKind_Any value)  any,required TResult Function( RoomListFilterKind_NonSpace value)  nonSpace,required TResult Function( RoomListFilterKind_NonLeft value)  nonLeft,required TResult Function( RoomListFilterKind_Joined value)  joined,required TResult Function( RoomListFilterKind_Unread value)  unread,required TResult Function( RoomListFilterKind_Favourite value)  favourite,required TResult Function( RoomListFilterKind_LowPriority value)  lowPriority,required TResult Function( RoomListFilterKind_NonLowPriority value)  nonLowPriority,required TResult Function( RoomListFilterKind_Invite value)  invite,required TResult Function( RoomListFilterKind_Category value)  category,required TResult Function( RoomListFilterKind_None value)  none,required TResult Function( RoomListFilterKind_NormalizedMatchRoomName value)  normalizedMatchRoomName,required TResult Function( RoomListFilterKind_FuzzyMatchRoomName value)  fuzzyMatchRoomName,required TResult Function( RoomListFilterKind_DeduplicateVersions value)  deduplicateVersions,required TResult Function( RoomListFilterKind_Tag value)  tag,required TResult Function( RoomListFilterKind_Space value)  space,}){
+final _that = this;
+switch (_that) {
+case RoomListFilterKind_All():
+return all(_that);case RoomListFilterKind_Any():
+return any(_that);case RoomListFilterKind_NonSpace():
+return nonSpace(_that);case RoomListFilterKind_NonLeft():
+return nonLeft(_that);case RoomListFilterKind_Joined():
+return joined(_that);case RoomListFilterKind_Unread():
+return unread(_that);case RoomListFilterKind_Favourite():
+return favourite(_that);case RoomListFilterKind_LowPriority():
+return lowPriority(_that);case RoomListFilterKind_NonLowPriority():
+return nonLowPriority(_that);case RoomListFilterKind_Invite():
+return invite(_that);case RoomListFilterKind_Category():
+return category(_that);case RoomListFilterKind_None():
+return none(_that);case RoomListFilterKind_NormalizedMatchRoomName():
+return normalizedMatchRoomName(_that);case RoomListFilterKind_FuzzyMatchRoomName():
+return fuzzyMatchRoomName(_that);case RoomListFilterKind_DeduplicateVersions():
+return deduplicateVersions(_that);case RoomListFilterKind_Tag():
+return tag(_that);case RoomListFilterKind_Space():
+return space(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RoomListFilterKind_All value)?  all,TResult? Function( RoomListFilterKind_Any value)?  any,TResult? Function( RoomListFilterKind_NonSpace value)?  nonSpace,TResult? Function( RoomListFilterKind_NonLeft value)?  nonLeft,TResult? Function( RoomListFilterKind_Joined value)?  joined,TResult? Function( RoomListFilterKind_Unread value)?  unread,TResult? Function( RoomListFilterKind_Favourite value)?  favourite,TResult? Function( RoomListFilterKind_LowPriority value)?  lowPriority,TResult? Function( RoomListFilterKind_NonLowPriority value)?  nonLowPriority,TResult? Function( RoomListFilterKind_Invite value)?  invite,TResult? Function( RoomListFilterKind_Category value)?  category,TResult? Function( RoomListFilterKind_None value)?  none,TResult? Function( RoomListFilterKind_NormalizedMatchRoomName value)?  normalizedMatchRoomName,TResult? Function( RoomListFilterKind_FuzzyMatchRoomName value)?  fuzzyMatchRoomName,TResult? Function( RoomListFilterKind_DeduplicateVersions value)?  deduplicateVersions,TResult? Function( RoomListFilterKind_Tag value)?  tag,TResult? Function( RoomListFilterKind_Space value)?  space,}){
+final _that = this;
+switch (_that) {
+case RoomListFilterKind_All() when all != null:
+return all(_that);case RoomListFilterKind_Any() when any != null:
+return any(_that);case RoomListFilterKind_NonSpace() when nonSpace != null:
+return nonSpace(_that);case RoomListFilterKind_NonLeft() when nonLeft != null:
+return nonLeft(_that);case RoomListFilterKind_Joined() when joined != null:
+return joined(_that);case RoomListFilterKind_Unread() when unread != null:
+return unread(_that);case RoomListFilterKind_Favourite() when favourite != null:
+return favourite(_that);case RoomListFilterKind_LowPriority() when lowPriority != null:
+return lowPriority(_that);case RoomListFilterKind_NonLowPriority() when nonLowPriority != null:
+return nonLowPriority(_that);case RoomListFilterKind_Invite() when invite != null:
+return invite(_that);case RoomListFilterKind_Category() when category != null:
+return category(_that);case RoomListFilterKind_None() when none != null:
+return none(_that);case RoomListFilterKind_NormalizedMatchRoomName() when normalizedMatchRoomName != null:
+return normalizedMatchRoomName(_that);case RoomListFilterKind_FuzzyMatchRoomName() when fuzzyMatchRoomName != null:
+return fuzzyMatchRoomName(_that);case RoomListFilterKind_DeduplicateVersions() when deduplicateVersions != null:
+return deduplicateVersions(_that);case RoomListFilterKind_Tag() when tag != null:
+return tag(_that);case RoomListFilterKind_Space() when space != null:
+return space(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( List<RoomListFilterKind> filters)?  all,TResult Function( List<RoomListFilterKind> filters)?  any,TResult Function()?  nonSpace,TResult Function()?  nonLeft,TResult Function()?  joined,TResult Function()?  unread,TResult Function()?  favourite,TResult Function()?  lowPriority,TResult Function()?  nonLowPriority,TResult Function()?  invite,TResult Function( RoomListFilterCategory expect)?  category,TResult Function()?  none,TResult Function( String pattern)?  normalizedMatchRoomName,TResult Function( String pattern)?  fuzzyMatchRoomName,TResult Function()?  deduplicateVersions,TResult Function( String name)?  tag,TResult Function( String spaceId)?  space,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case RoomListFilterKind_All() when all != null:
+return all(_that.filters);case RoomListFilterKind_Any() when any != null:
+return any(_that.filters);case RoomListFilterKind_NonSpace() when nonSpace != null:
+return nonSpace();case RoomListFilterKind_NonLeft() when nonLeft != null:
+return nonLeft();case RoomListFilterKind_Joined() when joined != null:
+return joined();case RoomListFilterKind_Unread() when unread != null:
+return unread();case RoomListFilterKind_Favourite() when favourite != null:
+return favourite();case RoomListFilterKind_LowPriority() when lowPriority != null:
+return lowPriority();case RoomListFilterKind_NonLowPriority() when nonLowPriority != null:
+return nonLowPriority();case RoomListFilterKind_Invite() when invite != null:
+return invite();case RoomListFilterKind_Category() when category != null:
+return category(_that.expect);case RoomListFilterKind_None() when none != null:
+return none();case RoomListFilterKind_NormalizedMatchRoomName() when normalizedMatchRoomName != null:
+return normalizedMatchRoomName(_that.pattern);case RoomListFilterKind_FuzzyMatchRoomName() when fuzzyMatchRoomName != null:
+return fuzzyMatchRoomName(_that.pattern);case RoomListFilterKind_DeduplicateVersions() when deduplicateVersions != null:
+return deduplicateVersions();case RoomListFilterKind_Tag() when tag != null:
+return tag(_that.name);case RoomListFilterKind_Space() when space != null:
+return space(_that.spaceId);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( List<RoomListFilterKind> filters)  all,required TResult Function( List<RoomListFilterKind> filters)  any,required TResult Function()  nonSpace,required TResult Function()  nonLeft,required TResult Function()  joined,required TResult Function()  unread,required TResult Function()  favourite,required TResult Function()  lowPriority,required TResult Function()  nonLowPriority,required TResult Function()  invite,required TResult Function( RoomListFilterCategory expect)  category,required TResult Function()  none,required TResult Function( String pattern)  normalizedMatchRoomName,required TResult Function( String pattern)  fuzzyMatchRoomName,required TResult Function()  deduplicateVersions,required TResult Function( String name)  tag,required TResult Function( String spaceId)  space,}) {final _that = this;
+switch (_that) {
+case RoomListFilterKind_All():
+return all(_that.filters);case RoomListFilterKind_Any():
+return any(_that.filters);case RoomListFilterKind_NonSpace():
+return nonSpace();case RoomListFilterKind_NonLeft():
+return nonLeft();case RoomListFilterKind_Joined():
+return joined();case RoomListFilterKind_Unread():
+return unread();case RoomListFilterKind_Favourite():
+return favourite();case RoomListFilterKind_LowPriority():
+return lowPriority();case RoomListFilterKind_NonLowPriority():
+return nonLowPriority();case RoomListFilterKind_Invite():
+return invite();case RoomListFilterKind_Category():
+return category(_that.expect);case RoomListFilterKind_None():
+return none();case RoomListFilterKind_NormalizedMatchRoomName():
+return normalizedMatchRoomName(_that.pattern);case RoomListFilterKind_FuzzyMatchRoomName():
+return fuzzyMatchRoomName(_that.pattern);case RoomListFilterKind_DeduplicateVersions():
+return deduplicateVersions();case RoomListFilterKind_Tag():
+return tag(_that.name);case RoomListFilterKind_Space():
+return space(_that.spaceId);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( List<RoomListFilterKind> filters)?  all,TResult? Function( List<RoomListFilterKind> filters)?  any,TResult? Function()?  nonSpace,TResult? Function()?  nonLeft,TResult? Function()?  joined,TResult? Function()?  unread,TResult? Function()?  favourite,TResult? Function()?  lowPriority,TResult? Function()?  nonLowPriority,TResult? Function()?  invite,TResult? Function( RoomListFilterCategory expect)?  category,TResult? Function()?  none,TResult? Function( String pattern)?  normalizedMatchRoomName,TResult? Function( String pattern)?  fuzzyMatchRoomName,TResult? Function()?  deduplicateVersions,TResult? Function( String name)?  tag,TResult? Function( String spaceId)?  space,}) {final _that = this;
+switch (_that) {
+case RoomListFilterKind_All() when all != null:
+return all(_that.filters);case RoomListFilterKind_Any() when any != null:
+return any(_that.filters);case RoomListFilterKind_NonSpace() when nonSpace != null:
+return nonSpace();case RoomListFilterKind_NonLeft() when nonLeft != null:
+return nonLeft();case RoomListFilterKind_Joined() when joined != null:
+return joined();case RoomListFilterKind_Unread() when unread != null:
+return unread();case RoomListFilterKind_Favourite() when favourite != null:
+return favourite();case RoomListFilterKind_LowPriority() when lowPriority != null:
+return lowPriority();case RoomListFilterKind_NonLowPriority() when nonLowPriority != null:
+return nonLowPriority();case RoomListFilterKind_Invite() when invite != null:
+return invite();case RoomListFilterKind_Category() when category != null:
+return category(_that.expect);case RoomListFilterKind_None() when none != null:
+return none();case RoomListFilterKind_NormalizedMatchRoomName() when normalizedMatchRoomName != null:
+return normalizedMatchRoomName(_that.pattern);case RoomListFilterKind_FuzzyMatchRoomName() when fuzzyMatchRoomName != null:
+return fuzzyMatchRoomName(_that.pattern);case RoomListFilterKind_DeduplicateVersions() when deduplicateVersions != null:
+return deduplicateVersions();case RoomListFilterKind_Tag() when tag != null:
+return tag(_that.name);case RoomListFilterKind_Space() when space != null:
+return space(_that.spaceId);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_All extends RoomListFilterKind {
+  const RoomListFilterKind_All({required final  List<RoomListFilterKind> filters}): _filters = filters,super._();
+  
+
+ final  List<RoomListFilterKind> _filters;
+ List<RoomListFilterKind> get filters {
+  if (_filters is EqualUnmodifiableListView) return _filters;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_filters);
+}
+
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_AllCopyWith<RoomListFilterKind_All> get copyWith => _$RoomListFilterKind_AllCopyWithImpl<RoomListFilterKind_All>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_All&&const DeepCollectionEquality().equals(other._filters, _filters));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_filters));
+
+@override
+String toString() {
+  return 'RoomListFilterKind.all(filters: $filters)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_AllCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_AllCopyWith(RoomListFilterKind_All value, $Res Function(RoomListFilterKind_All) _then) = _$RoomListFilterKind_AllCopyWithImpl;
+@useResult
+$Res call({
+ List<RoomListFilterKind> filters
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_AllCopyWithImpl<$Res>
+    implements $RoomListFilterKind_AllCopyWith<$Res> {
+  _$RoomListFilterKind_AllCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_All _self;
+  final $Res Function(RoomListFilterKind_All) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? filters = null,}) {
+  return _then(RoomListFilterKind_All(
+filters: null == filters ? _self._filters : filters // ignore: cast_nullable_to_non_nullable
+as List<RoomListFilterKind>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Any extends RoomListFilterKind {
+  const RoomListFilterKind_Any({required final  List<RoomListFilterKind> filters}): _filters = filters,super._();
+  
+
+ final  List<RoomListFilterKind> _filters;
+ List<RoomListFilterKind> get filters {
+  if (_filters is EqualUnmodifiableListView) return _filters;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_filters);
+}
+
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_AnyCopyWith<RoomListFilterKind_Any> get copyWith => _$RoomListFilterKind_AnyCopyWithImpl<RoomListFilterKind_Any>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Any&&const DeepCollectionEquality().equals(other._filters, _filters));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_filters));
+
+@override
+String toString() {
+  return 'RoomListFilterKind.any(filters: $filters)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_AnyCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_AnyCopyWith(RoomListFilterKind_Any value, $Res Function(RoomListFilterKind_Any) _then) = _$RoomListFilterKind_AnyCopyWithImpl;
+@useResult
+$Res call({
+ List<RoomListFilterKind> filters
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_AnyCopyWithImpl<$Res>
+    implements $RoomListFilterKind_AnyCopyWith<$Res> {
+  _$RoomListFilterKind_AnyCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_Any _self;
+  final $Res Function(RoomListFilterKind_Any) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? filters = null,}) {
+  return _then(RoomListFilterKind_Any(
+filters: null == filters ? _self._filters : filters // ignore: cast_nullable_to_non_nullable
+as List<RoomListFilterKind>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_NonSpace extends RoomListFilterKind {
+  const RoomListFilterKind_NonSpace(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_NonSpace);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.nonSpace()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_NonLeft extends RoomListFilterKind {
+  const RoomListFilterKind_NonLeft(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_NonLeft);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.nonLeft()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Joined extends RoomListFilterKind {
+  const RoomListFilterKind_Joined(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Joined);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.joined()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Unread extends RoomListFilterKind {
+  const RoomListFilterKind_Unread(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Unread);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.unread()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Favourite extends RoomListFilterKind {
+  const RoomListFilterKind_Favourite(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Favourite);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.favourite()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_LowPriority extends RoomListFilterKind {
+  const RoomListFilterKind_LowPriority(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_LowPriority);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.lowPriority()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_NonLowPriority extends RoomListFilterKind {
+  const RoomListFilterKind_NonLowPriority(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_NonLowPriority);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.nonLowPriority()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Invite extends RoomListFilterKind {
+  const RoomListFilterKind_Invite(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Invite);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.invite()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Category extends RoomListFilterKind {
+  const RoomListFilterKind_Category({required this.expect}): super._();
+  
+
+ final  RoomListFilterCategory expect;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_CategoryCopyWith<RoomListFilterKind_Category> get copyWith => _$RoomListFilterKind_CategoryCopyWithImpl<RoomListFilterKind_Category>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Category&&(identical(other.expect, expect) || other.expect == expect));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,expect);
+
+@override
+String toString() {
+  return 'RoomListFilterKind.category(expect: $expect)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_CategoryCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_CategoryCopyWith(RoomListFilterKind_Category value, $Res Function(RoomListFilterKind_Category) _then) = _$RoomListFilterKind_CategoryCopyWithImpl;
+@useResult
+$Res call({
+ RoomListFilterCategory expect
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_CategoryCopyWithImpl<$Res>
+    implements $RoomListFilterKind_CategoryCopyWith<$Res> {
+  _$RoomListFilterKind_CategoryCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_Category _self;
+  final $Res Function(RoomListFilterKind_Category) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? expect = null,}) {
+  return _then(RoomListFilterKind_Category(
+expect: null == expect ? _self.expect : expect // ignore: cast_nullable_to_non_nullable
+as RoomListFilterCategory,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_None extends RoomListFilterKind {
+  const RoomListFilterKind_None(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_None);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.none()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_NormalizedMatchRoomName extends RoomListFilterKind {
+  const RoomListFilterKind_NormalizedMatchRoomName({required this.pattern}): super._();
+  
+
+ final  String pattern;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_NormalizedMatchRoomNameCopyWith<RoomListFilterKind_NormalizedMatchRoomName> get copyWith => _$RoomListFilterKind_NormalizedMatchRoomNameCopyWithImpl<RoomListFilterKind_NormalizedMatchRoomName>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_NormalizedMatchRoomName&&(identical(other.pattern, pattern) || other.pattern == pattern));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,pattern);
+
+@override
+String toString() {
+  return 'RoomListFilterKind.normalizedMatchRoomName(pattern: $pattern)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_NormalizedMatchRoomNameCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_NormalizedMatchRoomNameCopyWith(RoomListFilterKind_NormalizedMatchRoomName value, $Res Function(RoomListFilterKind_NormalizedMatchRoomName) _then) = _$RoomListFilterKind_NormalizedMatchRoomNameCopyWithImpl;
+@useResult
+$Res call({
+ String pattern
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_NormalizedMatchRoomNameCopyWithImpl<$Res>
+    implements $RoomListFilterKind_NormalizedMatchRoomNameCopyWith<$Res> {
+  _$RoomListFilterKind_NormalizedMatchRoomNameCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_NormalizedMatchRoomName _self;
+  final $Res Function(RoomListFilterKind_NormalizedMatchRoomName) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? pattern = null,}) {
+  return _then(RoomListFilterKind_NormalizedMatchRoomName(
+pattern: null == pattern ? _self.pattern : pattern // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_FuzzyMatchRoomName extends RoomListFilterKind {
+  const RoomListFilterKind_FuzzyMatchRoomName({required this.pattern}): super._();
+  
+
+ final  String pattern;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_FuzzyMatchRoomNameCopyWith<RoomListFilterKind_FuzzyMatchRoomName> get copyWith => _$RoomListFilterKind_FuzzyMatchRoomNameCopyWithImpl<RoomListFilterKind_FuzzyMatchRoomName>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_FuzzyMatchRoomName&&(identical(other.pattern, pattern) || other.pattern == pattern));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,pattern);
+
+@override
+String toString() {
+  return 'RoomListFilterKind.fuzzyMatchRoomName(pattern: $pattern)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_FuzzyMatchRoomNameCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_FuzzyMatchRoomNameCopyWith(RoomListFilterKind_FuzzyMatchRoomName value, $Res Function(RoomListFilterKind_FuzzyMatchRoomName) _then) = _$RoomListFilterKind_FuzzyMatchRoomNameCopyWithImpl;
+@useResult
+$Res call({
+ String pattern
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_FuzzyMatchRoomNameCopyWithImpl<$Res>
+    implements $RoomListFilterKind_FuzzyMatchRoomNameCopyWith<$Res> {
+  _$RoomListFilterKind_FuzzyMatchRoomNameCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_FuzzyMatchRoomName _self;
+  final $Res Function(RoomListFilterKind_FuzzyMatchRoomName) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? pattern = null,}) {
+  return _then(RoomListFilterKind_FuzzyMatchRoomName(
+pattern: null == pattern ? _self.pattern : pattern // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_DeduplicateVersions extends RoomListFilterKind {
+  const RoomListFilterKind_DeduplicateVersions(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_DeduplicateVersions);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomListFilterKind.deduplicateVersions()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Tag extends RoomListFilterKind {
+  const RoomListFilterKind_Tag({required this.name}): super._();
+  
+
+ final  String name;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_TagCopyWith<RoomListFilterKind_Tag> get copyWith => _$RoomListFilterKind_TagCopyWithImpl<RoomListFilterKind_Tag>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Tag&&(identical(other.name, name) || other.name == name));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name);
+
+@override
+String toString() {
+  return 'RoomListFilterKind.tag(name: $name)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_TagCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_TagCopyWith(RoomListFilterKind_Tag value, $Res Function(RoomListFilterKind_Tag) _then) = _$RoomListFilterKind_TagCopyWithImpl;
+@useResult
+$Res call({
+ String name
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_TagCopyWithImpl<$Res>
+    implements $RoomListFilterKind_TagCopyWith<$Res> {
+  _$RoomListFilterKind_TagCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_Tag _self;
+  final $Res Function(RoomListFilterKind_Tag) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? name = null,}) {
+  return _then(RoomListFilterKind_Tag(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomListFilterKind_Space extends RoomListFilterKind {
+  const RoomListFilterKind_Space({required this.spaceId}): super._();
+  
+
+ final  String spaceId;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomListFilterKind_SpaceCopyWith<RoomListFilterKind_Space> get copyWith => _$RoomListFilterKind_SpaceCopyWithImpl<RoomListFilterKind_Space>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomListFilterKind_Space&&(identical(other.spaceId, spaceId) || other.spaceId == spaceId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,spaceId);
+
+@override
+String toString() {
+  return 'RoomListFilterKind.space(spaceId: $spaceId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomListFilterKind_SpaceCopyWith<$Res> implements $RoomListFilterKindCopyWith<$Res> {
+  factory $RoomListFilterKind_SpaceCopyWith(RoomListFilterKind_Space value, $Res Function(RoomListFilterKind_Space) _then) = _$RoomListFilterKind_SpaceCopyWithImpl;
+@useResult
+$Res call({
+ String spaceId
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomListFilterKind_SpaceCopyWithImpl<$Res>
+    implements $RoomListFilterKind_SpaceCopyWith<$Res> {
+  _$RoomListFilterKind_SpaceCopyWithImpl(this._self, this._then);
+
+  final RoomListFilterKind_Space _self;
+  final $Res Function(RoomListFilterKind_Space) _then;
+
+/// Create a copy of RoomListFilterKind
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? spaceId = null,}) {
+  return _then(RoomListFilterKind_Space(
+spaceId: null == spaceId ? _self.spaceId : spaceId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
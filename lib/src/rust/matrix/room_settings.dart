@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'rooms.dart';
+part 'room_settings.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get_allowed_room_ids`, `parse_allowed_rooms`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `get_room_history_visibility`, `get_room_join_rule`
+
+Future<void> setRoomName({required String roomId, required String name}) =>
+    RustLib.instance.api.crateMatrixRoomSettingsSetRoomName(
+      roomId: roomId,
+      name: name,
+    );
+
+Future<void> setRoomTopic({required String roomId, required String topic}) =>
+    RustLib.instance.api.crateMatrixRoomSettingsSetRoomTopic(
+      roomId: roomId,
+      topic: topic,
+    );
+
+/// Upload `data` and make it the room avatar. `mime_type` is e.g. `image/png`.
+Future<void> setRoomAvatar({
+  required String roomId,
+  required Uint8List data,
+  required String mimeType,
+}) => RustLib.instance.api.crateMatrixRoomSettingsSetRoomAvatar(
+  roomId: roomId,
+  data: data,
+  mimeType: mimeType,
+);
+
+Future<void> removeRoomAvatar({required String roomId}) =>
+    RustLib.instance.api.crateMatrixRoomSettingsRemoveRoomAvatar(
+      roomId: roomId,
+    );
+
+/// Turn on end-to-end encryption. It can't be turned off again.
+Future<void> enableRoomEncryption({required String roomId}) =>
+    RustLib.instance.api.crateMatrixRoomSettingsEnableRoomEncryption(
+      roomId: roomId,
+    );
+
+/// Change who can read the history; only applies to messages sent afterwards.
+Future<void> setRoomHistoryVisibility({
+  required String roomId,
+  required RoomHistoryVisibility historyVisibility,
+}) => RustLib.instance.api.crateMatrixRoomSettingsSetRoomHistoryVisibility(
+  roomId: roomId,
+  historyVisibility: historyVisibility,
+);
+
+Future<void> setRoomJoinRule({
+  required String roomId,
+  required RoomJoinRule joinRule,
+}) => RustLib.instance.api.crateMatrixRoomSettingsSetRoomJoinRule(
+  roomId: roomId,
+  joinRule: joinRule,
+);
+
+/// Upgrade the room to `new_version`. The server creates the new room and
+/// tombstones this one; returns the ID of the new room.
+Future<String> upgradeRoom({
+  required String roomId,
+  required String newVersion,
+}) => RustLib.instance.api.crateMatrixRoomSettingsUpgradeRoom(
+  roomId: roomId,
+  newVersion: newVersion,
+);
+
+/// Who can join a room.
+@freezed
+sealed class RoomJoinRule with _$RoomJoinRule {
+  const RoomJoinRule._();
+
+  const factory RoomJoinRule.public() = RoomJoinRule_Public;
+  const factory RoomJoinRule.invite() = RoomJoinRule_Invite;
+  const factory RoomJoinRule.knock() = RoomJoinRule_Knock;
+
+  /// Members of one of `allowed_room_ids` can join without an invite.
+  const factory RoomJoinRule.restricted({
+    required List<String> allowedRoomIds,
+  }) = RoomJoinRule_Restricted;
+
+  /// Like `Restricted`, everyone else can knock.
+  const factory RoomJoinRule.knockRestricted({
+    required List<String> allowedRoomIds,
+  }) = RoomJoinRule_KnockRestricted;
+}
@@ -0,0 +1,423 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'room_settings.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$RoomJoinRule {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomJoinRule()';
+}
+
+
+}
+
+/// @nodoc
+class $RoomJoinRuleCopyWith<$Res>  {
+$RoomJoinRuleCopyWith(RoomJoinRule _, $Res Function(RoomJoinRule) __);
+}
+
+
+/// Adds pattern-matching-related methods to [RoomJoinRule].
+extension RoomJoinRulePatterns on RoomJoinRule {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RoomJoinRule_Public value)?  public,TResult Function( RoomJoinRule_Invite value)?  invite,TResult Function( RoomJoinRule_Knock value)?  knock,TResult Function( RoomJoinRule_Restricted value)?  restricted,TResult Function( RoomJoinRule_KnockRestricted value)?  knockRestricted,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case RoomJoinRule_Public() when public != null:
+return public(_that);case RoomJoinRule_Invite() when invite != null:
+return invite(_that);case RoomJoinRule_Knock() when knock != null:
+return knock(_that);case RoomJoinRule_Restricted() when restricted != null:
+return restricted(_that);case RoomJoinRule_KnockRestricted() when knockRestricted != null:
+return knockRestricted(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RoomJoinRule_Public value)  public,required TResult Function( RoomJoinRule_Invite value)  invite,required TResult Function( RoomJoinRule_Knock value)  knock,required TResult Function( RoomJoinRule_Restricted value)  restricted,required TResult Function( RoomJoinRule_KnockRestricted value)  knockRestricted,}){
+final _that = this;
+switch (_that) {
+case RoomJoinRule_Public():
+return public(_that);case RoomJoinRule_Invite():
+return invite(_that);case RoomJoinRule_Knock():
+return knock(_that);case RoomJoinRule_Restricted():
+return restricted(_that);case RoomJoinRule_KnockRestricted():
+return knockRestricted(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RoomJoinRule_Public value)?  public,TResult? Function( RoomJoinRule_Invite value)?  invite,TResult? Function( RoomJoinRule_Knock value)?  knock,TResult? Function( RoomJoinRule_Restricted value)?  restricted,TResult? Function( RoomJoinRule_KnockRestricted value)?  knockRestricted,}){
+final _that = this;
+switch (_that) {
+case RoomJoinRule_Public() when public != null:
+return public(_that);case RoomJoinRule_Invite() when invite != null:
+return invite(_that);case RoomJoinRule_Knock() when knock != null:
+return knock(_that);case RoomJoinRule_Restricted() when restricted != null:
+return restricted(_that);case RoomJoinRule_KnockRestricted() when knockRestricted != null:
+return knockRestricted(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  public,TResult Function()?  invite,TResult Function()?  knock,TResult Function( List<String> allowedRoomIds)?  restricted,TResult Function( List<String> allowedRoomIds)?  knockRestricted,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case RoomJoinRule_Public() when public != null:
+return public();case RoomJoinRule_Invite() when invite != null:
+return invite();case RoomJoinRule_Knock() when knock != null:
+return knock();case RoomJoinRule_Restricted() when restricted != null:
+return restricted(_that.allowedRoomIds);case RoomJoinRule_KnockRestricted() when knockRestricted != null:
+return knockRestricted(_that.allowedRoomIds);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  public,required TResult Function()  invite,required TResult Function()  knock,required TResult Function( List<String> allowedRoomIds)  restricted,required TResult Function( List<String> allowedRoomIds)  knockRestricted,}) {final _that = this;
+switch (_that) {
+case RoomJoinRule_Public():
+return public();case RoomJoinRule_Invite():
+return invite();case RoomJoinRule_Knock():
+return knock();case RoomJoinRule_Restricted():
+return restricted(_that.allowedRoomIds);case RoomJoinRule_KnockRestricted():
+return knockRestricted(_that.allowedRoomIds);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  public,TResult? Function()?  invite,TResult? Function()?  knock,TResult? Function( List<String> allowedRoomIds)?  restricted,TResult? Function( List<String> allowedRoomIds)?  knockRestricted,}) {final _that = this;
+switch (_that) {
+case RoomJoinRule_Public() when public != null:
+return public();case RoomJoinRule_Invite() when invite != null:
+return invite();case RoomJoinRule_Knock() when knock != null:
+return knock();case RoomJoinRule_Restricted() when restricted != null:
+return restricted(_that.allowedRoomIds);case RoomJoinRule_KnockRestricted() when knockRestricted != null:
+return knockRestricted(_that.allowedRoomIds);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class RoomJoinRule_Public extends RoomJoinRule {
+  const RoomJoinRule_Public(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule_Public);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomJoinRule.public()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomJoinRule_Invite extends RoomJoinRule {
+  const RoomJoinRule_Invite(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule_Invite);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomJoinRule.invite()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomJoinRule_Knock extends RoomJoinRule {
+  const RoomJoinRule_Knock(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule_Knock);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RoomJoinRule.knock()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomJoinRule_Restricted extends RoomJoinRule {
+  const RoomJoinRule_Restricted({required final  List<String> allowedRoomIds}): _allowedRoomIds = allowedRoomIds,super._();
+  
+
+ final  List<String> _allowedRoomIds;
+ List<String> get allowedRoomIds {
+  if (_allowedRoomIds is EqualUnmodifiableListView) return _allowedRoomIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_allowedRoomIds);
+}
+
+
+/// Create a copy of RoomJoinRule
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomJoinRule_RestrictedCopyWith<RoomJoinRule_Restricted> get copyWith => _$RoomJoinRule_RestrictedCopyWithImpl<RoomJoinRule_Restricted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule_Restricted&&const DeepCollectionEquality().equals(other._allowedRoomIds, _allowedRoomIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_allowedRoomIds));
+
+@override
+String toString() {
+  return 'RoomJoinRule.restricted(allowedRoomIds: $allowedRoomIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomJoinRule_RestrictedCopyWith<$Res> implements $RoomJoinRuleCopyWith<$Res> {
+  factory $RoomJoinRule_RestrictedCopyWith(RoomJoinRule_Restricted value, $Res Function(RoomJoinRule_Restricted) _then) = _$RoomJoinRule_RestrictedCopyWithImpl;
+@useResult
+$Res call({
+ List<String> allowedRoomIds
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomJoinRule_RestrictedCopyWithImpl<$Res>
+    implements $RoomJoinRule_RestrictedCopyWith<$Res> {
+  _$RoomJoinRule_RestrictedCopyWithImpl(this._self, this._then);
+
+  final RoomJoinRule_Restricted _self;
+  final $Res Function(RoomJoinRule_Restricted) _then;
+
+/// Create a copy of RoomJoinRule
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? allowedRoomIds = null,}) {
+  return _then(RoomJoinRule_Restricted(
+allowedRoomIds: null == allowedRoomIds ? _self._allowedRoomIds : allowedRoomIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class RoomJoinRule_KnockRestricted extends RoomJoinRule {
+  const RoomJoinRule_KnockRestricted({required final  List<String> allowedRoomIds}): _allowedRoomIds = allowedRoomIds,super._();
+  
+
+ final  List<String> _allowedRoomIds;
+ List<String> get allowedRoomIds {
+  if (_allowedRoomIds is EqualUnmodifiableListView) return _allowedRoomIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_allowedRoomIds);
+}
+
+
+/// Create a copy of RoomJoinRule
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RoomJoinRule_KnockRestrictedCopyWith<RoomJoinRule_KnockRestricted> get copyWith => _$RoomJoinRule_KnockRestrictedCopyWithImpl<RoomJoinRule_KnockRestricted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RoomJoinRule_KnockRestricted&&const DeepCollectionEquality().equals(other._allowedRoomIds, _allowedRoomIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_allowedRoomIds));
+
+@override
+String toString() {
+  return 'RoomJoinRule.knockRestricted(allowedRoomIds: $allowedRoomIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RoomJoinRule_KnockRestrictedCopyWith<$Res> implements $RoomJoinRuleCopyWith<$Res> {
+  factory $RoomJoinRule_KnockRestrictedCopyWith(RoomJoinRule_KnockRestricted value, $Res Function(RoomJoinRule_KnockRestricted) _then) = _$RoomJoinRule_KnockRestrictedCopyWithImpl;
+@useResult
+$Res call({
+ List<String> allowedRoomIds
+});
+
+
+
+
+}
+/// @nodoc
+class _$RoomJoinRule_KnockRestrictedCopyWithImpl<$Res>
+    implements $RoomJoinRule_KnockRestrictedCopyWith<$Res> {
+  _$RoomJoinRule_KnockRestrictedCopyWithImpl(this._self, this._then);
+
+  final RoomJoinRule_KnockRestricted _self;
+  final $Res Function(RoomJoinRule_KnockRestricted) _then;
+
+/// Create a copy of RoomJoinRule
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? allowedRoomIds = null,}) {
+  return _then(RoomJoinRule_KnockRestricted(
+allowedRoomIds: null == allowedRoomIds ? _self._allowedRoomIds : allowedRoomIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -5,17 +5,26 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_list.dart';
+import 'room_settings.dart';
+import 'tags.dart';
 import 'timelines.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_room_update_data`
+// These functions are ignored because they are not marked as `pub`: `get_latest_message`, `get_power_levels_override`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExtraRoomInfo`, `RoomList`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `create_room_with_params`, `from`, `get_room_update_data`, `get_room`
 // These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `get_room_by_id`, `new`
 
 Future<List<RoomUpdate>> getAllRooms() =>
     RustLib.instance.api.crateMatrixRoomsGetAllRooms();
 
-Stream<RoomUpdate> subscribeToAllRoomUpdates() =>
+/// Stream the room list as diffs, with the same ordering and indices as the
+/// SDK's room list.
+///
+/// The first batch is a `Reset` with the current rooms, the following ones
+/// must be applied on top of it, in order.
+Stream<List<RoomListUpdate>> subscribeToAllRoomUpdates() =>
     RustLib.instance.api.crateMatrixRoomsSubscribeToAllRoomUpdates();
 
 Future<String> sendMessage({required String roomId, required String content}) =>
@@ -24,6 +33,10 @@ Future<String> sendMessage({required String roomId, required String content}) =>
       content: content,
     );
 
+/// Always creates a new DM, see [`get_or_create_dm`] to reuse an existing
+/// one.
+///
+/// [`get_or_create_dm`]: crate::matrix::direct_messages::get_or_create_dm
 Future<String> createDirectRoom({required String userId}) =>
     RustLib.instance.api.crateMatrixRoomsCreateDirectRoom(userId: userId);
 
@@ -35,12 +48,196 @@ Future<String> createGroupRoom({
   userIds: userIds,
 );
 
+/// Create a room with all the options of [`CreateRoomParams`]. Returns the ID
+/// of the new room.
+Future<String> createRoom({required CreateRoomParams params}) =>
+    RustLib.instance.api.crateMatrixRoomsCreateRoom(params: params);
+
 Future<String> joinRoom({required String roomId}) =>
     RustLib.instance.api.crateMatrixRoomsJoinRoom(roomId: roomId);
 
 Future<String> leaveRoom({required String roomId}) =>
     RustLib.instance.api.crateMatrixRoomsLeaveRoom(roomId: roomId);
 
+class CreateRoomParams {
+  final String? name;
+  final String? topic;
+
+  /// `mxc://` URI of an already uploaded avatar.
+  final String? avatarUrl;
+
+  /// Local part of the alias to create, e.g. `team` for `#team:example.org`.
+  final String? alias;
+  final RoomVisibility visibility;
+  final bool isEncrypted;
+  final RoomHistoryVisibility? historyVisibility;
+
+  /// Whether guests can join, `None` keeps the server's default for the
+  /// preset.
+  final bool? guestAccess;
+  final PowerLevelOverrides? powerLevelOverrides;
+  final String? roomVersion;
+
+  /// Space to add the room to, we need to be allowed to add children to it.
+  final String? spaceParentId;
+  final List<String> invite;
+  final bool isDirect;
+
+  /// Create a space instead of a room, see `spaces::create_space`.
+  final bool isSpace;
+
+  const CreateRoomParams({
+    this.name,
+    this.topic,
+    this.avatarUrl,
+    this.alias,
+    required this.visibility,
+    required this.isEncrypted,
+    this.historyVisibility,
+    this.guestAccess,
+    this.powerLevelOverrides,
+    this.roomVersion,
+    this.spaceParentId,
+    required this.invite,
+    required this.isDirect,
+    required this.isSpace,
+  });
+
+  static Future<CreateRoomParams> default_() =>
+      RustLib.instance.api.crateMatrixRoomsCreateRoomParamsDefault();
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      alias.hashCode ^
+      visibility.hashCode ^
+      isEncrypted.hashCode ^
+      historyVisibility.hashCode ^
+      guestAccess.hashCode ^
+      powerLevelOverrides.hashCode ^
+      roomVersion.hashCode ^
+      spaceParentId.hashCode ^
+      invite.hashCode ^
+      isDirect.hashCode ^
+      isSpace.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreateRoomParams &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          alias == other.alias &&
+          visibility == other.visibility &&
+          isEncrypted == other.isEncrypted &&
+          historyVisibility == other.historyVisibility &&
+          guestAccess == other.guestAccess &&
+          powerLevelOverrides == other.powerLevelOverrides &&
+          roomVersion == other.roomVersion &&
+          spaceParentId == other.spaceParentId &&
+          invite == other.invite &&
+          isDirect == other.isDirect &&
+          isSpace == other.isSpace;
+}
+
+class EventPowerLevel {
+  final String eventType;
+  final PlatformInt64 powerLevel;
+
+  const EventPowerLevel({required this.eventType, required this.powerLevel});
+
+  @override
+  int get hashCode => eventType.hashCode ^ powerLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventPowerLevel &&
+          runtimeType == other.runtimeType &&
+          eventType == other.eventType &&
+          powerLevel == other.powerLevel;
+}
+
+/// Power levels to change from the server defaults when creating a room; unset
+/// fields keep their default.
+class PowerLevelOverrides {
+  final PlatformInt64? ban;
+  final PlatformInt64? invite;
+  final PlatformInt64? kick;
+  final PlatformInt64? redact;
+  final PlatformInt64? eventsDefault;
+  final PlatformInt64? stateDefault;
+  final PlatformInt64? usersDefault;
+  final List<EventPowerLevel> events;
+  final List<UserPowerLevel> users;
+
+  const PowerLevelOverrides({
+    this.ban,
+    this.invite,
+    this.kick,
+    this.redact,
+    this.eventsDefault,
+    this.stateDefault,
+    this.usersDefault,
+    required this.events,
+    required this.users,
+  });
+
+  static Future<PowerLevelOverrides> default_() =>
+      RustLib.instance.api.crateMatrixRoomsPowerLevelOverridesDefault();
+
+  @override
+  int get hashCode =>
+      ban.hashCode ^
+      invite.hashCode ^
+      kick.hashCode ^
+      redact.hashCode ^
+      eventsDefault.hashCode ^
+      stateDefault.hashCode ^
+      usersDefault.hashCode ^
+      events.hashCode ^
+      users.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PowerLevelOverrides &&
+          runtimeType == other.runtimeType &&
+          ban == other.ban &&
+          invite == other.invite &&
+          kick == other.kick &&
+          redact == other.redact &&
+          eventsDefault == other.eventsDefault &&
+          stateDefault == other.stateDefault &&
+          usersDefault == other.usersDefault &&
+          events == other.events &&
+          users == other.users;
+}
+
+enum RoomHistoryVisibility { invited, joined, shared, worldReadable }
+
+class RoomSuccessor {
+  final String roomId;
+  final String? reason;
+
+  const RoomSuccessor({required this.roomId, this.reason});
+
+  @override
+  int get hashCode => roomId.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomSuccessor &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          reason == other.reason;
+}
+
 class RoomUpdate {
   final String roomId;
   final String? rawName;
@@ -51,7 +248,24 @@ class RoomUpdate {
   final BigInt? unreadHighlight;
   final BigInt? unreadMentions;
   final BigInt? unreadMessages;
+  final bool isMarkedUnread;
   final Message? message;
+  final List<RoomTag> tags;
+  final String? topic;
+  final String? avatarUrl;
+  final String? canonicalAlias;
+
+  /// Is end-to-end encryption enabled? `false` while the encryption state
+  /// hasn't been synced yet.
+  final bool isEncrypted;
+  final RoomHistoryVisibility? historyVisibility;
+  final RoomJoinRule? joinRule;
+
+  /// Set once the room was upgraded, the UI should offer to join it.
+  final RoomSuccessor? successor;
+
+  /// The room this one was upgraded from.
+  final String? predecessorRoomId;
 
   const RoomUpdate({
     required this.roomId,
@@ -63,7 +277,17 @@ class RoomUpdate {
     this.unreadHighlight,
     this.unreadMentions,
     this.unreadMessages,
+    required this.isMarkedUnread,
     this.message,
+    required this.tags,
+    this.topic,
+    this.avatarUrl,
+    this.canonicalAlias,
+    required this.isEncrypted,
+    this.historyVisibility,
+    this.joinRule,
+    this.successor,
+    this.predecessorRoomId,
   });
 
   @override
@@ -77,7 +301,17 @@ class RoomUpdate {
       unreadHighlight.hashCode ^
       unreadMentions.hashCode ^
       unreadMessages.hashCode ^
-      message.hashCode;
+      isMarkedUnread.hashCode ^
+      message.hashCode ^
+      tags.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      canonicalAlias.hashCode ^
+      isEncrypted.hashCode ^
+      historyVisibility.hashCode ^
+      joinRule.hashCode ^
+      successor.hashCode ^
+      predecessorRoomId.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -93,7 +327,50 @@ class RoomUpdate {
           unreadHighlight == other.unreadHighlight &&
           unreadMentions == other.unreadMentions &&
           unreadMessages == other.unreadMessages &&
-          message == other.message;
+          isMarkedUnread == other.isMarkedUnread &&
+          message == other.message &&
+          tags == other.tags &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          canonicalAlias == other.canonicalAlias &&
+          isEncrypted == other.isEncrypted &&
+          historyVisibility == other.historyVisibility &&
+          joinRule == other.joinRule &&
+          successor == other.successor &&
+          predecessorRoomId == other.predecessorRoomId;
+}
+
+/// Who can join a room created with [`create_room`].
+enum RoomVisibility {
+  /// Anyone can join, and the room is published in the room directory.
+  public,
+
+  /// Invite only.
+  private,
+
+  /// Anyone can ask to join, see `knocking`.
+  knock;
+
+  static Future<RoomVisibility> default_() =>
+      RustLib.instance.api.crateMatrixRoomsRoomVisibilityDefault();
 }
 
 enum UpdateType { joined, left, invited, knocked, banned }
+
+class UserPowerLevel {
+  final String userId;
+  final PlatformInt64 powerLevel;
+
+  const UserPowerLevel({required this.userId, required this.powerLevel});
+
+  @override
+  int get hashCode => userId.hashCode ^ powerLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserPowerLevel &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          powerLevel == other.powerLevel;
+}
@@ -0,0 +1,138 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'rooms.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get_own_server`, `get_space`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `add_child_to_space`, `get_space_children`
+
+/// All the spaces we are joined to.
+Future<List<RoomUpdate>> getJoinedSpaces() =>
+    RustLib.instance.api.crateMatrixSpacesGetJoinedSpaces();
+
+/// A page of the hierarchy of a space, walked depth first from the space
+/// itself, which is the first room of the first page.
+Future<SpaceHierarchyPage> getSpaceHierarchy({
+  required String spaceId,
+  String? from,
+  int? limit,
+  int? maxDepth,
+  required bool suggestedOnly,
+}) => RustLib.instance.api.crateMatrixSpacesGetSpaceHierarchy(
+  spaceId: spaceId,
+  from: from,
+  limit: limit,
+  maxDepth: maxDepth,
+  suggestedOnly: suggestedOnly,
+);
+
+/// Create a space, same options as for rooms. Returns the ID of the space.
+Future<String> createSpace({required CreateRoomParams params}) =>
+    RustLib.instance.api.crateMatrixSpacesCreateSpace(params: params);
+
+/// Add `room_id` to a space we are allowed to manage.
+Future<void> addRoomToSpace({
+  required String spaceId,
+  required String roomId,
+  required bool suggested,
+}) => RustLib.instance.api.crateMatrixSpacesAddRoomToSpace(
+  spaceId: spaceId,
+  roomId: roomId,
+  suggested: suggested,
+);
+
+/// Remove `room_id` from a space, clearing the `m.space.parent` event too when
+/// we're allowed to.
+Future<void> removeRoomFromSpace({
+  required String spaceId,
+  required String roomId,
+}) => RustLib.instance.api.crateMatrixSpacesRemoveRoomFromSpace(
+  spaceId: spaceId,
+  roomId: roomId,
+);
+
+class SpaceHierarchyPage {
+  final List<SpaceHierarchyRoom> rooms;
+
+  /// Token to pass as `from` to get the next page, `None` on the last page.
+  final String? nextBatch;
+
+  const SpaceHierarchyPage({required this.rooms, this.nextBatch});
+
+  @override
+  int get hashCode => rooms.hashCode ^ nextBatch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpaceHierarchyPage &&
+          runtimeType == other.runtimeType &&
+          rooms == other.rooms &&
+          nextBatch == other.nextBatch;
+}
+
+/// A room or subspace in the hierarchy of a space.
+class SpaceHierarchyRoom {
+  final String roomId;
+  final String? name;
+  final String? topic;
+  final String? avatarUrl;
+  final String? canonicalAlias;
+  final BigInt numJoinedMembers;
+  final bool isSpace;
+
+  /// Whether the parent space suggests joining this room.
+  final bool suggested;
+
+  /// IDs of the direct children, for subspaces.
+  final List<String> children;
+
+  /// Our membership in the room, if we know the room already.
+  final UpdateType? membership;
+
+  const SpaceHierarchyRoom({
+    required this.roomId,
+    this.name,
+    this.topic,
+    this.avatarUrl,
+    this.canonicalAlias,
+    required this.numJoinedMembers,
+    required this.isSpace,
+    required this.suggested,
+    required this.children,
+    this.membership,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      name.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      canonicalAlias.hashCode ^
+      numJoinedMembers.hashCode ^
+      isSpace.hashCode ^
+      suggested.hashCode ^
+      children.hashCode ^
+      membership.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpaceHierarchyRoom &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          name == other.name &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          canonicalAlias == other.canonicalAlias &&
+          numJoinedMembers == other.numJoinedMembers &&
+          isSpace == other.isSpace &&
+          suggested == other.suggested &&
+          children == other.children &&
+          membership == other.membership;
+}
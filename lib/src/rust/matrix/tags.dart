@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `get_room_tags`
+
+Future<void> setRoomFavourite({
+  required String roomId,
+  required bool isFavourite,
+  double? order,
+}) => RustLib.instance.api.crateMatrixTagsSetRoomFavourite(
+  roomId: roomId,
+  isFavourite: isFavourite,
+  order: order,
+);
+
+Future<void> setRoomLowPriority({
+  required String roomId,
+  required bool isLowPriority,
+  double? order,
+}) => RustLib.instance.api.crateMatrixTagsSetRoomLowPriority(
+  roomId: roomId,
+  isLowPriority: isLowPriority,
+  order: order,
+);
+
+/// Set a tag on a room. Custom tags should use the `u.` prefix, e.g.
+/// `u.work`.
+Future<void> setRoomTag({
+  required String roomId,
+  required String tag,
+  double? order,
+}) => RustLib.instance.api.crateMatrixTagsSetRoomTag(
+  roomId: roomId,
+  tag: tag,
+  order: order,
+);
+
+Future<void> removeRoomTag({required String roomId, required String tag}) =>
+    RustLib.instance.api.crateMatrixTagsRemoveRoomTag(roomId: roomId, tag: tag);
+
+/// A `m.tag` entry of a room: `m.favourite`, `m.lowpriority` or a custom
+/// `u.*` tag, with its optional ordering in `[0, 1]`.
+class RoomTag {
+  final String name;
+  final double? order;
+
+  const RoomTag({required this.name, this.order});
+
+  @override
+  int get hashCode => name.hashCode ^ order.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomTag &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          order == other.order;
+}
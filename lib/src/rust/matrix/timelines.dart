@@ -5,30 +5,143 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'receipts.dart';
+part 'timelines.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_timeline_sdk_by_room_id`
+// These functions are ignored because they are not marked as `pub`: `add_subscription`, `evict_idle_timelines`, `get_focused_timeline_by_room_id`, `get_message_updates_from_diffs`, `get_own_receipt_event_id`, `paginate_backwards`, `release_subscription`, `with_live_timeline`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RoomView`, `TimelineKind`, `Timeline`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `clone`, `get_message_from_timeline_item`, `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `build_live`, `clone`, `coalesce_message_updates`, `drop`, `get_message_from_timeline_item`, `get_message_update_from_diff`, `get_or_create_timeline`, `new`, `new`, `preload_room_timelines`
 
 Future<List<Message>> getTimelineItemsByRoomId({required String roomId}) =>
     RustLib.instance.api.crateMatrixTimelinesGetTimelineItemsByRoomId(
       roomId: roomId,
     );
 
-Stream<MessageUpdate> subscribeToTimelineUpdates({required String roomId}) =>
-    RustLib.instance.api.crateMatrixTimelinesSubscribeToTimelineUpdates(
+/// Allocate the ID of a timeline subscription, to pass to
+/// [`subscribe_to_timeline_updates`] and later to
+/// [`unsubscribe_from_timeline_updates`].
+Future<BigInt> newTimelineSubscriptionId() =>
+    RustLib.instance.api.crateMatrixTimelinesNewTimelineSubscriptionId();
+
+/// Stream the updates of a room's live timeline.
+///
+/// Each item of the stream is the batch of updates produced by one timeline
+/// change and must be applied as a whole. With `throttle_ms`, batches coming
+/// in during that window are merged and coalesced into a single one, which
+/// keeps busy rooms from flooding the bridge.
+///
+/// The first batch is a `Reset` with the current items, so the stream alone
+/// is enough to render the room.
+///
+/// All the subscribers of a room share the same timeline, which is built the
+/// first time the room is opened. `subscription_id` comes from
+/// [`new_timeline_subscription_id`] and is what
+/// [`unsubscribe_from_timeline_updates`] stops; the timeline then stays cached
+/// until it gets evicted by more recently used rooms. A stream closed on the
+/// Dart side is only noticed when the next batch fails to be sent, so quiet
+/// rooms keep their subscription until then.
+Stream<List<MessageUpdate>> subscribeToTimelineUpdates({
+  required String roomId,
+  required BigInt subscriptionId,
+  BigInt? throttleMs,
+}) => RustLib.instance.api.crateMatrixTimelinesSubscribeToTimelineUpdates(
+  roomId: roomId,
+  subscriptionId: subscriptionId,
+  throttleMs: throttleMs,
+);
+
+/// Stop a live timeline subscription started by
+/// [`subscribe_to_timeline_updates`]. Once a room has no subscriptions left,
+/// its timeline is up for eviction.
+Future<void> unsubscribeFromTimelineUpdates({
+  required String roomId,
+  required BigInt subscriptionId,
+}) => RustLib.instance.api.crateMatrixTimelinesUnsubscribeFromTimelineUpdates(
+  roomId: roomId,
+  subscriptionId: subscriptionId,
+);
+
+/// Build the timelines of the rooms currently visible in the room list, so
+/// opening them is instant. Only the first [`PRELOADED_TIMELINES`] rooms are
+/// considered.
+Future<void> preloadTimelines({required List<String> roomIds}) =>
+    RustLib.instance.api.crateMatrixTimelinesPreloadTimelines(roomIds: roomIds);
+
+/// Load `count` older events in the live timeline of a room.
+///
+/// The new items reach Dart through [`subscribe_to_timeline_updates`]; this
+/// only returns whether there is more history to load. Concurrent calls for
+/// the same room share a single pagination.
+Future<bool> getOlderMessages({required String roomId, required int count}) =>
+    RustLib.instance.api.crateMatrixTimelinesGetOlderMessages(
       roomId: roomId,
+      count: count,
     );
 
-Future<List<Message>> getOlderMessages({
+/// Stream the back-pagination state of a room's live timeline.
+///
+/// The stream ends when the timeline is evicted from the cache; subscribe
+/// again after reopening the room to follow the rebuilt timeline.
+Stream<PaginationStatus> subscribeToPaginationStatus({
+  required String roomId,
+}) => RustLib.instance.api.crateMatrixTimelinesSubscribeToPaginationStatus(
+  roomId: roomId,
+);
+
+/// Open a timeline centered on `event_id` (e.g. a search result or a
+/// notification tap) and stream its updates.
+///
+/// The first batch is always a `Reset` with the items around the event, the
+/// following ones are regular diffs, batched like in
+/// [`subscribe_to_timeline_updates`]. Only one event-focused timeline is kept
+/// per room: opening a new one replaces the previous one.
+Stream<List<MessageUpdate>> subscribeToEventTimelineUpdates({
+  required String roomId,
+  required String eventId,
+  int? numContextEvents,
+  BigInt? throttleMs,
+}) => RustLib.instance.api.crateMatrixTimelinesSubscribeToEventTimelineUpdates(
+  roomId: roomId,
+  eventId: eventId,
+  numContextEvents: numContextEvents,
+  throttleMs: throttleMs,
+);
+
+/// Load `count` older events in the event-focused timeline of a room.
+///
+/// Returns whether there are older events left to load.
+Future<bool> paginateEventTimelineBackwards({
   required String roomId,
   required int count,
-}) => RustLib.instance.api.crateMatrixTimelinesGetOlderMessages(
+}) => RustLib.instance.api.crateMatrixTimelinesPaginateEventTimelineBackwards(
   roomId: roomId,
   count: count,
 );
 
+/// Load `count` newer events in the event-focused timeline of a room.
+///
+/// Returns whether there are newer events left to load. Once it returns
+/// `false` the timeline caught up with the live end of the room and the
+/// caller should switch back with [`return_to_live_timeline`].
+Future<bool> paginateEventTimelineForwards({
+  required String roomId,
+  required int count,
+}) => RustLib.instance.api.crateMatrixTimelinesPaginateEventTimelineForwards(
+  roomId: roomId,
+  count: count,
+);
+
+/// Drop the event-focused timeline of a room, ending its update stream.
+///
+/// Used when the user scrolled back to the bottom; the live timeline
+/// (see [`subscribe_to_timeline_updates`]) takes over from there.
+Future<void> returnToLiveTimeline({required String roomId}) =>
+    RustLib.instance.api.crateMatrixTimelinesReturnToLiveTimeline(
+      roomId: roomId,
+    );
+
 class Message {
   final String eventId;
   final String sender;
@@ -36,12 +149,16 @@ class Message {
   final BigInt timestamp;
   final MessageType messageType;
 
+  /// Members who have read up to this message, for "seen by" avatars.
+  final List<ReadReceipt> readReceipts;
+
   const Message({
     required this.eventId,
     required this.sender,
     required this.content,
     required this.timestamp,
     required this.messageType,
+    required this.readReceipts,
   });
 
   @override
@@ -50,7 +167,8 @@ class Message {
       sender.hashCode ^
       content.hashCode ^
       timestamp.hashCode ^
-      messageType.hashCode;
+      messageType.hashCode ^
+      readReceipts.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -61,10 +179,31 @@ class Message {
           sender == other.sender &&
           content == other.content &&
           timestamp == other.timestamp &&
-          messageType == other.messageType;
+          messageType == other.messageType &&
+          readReceipts == other.readReceipts;
 }
 
-enum MessageType { message, dateDivider, readMarker, timelineStart }
+@freezed
+sealed class MessageType with _$MessageType {
+  const MessageType._();
+
+  const factory MessageType.message() = MessageType_Message;
+  const factory MessageType.dateDivider() = MessageType_DateDivider;
+
+  /// Follows the last message we read. `own_receipt_event_id` is the event
+  /// of our own latest read receipt, if we have one.
+  const factory MessageType.readMarker({String? ownReceiptEventId}) =
+      MessageType_ReadMarker;
+  const factory MessageType.timelineStart() = MessageType_TimelineStart;
+
+  /// The room was upgraded, its conversation continues in another room.
+  const factory MessageType.tombstone({required String replacementRoomId}) =
+      MessageType_Tombstone;
+
+  /// The room was created by upgrading `predecessor_room_id`.
+  const factory MessageType.predecessor({required String predecessorRoomId}) =
+      MessageType_Predecessor;
+}
 
 class MessageUpdate {
   final MessageUpdateType messageUpdateType;
@@ -110,3 +249,6 @@ enum MessageUpdateType {
   clear,
   append,
 }
+
+/// Back-pagination state of a room's timeline.
+enum PaginationStatus { idle, paginating, reachedStart }
@@ -0,0 +1,483 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'timelines.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$MessageType {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MessageType()';
+}
+
+
+}
+
+/// @nodoc
+class $MessageTypeCopyWith<$Res>  {
+$MessageTypeCopyWith(MessageType _, $Res Function(MessageType) __);
+}
+
+
+/// Adds pattern-matching-related methods to [MessageType].
+extension MessageTypePatterns on MessageType {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MessageType_Message value)?  message,TResult Function( MessageType_DateDivider value)?  dateDivider,TResult Function( MessageType_ReadMarker value)?  readMarker,TResult Function( MessageType_TimelineStart value)?  timelineStart,TResult Function( MessageType_Tombstone value)?  tombstone,TResult Function( MessageType_Predecessor value)?  predecessor,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case MessageType_Message() when message != null:
+return message(_that);case MessageType_DateDivider() when dateDivider != null:
+return dateDivider(_that);case MessageType_ReadMarker() when readMarker != null:
+return readMarker(_that);case MessageType_TimelineStart() when timelineStart != null:
+return timelineStart(_that);case MessageType_Tombstone() when tombstone != null:
+return tombstone(_that);case MessageType_Predecessor() when predecessor != null:
+return predecessor(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MessageType_Message value)  message,required TResult Function( MessageType_DateDivider value)  dateDivider,required TResult Function( MessageType_ReadMarker value)  readMarker,required TResult Function( MessageType_TimelineStart value)  timelineStart,required TResult Function( MessageType_Tombstone value)  tombstone,required TResult Function( MessageType_Predecessor value)  predecessor,}){
+final _that = this;
+switch (_that) {
+case MessageType_Message():
+return message(_that);case MessageType_DateDivider():
+return dateDivider(_that);case MessageType_ReadMarker():
+return readMarker(_that);case MessageType_TimelineStart():
+return timelineStart(_that);case MessageType_Tombstone():
+return tombstone(_that);case MessageType_Predecessor():
+return predecessor(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MessageType_Message value)?  message,TResult? Function( MessageType_DateDivider value)?  dateDivider,TResult? Function( MessageType_ReadMarker value)?  readMarker,TResult? Function( MessageType_TimelineStart value)?  timelineStart,TResult? Function( MessageType_Tombstone value)?  tombstone,TResult? Function( MessageType_Predecessor value)?  predecessor,}){
+final _that = this;
+switch (_that) {
+case MessageType_Message() when message != null:
+return message(_that);case MessageType_DateDivider() when dateDivider != null:
+return dateDivider(_that);case MessageType_ReadMarker() when readMarker != null:
+return readMarker(_that);case MessageType_TimelineStart() when timelineStart != null:
+return timelineStart(_that);case MessageType_Tombstone() when tombstone != null:
+return tombstone(_that);case MessageType_Predecessor() when predecessor != null:
+return predecessor(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  message,TResult Function()?  dateDivider,TResult Function( String? ownReceiptEventId)?  readMarker,TResult Function()?  timelineStart,TResult Function( String replacementRoomId)?  tombstone,TResult Function( String predecessorRoomId)?  predecessor,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case MessageType_Message() when message != null:
+return message();case MessageType_DateDivider() when dateDivider != null:
+return dateDivider();case MessageType_ReadMarker() when readMarker != null:
+return readMarker(_that.ownReceiptEventId);case MessageType_TimelineStart() when timelineStart != null:
+return timelineStart();case MessageType_Tombstone() when tombstone != null:
+return tombstone(_that.replacementRoomId);case MessageType_Predecessor() when predecessor != null:
+return predecessor(_that.predecessorRoomId);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  message,required TResult Function()  dateDivider,required TResult Function( String? ownReceiptEventId)  readMarker,required TResult Function()  timelineStart,required TResult Function( String replacementRoomId)  tombstone,required TResult Function( String predecessorRoomId)  predecessor,}) {final _that = this;
+switch (_that) {
+case MessageType_Message():
+return message();case MessageType_DateDivider():
+return dateDivider();case MessageType_ReadMarker():
+return readMarker(_that.ownReceiptEventId);case MessageType_TimelineStart():
+return timelineStart();case MessageType_Tombstone():
+return tombstone(_that.replacementRoomId);case MessageType_Predecessor():
+return predecessor(_that.predecessorRoomId);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  message,TResult? Function()?  dateDivider,TResult? Function( String? ownReceiptEventId)?  readMarker,TResult? Function()?  timelineStart,TResult? Function( String replacementRoomId)?  tombstone,TResult? Function( String predecessorRoomId)?  predecessor,}) {final _that = this;
+switch (_that) {
+case MessageType_Message() when message != null:
+return message();case MessageType_DateDivider() when dateDivider != null:
+return dateDivider();case MessageType_ReadMarker() when readMarker != null:
+return readMarker(_that.ownReceiptEventId);case MessageType_TimelineStart() when timelineStart != null:
+return timelineStart();case MessageType_Tombstone() when tombstone != null:
+return tombstone(_that.replacementRoomId);case MessageType_Predecessor() when predecessor != null:
+return predecessor(_that.predecessorRoomId);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class MessageType_Message extends MessageType {
+  const MessageType_Message(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_Message);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MessageType.message()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class MessageType_DateDivider extends MessageType {
+  const MessageType_DateDivider(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_DateDivider);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MessageType.dateDivider()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class MessageType_ReadMarker extends MessageType {
+  const MessageType_ReadMarker({this.ownReceiptEventId}): super._();
+  
+
+ final  String? ownReceiptEventId;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MessageType_ReadMarkerCopyWith<MessageType_ReadMarker> get copyWith => _$MessageType_ReadMarkerCopyWithImpl<MessageType_ReadMarker>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_ReadMarker&&(identical(other.ownReceiptEventId, ownReceiptEventId) || other.ownReceiptEventId == ownReceiptEventId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,ownReceiptEventId);
+
+@override
+String toString() {
+  return 'MessageType.readMarker(ownReceiptEventId: $ownReceiptEventId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MessageType_ReadMarkerCopyWith<$Res> implements $MessageTypeCopyWith<$Res> {
+  factory $MessageType_ReadMarkerCopyWith(MessageType_ReadMarker value, $Res Function(MessageType_ReadMarker) _then) = _$MessageType_ReadMarkerCopyWithImpl;
+@useResult
+$Res call({
+ String? ownReceiptEventId
+});
+
+
+
+
+}
+/// @nodoc
+class _$MessageType_ReadMarkerCopyWithImpl<$Res>
+    implements $MessageType_ReadMarkerCopyWith<$Res> {
+  _$MessageType_ReadMarkerCopyWithImpl(this._self, this._then);
+
+  final MessageType_ReadMarker _self;
+  final $Res Function(MessageType_ReadMarker) _then;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? ownReceiptEventId = freezed,}) {
+  return _then(MessageType_ReadMarker(
+ownReceiptEventId: freezed == ownReceiptEventId ? _self.ownReceiptEventId : ownReceiptEventId // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MessageType_TimelineStart extends MessageType {
+  const MessageType_TimelineStart(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_TimelineStart);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MessageType.timelineStart()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class MessageType_Tombstone extends MessageType {
+  const MessageType_Tombstone({required this.replacementRoomId}): super._();
+  
+
+ final  String replacementRoomId;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MessageType_TombstoneCopyWith<MessageType_Tombstone> get copyWith => _$MessageType_TombstoneCopyWithImpl<MessageType_Tombstone>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_Tombstone&&(identical(other.replacementRoomId, replacementRoomId) || other.replacementRoomId == replacementRoomId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,replacementRoomId);
+
+@override
+String toString() {
+  return 'MessageType.tombstone(replacementRoomId: $replacementRoomId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MessageType_TombstoneCopyWith<$Res> implements $MessageTypeCopyWith<$Res> {
+  factory $MessageType_TombstoneCopyWith(MessageType_Tombstone value, $Res Function(MessageType_Tombstone) _then) = _$MessageType_TombstoneCopyWithImpl;
+@useResult
+$Res call({
+ String replacementRoomId
+});
+
+
+
+
+}
+/// @nodoc
+class _$MessageType_TombstoneCopyWithImpl<$Res>
+    implements $MessageType_TombstoneCopyWith<$Res> {
+  _$MessageType_TombstoneCopyWithImpl(this._self, this._then);
+
+  final MessageType_Tombstone _self;
+  final $Res Function(MessageType_Tombstone) _then;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? replacementRoomId = null,}) {
+  return _then(MessageType_Tombstone(
+replacementRoomId: null == replacementRoomId ? _self.replacementRoomId : replacementRoomId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MessageType_Predecessor extends MessageType {
+  const MessageType_Predecessor({required this.predecessorRoomId}): super._();
+  
+
+ final  String predecessorRoomId;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MessageType_PredecessorCopyWith<MessageType_Predecessor> get copyWith => _$MessageType_PredecessorCopyWithImpl<MessageType_Predecessor>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MessageType_Predecessor&&(identical(other.predecessorRoomId, predecessorRoomId) || other.predecessorRoomId == predecessorRoomId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,predecessorRoomId);
+
+@override
+String toString() {
+  return 'MessageType.predecessor(predecessorRoomId: $predecessorRoomId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MessageType_PredecessorCopyWith<$Res> implements $MessageTypeCopyWith<$Res> {
+  factory $MessageType_PredecessorCopyWith(MessageType_Predecessor value, $Res Function(MessageType_Predecessor) _then) = _$MessageType_PredecessorCopyWithImpl;
+@useResult
+$Res call({
+ String predecessorRoomId
+});
+
+
+
+
+}
+/// @nodoc
+class _$MessageType_PredecessorCopyWithImpl<$Res>
+    implements $MessageType_PredecessorCopyWith<$Res> {
+  _$MessageType_PredecessorCopyWithImpl(this._self, this._then);
+
+  final MessageType_Predecessor _self;
+  final $Res Function(MessageType_Predecessor) _then;
+
+/// Create a copy of MessageType
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? predecessorRoomId = null,}) {
+  return _then(MessageType_Predecessor(
+predecessorRoomId: null == predecessorRoomId ? _self.predecessorRoomId : predecessorRoomId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'user_serach.dart';
+
+// These functions are ignored because they are not marked as `pub`: `send_typing_notice`
+
+/// Tell the room whether we are typing. Meant to be called on every
+/// keystroke: the SDK only resends the notice every few seconds, and it's
+/// cleared automatically once the calls stop.
+Future<void> setTyping({required String roomId, required bool typing}) =>
+    RustLib.instance.api.crateMatrixTypingSetTyping(
+      roomId: roomId,
+      typing: typing,
+    );
+
+/// Stream the users typing in a room, without ourselves; every item is the
+/// full list.
+Stream<List<User>> subscribeToTypingUsers({required String roomId}) =>
+    RustLib.instance.api.crateMatrixTypingSubscribeToTypingUsers(
+      roomId: roomId,
+    );
@@ -39,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1787116871;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1184203577;
 
 // Section: executor
 
@@ -47,7 +47,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
+fn wire__crate__matrix__rooms__CreateRoomParams_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -55,7 +55,7 @@ fn wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "FieldsFormatterForFiles_default",
+            debug_name: "CreateRoomParams_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -72,16 +72,15 @@ fn wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::platform::FieldsFormatterForFiles::default(),
-                    )?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::matrix::rooms::CreateRoomParams::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger___log_impl(
+fn wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -89,7 +88,7 @@ fn wire__crate__api__logger___log_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "_log",
+            debug_name: "FieldsFormatterForFiles_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -103,22 +102,19 @@ fn wire__crate__api__logger___log_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_tag = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::_log(api_level, api_message, api_tag);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::platform::FieldsFormatterForFiles::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__init__check_matrix_sdk_status_impl(
+fn wire__crate__matrix__rooms__PowerLevelOverrides_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -126,7 +122,7 @@ fn wire__crate__api__init__check_matrix_sdk_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_matrix_sdk_status",
+            debug_name: "PowerLevelOverrides_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -144,22 +140,22 @@ fn wire__crate__api__init__check_matrix_sdk_status_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::init::check_matrix_sdk_status())?;
+                        Result::<_, ()>::Ok(crate::matrix::rooms::PowerLevelOverrides::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__client__configure_client_impl(
+fn wire__crate__matrix__rooms__RoomVisibility_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "configure_client",
+            debug_name: "RoomVisibility_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -173,21 +169,18 @@ fn wire__crate__matrix__client__configure_client_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <crate::matrix::client::ClientConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::matrix::client::configure_client(api_config).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::matrix::rooms::RoomVisibility::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__create_direct_room_impl(
+fn wire__crate__api__logger___log_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -195,7 +188,7 @@ fn wire__crate__matrix__rooms__create_direct_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_direct_room",
+            debug_name: "_log",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -209,18 +202,22 @@ fn wire__crate__matrix__rooms__create_direct_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::rooms::create_direct_room(api_user_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::_log(api_level, api_message, api_tag);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__create_group_room_impl(
+fn wire__crate__matrix__invites__accept_invite_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -228,7 +225,7 @@ fn wire__crate__matrix__rooms__create_group_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_group_room",
+            debug_name: "accept_invite",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -242,20 +239,18 @@ fn wire__crate__matrix__rooms__create_group_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            let api_user_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::matrix::rooms::create_group_room(api_name, api_user_ids)?;
+                    let output_ok = crate::matrix::invites::accept_invite(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__create_log_stream_impl(
+fn wire__crate__matrix__knocking__accept_knock_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -263,7 +258,7 @@ fn wire__crate__api__logger__create_log_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_log_stream",
+            debug_name: "accept_knock_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -277,23 +272,20 @@ fn wire__crate__api__logger__create_log_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_s = <StreamSink<
-                crate::api::logger::LogEntry,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::create_log_stream(api_s);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::knocking::accept_knock_request(api_room_id, api_user_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__get_all_rooms_impl(
+fn wire__crate__matrix__directory__add_room_alias_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -301,7 +293,7 @@ fn wire__crate__matrix__rooms__get_all_rooms_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_rooms",
+            debug_name: "add_room_alias",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -315,17 +307,20 @@ fn wire__crate__matrix__rooms__get_all_rooms_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_alias = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::matrix::rooms::get_all_rooms())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::directory::add_room_alias(api_room_id, api_alias)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__init__get_matrix_config_impl(
+fn wire__crate__matrix__spaces__add_room_to_space_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -333,7 +328,7 @@ fn wire__crate__api__init__get_matrix_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_matrix_config",
+            debug_name: "add_room_to_space",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -347,25 +342,32 @@ fn wire__crate__api__init__get_matrix_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_space_id = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_suggested = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::init::get_matrix_config())?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::spaces::add_room_to_space(
+                        api_space_id,
+                        api_room_id,
+                        api_suggested,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__timelines__get_older_messages_impl(
+fn wire__crate__matrix__moderation__ban_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_older_messages",
+            debug_name: "ban_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -380,23 +382,20 @@ fn wire__crate__matrix__timelines__get_older_messages_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_room_id = <String>::sse_decode(&mut deserializer);
-            let api_count = <u16>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::matrix::timelines::get_older_messages(api_room_id, api_count)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::moderation::ban_user(api_room_id, api_user_id, api_reason)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__init__get_sync_operations_status_impl(
+fn wire__crate__api__init__check_matrix_sdk_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -404,7 +403,7 @@ fn wire__crate__api__init__get_sync_operations_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_sync_operations_status",
+            debug_name: "check_matrix_sdk_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -422,22 +421,22 @@ fn wire__crate__api__init__get_sync_operations_status_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::init::get_sync_operations_status())?;
+                        Result::<_, ()>::Ok(crate::api::init::check_matrix_sdk_status())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__timelines__get_timeline_items_by_room_id_impl(
+fn wire__crate__matrix__client__configure_client_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_timeline_items_by_room_id",
+            debug_name: "configure_client",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -451,20 +450,21 @@ fn wire__crate__matrix__timelines__get_timeline_items_by_room_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_config = <crate::matrix::client::ClientConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::matrix::timelines::get_timeline_items_by_room_id(api_room_id),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::client::configure_client(api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__init__init_app_impl(
+fn wire__crate__matrix__rooms__create_direct_room_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -472,7 +472,7 @@ fn wire__crate__api__init__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "create_direct_room",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -486,19 +486,18 @@ fn wire__crate__api__init__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::rooms::create_direct_room(api_user_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__init_logger_impl(
+fn wire__crate__matrix__rooms__create_group_room_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -506,7 +505,7 @@ fn wire__crate__api__logger__init_logger_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logger",
+            debug_name: "create_group_room",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -520,23 +519,20 @@ fn wire__crate__api__logger__init_logger_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_log_stream = <StreamSink<
-                crate::api::logger::LogEntry,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_user_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::init_logger(api_log_stream);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::rooms::create_group_room(api_name, api_user_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__platform__init_platform_impl(
+fn wire__crate__api__logger__create_log_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -544,7 +540,7 @@ fn wire__crate__api__platform__init_platform_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_platform",
+            debug_name: "create_log_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -558,23 +554,23 @@ fn wire__crate__api__platform__init_platform_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config =
-                <crate::api::platform::TracingConfiguration>::sse_decode(&mut deserializer);
-            let api_use_lightweight_tokio_runtime = <bool>::sse_decode(&mut deserializer);
+            let api_s = <StreamSink<
+                crate::api::logger::LogEntry,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::platform::ClientError>((move || {
-                    let output_ok = crate::api::platform::init_platform(
-                        api_config,
-                        api_use_lightweight_tokio_runtime,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::create_log_stream(api_s);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__authentication__is_client_authenticated_impl(
+fn wire__crate__matrix__rooms__create_room_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -582,7 +578,7 @@ fn wire__crate__matrix__authentication__is_client_authenticated_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_client_authenticated",
+            debug_name: "create_room",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -596,17 +592,19 @@ fn wire__crate__matrix__authentication__is_client_authenticated_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_params =
+                <crate::matrix::rooms::CreateRoomParams>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::authentication::is_client_authenticated()?;
+                    let output_ok = crate::matrix::rooms::create_room(api_params)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__join_room_impl(
+fn wire__crate__matrix__spaces__create_space_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -614,7 +612,7 @@ fn wire__crate__matrix__rooms__join_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "join_room",
+            debug_name: "create_space",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -628,18 +626,19 @@ fn wire__crate__matrix__rooms__join_room_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_params =
+                <crate::matrix::rooms::CreateRoomParams>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::rooms::join_room(api_room_id)?;
+                    let output_ok = crate::matrix::spaces::create_space(api_params)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__leave_room_impl(
+fn wire__crate__matrix__invites__decline_invite_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -647,7 +646,7 @@ fn wire__crate__matrix__rooms__leave_room_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "leave_room",
+            debug_name: "decline_invite",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -662,17 +661,23 @@ fn wire__crate__matrix__rooms__leave_room_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ignore_inviter = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::rooms::leave_room(api_room_id)?;
+                    let output_ok = crate::matrix::invites::decline_invite(
+                        api_room_id,
+                        api_reason,
+                        api_ignore_inviter,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__log_debug_impl(
+fn wire__crate__matrix__knocking__decline_knock_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -680,7 +685,7 @@ fn wire__crate__api__logger__log_debug_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_debug",
+            debug_name: "decline_knock_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -694,20 +699,26 @@ fn wire__crate__api__logger__log_debug_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ban = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::log_debug(api_message);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::knocking::decline_knock_request(
+                        api_room_id,
+                        api_user_id,
+                        api_reason,
+                        api_ban,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__log_error_impl(
+fn wire__crate__matrix__room_settings__enable_room_encryption_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -715,7 +726,7 @@ fn wire__crate__api__logger__log_error_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_error",
+            debug_name: "enable_room_encryption",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -729,20 +740,19 @@ fn wire__crate__api__logger__log_error_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::log_error(api_message);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_settings::enable_room_encryption(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tracing__log_event_impl(
+fn wire__crate__matrix__rooms__get_all_rooms_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -750,7 +760,7 @@ fn wire__crate__api__tracing__log_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_event",
+            debug_name: "get_all_rooms",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -764,30 +774,17 @@ fn wire__crate__api__tracing__log_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file = <String>::sse_decode(&mut deserializer);
-            let api_line = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_level = <crate::api::tracing::LogLevel>::sse_decode(&mut deserializer);
-            let api_target = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::tracing::log_event(
-                            api_file,
-                            api_line,
-                            api_level,
-                            api_target,
-                            api_message,
-                        );
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(crate::matrix::rooms::get_all_rooms())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__log_info_impl(
+fn wire__crate__matrix__ignored_users__get_ignored_users_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -795,7 +792,7 @@ fn wire__crate__api__logger__log_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_info",
+            debug_name: "get_ignored_users",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -809,20 +806,17 @@ fn wire__crate__api__logger__log_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::log_info(api_message);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::ignored_users::get_ignored_users()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__logger__log_warn_impl(
+fn wire__crate__matrix__invites__get_invite_details_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -830,7 +824,7 @@ fn wire__crate__api__logger__log_warn_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_warn",
+            debug_name: "get_invite_details",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -844,20 +838,18 @@ fn wire__crate__api__logger__log_warn_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::logger::log_warn(api_message);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::invites::get_invite_details(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__authentication__login_impl(
+fn wire__crate__matrix__invites__get_invites_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -865,7 +857,7 @@ fn wire__crate__matrix__authentication__login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "get_invites",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -879,20 +871,50 @@ fn wire__crate__matrix__authentication__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::invites::get_invites()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__spaces__get_joined_spaces_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_joined_spaces",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        crate::matrix::authentication::login(api_username, api_password)?;
+                        Result::<_, ()>::Ok(crate::matrix::spaces::get_joined_spaces())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__authentication__logout_impl(
+fn wire__crate__api__init__get_matrix_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -900,7 +922,7 @@ fn wire__crate__matrix__authentication__logout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout",
+            debug_name: "get_matrix_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -916,15 +938,54 @@ fn wire__crate__matrix__authentication__logout_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::authentication::logout()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::init::get_matrix_config())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__authentication__register_impl(
+fn wire__crate__matrix__timelines__get_older_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_older_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_count = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::matrix::timelines::get_older_messages(api_room_id, api_count)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__direct_messages__get_or_create_dm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -932,7 +993,7 @@ fn wire__crate__matrix__authentication__register_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "register",
+            debug_name: "get_or_create_dm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -946,20 +1007,18 @@ fn wire__crate__matrix__authentication__register_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::matrix::authentication::register(api_username, api_password)?;
+                    let output_ok = crate::matrix::direct_messages::get_or_create_dm(api_user_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__platform__reload_tracing_file_writer_impl(
+fn wire__crate__matrix__profile__get_own_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -967,7 +1026,7 @@ fn wire__crate__api__platform__reload_tracing_file_writer_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reload_tracing_file_writer",
+            debug_name: "get_own_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -981,20 +1040,17 @@ fn wire__crate__api__platform__reload_tracing_file_writer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_configuration =
-                <crate::api::platform::TracingFileConfiguration>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::platform::ClientError>((move || {
-                    let output_ok =
-                        crate::api::platform::reload_tracing_file_writer(api_configuration)?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::profile::get_own_profile()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__user_serach__search_users_impl(
+fn wire__crate__matrix__receipts__get_own_read_receipt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1002,7 +1058,7 @@ fn wire__crate__matrix__user_serach__search_users_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_users",
+            debug_name: "get_own_read_receipt",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1016,18 +1072,18 @@ fn wire__crate__matrix__user_serach__search_users_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::user_serach::search_users(api_query)?;
+                    let output_ok = crate::matrix::receipts::get_own_read_receipt(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__send_message_impl(
+fn wire__crate__matrix__directory__get_room_aliases_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1035,7 +1091,7 @@ fn wire__crate__matrix__rooms__send_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message",
+            debug_name: "get_room_aliases",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1050,18 +1106,17 @@ fn wire__crate__matrix__rooms__send_message_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_room_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::rooms::send_message(api_room_id, api_content)?;
+                    let output_ok = crate::matrix::directory::get_room_aliases(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__sync_service__start_sync_service_impl(
+fn wire__crate__matrix__members__get_room_members_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1069,7 +1124,7 @@ fn wire__crate__matrix__sync_service__start_sync_service_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_sync_service",
+            debug_name: "get_room_members",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1083,17 +1138,24 @@ fn wire__crate__matrix__sync_service__start_sync_service_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::matrix::sync_service::start_sync_service()?;
+                    let output_ok = crate::matrix::members::get_room_members(
+                        api_room_id,
+                        api_offset,
+                        api_limit,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
+fn wire__crate__matrix__moderation__get_room_permissions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1101,7 +1163,7 @@ fn wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_to_all_room_updates",
+            debug_name: "get_room_permissions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1115,31 +1177,26 @@ fn wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stream = <StreamSink<
-                crate::matrix::rooms::RoomUpdate,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::matrix::rooms::subscribe_to_all_room_updates(api_stream);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::moderation::get_room_permissions(api_room_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__matrix__timelines__subscribe_to_timeline_updates_impl(
+fn wire__crate__matrix__moderation__get_room_power_levels_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_to_timeline_updates",
+            debug_name: "get_room_power_levels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1153,31 +1210,18 @@ fn wire__crate__matrix__timelines__subscribe_to_timeline_updates_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stream = <StreamSink<
-                crate::matrix::timelines::MessageUpdate,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             let api_room_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::matrix::timelines::subscribe_to_timeline_updates(
-                                api_stream,
-                                api_room_id,
-                            )
-                            .await;
-                        })?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::moderation::get_room_power_levels(api_room_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__init__test_server_connectivity_impl(
+fn wire__crate__matrix__joining__get_room_preview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1185,7 +1229,7 @@ fn wire__crate__api__init__test_server_connectivity_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_server_connectivity",
+            debug_name: "get_room_preview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1199,1359 +1243,7801 @@ fn wire__crate__api__init__test_server_connectivity_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <String>::sse_decode(&mut deserializer);
+            let api_via_servers = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::init::test_server_connectivity())?;
+                        crate::matrix::joining::get_room_preview(api_target, api_via_servers)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for Certificate {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
-}
-
-impl SseDecode for FieldsFormatterForFiles {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+fn wire__crate__matrix__spaces__get_space_hierarchy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_space_hierarchy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_space_id = <String>::sse_decode(&mut deserializer);
+            let api_from = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_max_depth = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_suggested_only = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::spaces::get_space_hierarchy(
+                        api_space_id,
+                        api_from,
+                        api_limit,
+                        api_max_depth,
+                        api_suggested_only,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
+fn wire__crate__api__init__get_sync_operations_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_sync_operations_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::init::get_sync_operations_status())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode
+fn wire__crate__matrix__timelines__get_timeline_items_by_room_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_timeline_items_by_room_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::matrix::timelines::get_timeline_items_by_room_id(api_room_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__presence__get_user_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_user_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::presence::get_user_presence(api_user_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__profile__get_user_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_user_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::profile::get_user_profile(api_user_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__ignored_users__ignore_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ignore_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::ignored_users::ignore_user(api_user_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__init_logger_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logger",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_log_stream = <StreamSink<
+                crate::api::logger::LogEntry,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::init_logger(api_log_stream);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__platform__init_platform_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_platform",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::platform::TracingConfiguration>::sse_decode(&mut deserializer);
+            let api_use_lightweight_tokio_runtime = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::platform::ClientError>((move || {
+                    let output_ok = crate::api::platform::init_platform(
+                        api_config,
+                        api_use_lightweight_tokio_runtime,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__moderation__invite_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "invite_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::moderation::invite_user(api_room_id, api_user_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__authentication__is_client_authenticated_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_client_authenticated",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::authentication::is_client_authenticated()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__directory__is_room_published_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_room_published",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::directory::is_room_published(api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__rooms__join_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "join_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::rooms::join_room(api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__joining__join_room_by_id_or_alias_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "join_room_by_id_or_alias",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <String>::sse_decode(&mut deserializer);
+            let api_via_servers = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::joining::join_room_by_id_or_alias(
+                        api_target,
+                        api_via_servers,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__moderation__kick_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kick_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::moderation::kick_user(api_room_id, api_user_id, api_reason)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__knocking__knock_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "knock_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_via_servers = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::knocking::knock_room(
+                        api_target,
+                        api_reason,
+                        api_via_servers,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__rooms__leave_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "leave_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::rooms::leave_room(api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__log_debug_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_debug",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::log_debug(api_message);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__log_error_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_error",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::log_error(api_message);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tracing__log_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <String>::sse_decode(&mut deserializer);
+            let api_line = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_level = <crate::api::tracing::LogLevel>::sse_decode(&mut deserializer);
+            let api_target = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::tracing::log_event(
+                            api_file,
+                            api_line,
+                            api_level,
+                            api_target,
+                            api_message,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__log_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::log_info(api_message);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__log_warn_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_warn",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logger::log_warn(api_message);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__authentication__login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::authentication::login(api_username, api_password)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__authentication__logout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "logout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::authentication::logout()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__knocking__mark_knock_requests_as_seen_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_knock_requests_as_seen",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::knocking::mark_knock_requests_as_seen(
+                        api_room_id,
+                        api_user_ids,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__receipts__mark_room_as_read_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_room_as_read",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_kind = <crate::matrix::receipts::ReceiptKind>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::receipts::mark_room_as_read(api_room_id, api_kind)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__presence__new_presence_subscription_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_presence_subscription_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::matrix::presence::new_presence_subscription_id(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_list__new_room_list_subscription_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_room_list_subscription_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::matrix::room_list::new_room_list_subscription_id(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__new_timeline_subscription_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_timeline_subscription_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::matrix::timelines::new_timeline_subscription_id(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__paginate_event_timeline_backwards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "paginate_event_timeline_backwards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_count = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::matrix::timelines::paginate_event_timeline_backwards(
+                                api_room_id,
+                                api_count,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__paginate_event_timeline_forwards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "paginate_event_timeline_forwards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_count = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::timelines::paginate_event_timeline_forwards(
+                            api_room_id,
+                            api_count,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__preload_timelines_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preload_timelines",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::matrix::timelines::preload_timelines(api_room_ids).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__authentication__register_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::authentication::register(api_username, api_password)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__platform__reload_tracing_file_writer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_tracing_file_writer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_configuration =
+                <crate::api::platform::TracingFileConfiguration>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::platform::ClientError>((move || {
+                    let output_ok =
+                        crate::api::platform::reload_tracing_file_writer(api_configuration)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__profile__remove_avatar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_avatar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::profile::remove_avatar()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__directory__remove_room_alias_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_room_alias",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_alias = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::directory::remove_room_alias(api_room_id, api_alias)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__remove_room_avatar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_room_avatar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::room_settings::remove_room_avatar(api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__spaces__remove_room_from_space_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_room_from_space",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_space_id = <String>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::spaces::remove_room_from_space(api_space_id, api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__tags__remove_room_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_room_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::tags::remove_room_tag(api_room_id, api_tag)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__reporting__report_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_score = <Option<i8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::reporting::report_event(
+                        api_room_id,
+                        api_event_id,
+                        api_reason,
+                        api_score,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__reporting__report_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::reporting::report_room(api_room_id, api_reason)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__reporting__report_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::reporting::report_user(api_user_id, api_reason)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__return_to_live_timeline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "return_to_live_timeline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::timelines::return_to_live_timeline(api_room_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_list__room_list_load_next_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "room_list_load_next_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_list::room_list_load_next_page(api_subscription_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_list__room_list_reset_to_one_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "room_list_reset_to_one_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_list::room_list_reset_to_one_page(api_subscription_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__directory__search_public_rooms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_public_rooms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_search_term = <Option<String>>::sse_decode(&mut deserializer);
+            let api_server = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_since = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::directory::search_public_rooms(
+                        api_search_term,
+                        api_server,
+                        api_limit,
+                        api_since,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__members__search_room_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_room_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::members::search_room_members(
+                        api_room_id,
+                        api_query,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__user_serach__search_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::user_serach::search_users(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__rooms__send_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::rooms::send_message(api_room_id, api_content)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__receipts__send_read_receipt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_read_receipt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_kind = <crate::matrix::receipts::ReceiptKind>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::receipts::send_read_receipt(
+                        api_room_id,
+                        api_event_id,
+                        api_kind,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__moderation__set_action_power_level_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_action_power_level",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_action =
+                <crate::matrix::moderation::PowerLevelAction>::sse_decode(&mut deserializer);
+            let api_power_level = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::moderation::set_action_power_level(
+                        api_room_id,
+                        api_action,
+                        api_power_level,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__profile__set_avatar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_avatar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::profile::set_avatar(api_data, api_mime_type)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__directory__set_canonical_alias_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_canonical_alias",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_alias = <Option<String>>::sse_decode(&mut deserializer);
+            let api_alt_aliases = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::directory::set_canonical_alias(
+                        api_room_id,
+                        api_alias,
+                        api_alt_aliases,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__profile__set_display_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_display_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_display_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::profile::set_display_name(api_display_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__presence__set_own_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_own_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_presence =
+                <crate::matrix::presence::PresenceKind>::sse_decode(&mut deserializer);
+            let api_status_message = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::presence::set_own_presence(
+                        api_presence,
+                        api_status_message,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__receipts__set_read_markers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_read_markers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_fully_read_event_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_read_receipt_event_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_private_read_receipt = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::receipts::set_read_markers(
+                        api_room_id,
+                        api_fully_read_event_id,
+                        api_read_receipt_event_id,
+                        api_private_read_receipt,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__set_room_avatar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_avatar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::room_settings::set_room_avatar(
+                        api_room_id,
+                        api_data,
+                        api_mime_type,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__tags__set_room_favourite_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_favourite",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_is_favourite = <bool>::sse_decode(&mut deserializer);
+            let api_order = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::tags::set_room_favourite(
+                        api_room_id,
+                        api_is_favourite,
+                        api_order,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__set_room_history_visibility_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_history_visibility",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_history_visibility =
+                <crate::matrix::rooms::RoomHistoryVisibility>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::room_settings::set_room_history_visibility(
+                        api_room_id,
+                        api_history_visibility,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__set_room_join_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_join_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_join_rule =
+                <crate::matrix::room_settings::RoomJoinRule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::room_settings::set_room_join_rule(
+                        api_room_id,
+                        api_join_rule,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_list__set_room_list_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_list_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            let api_filter =
+                <crate::matrix::room_list::RoomListFilterKind>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::room_list::set_room_list_filter(
+                        api_subscription_id,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__tags__set_room_low_priority_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_low_priority",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_is_low_priority = <bool>::sse_decode(&mut deserializer);
+            let api_order = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::tags::set_room_low_priority(
+                        api_room_id,
+                        api_is_low_priority,
+                        api_order,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__receipts__set_room_marked_unread_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_marked_unread",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_unread = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::receipts::set_room_marked_unread(api_room_id, api_unread)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__set_room_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_settings::set_room_name(api_room_id, api_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__directory__set_room_published_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_published",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_published = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::directory::set_room_published(api_room_id, api_published)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__tags__set_room_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            let api_order = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::tags::set_room_tag(api_room_id, api_tag, api_order)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__set_room_topic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_room_topic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_topic = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_settings::set_room_topic(api_room_id, api_topic)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__typing__set_typing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_typing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_typing = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::typing::set_typing(api_room_id, api_typing)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__moderation__set_user_power_level_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_user_power_level",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_power_level = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::moderation::set_user_power_level(
+                        api_room_id,
+                        api_user_id,
+                        api_power_level,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__sync_service__start_sync_service_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_sync_service",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::sync_service::start_sync_service()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_all_room_updates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::room_list::RoomListUpdate>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::matrix::rooms::subscribe_to_all_room_updates(api_stream);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__subscribe_to_event_timeline_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_event_timeline_updates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::timelines::MessageUpdate>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_num_context_events = <Option<u16>>::sse_decode(&mut deserializer);
+            let api_throttle_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::matrix::timelines::subscribe_to_event_timeline_updates(
+                                api_stream,
+                                api_room_id,
+                                api_event_id,
+                                api_num_context_events,
+                                api_throttle_ms,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__knocking__subscribe_to_knock_requests_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_knock_requests",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::knocking::KnockRequestInfo>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::knocking::subscribe_to_knock_requests(
+                            api_stream,
+                            api_room_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__subscribe_to_pagination_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_pagination_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                crate::matrix::timelines::PaginationStatus,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::timelines::subscribe_to_pagination_status(
+                            api_stream,
+                            api_room_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__presence__subscribe_to_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                crate::matrix::presence::UserPresence,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_user_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::presence::subscribe_to_presence(
+                            api_stream,
+                            api_user_ids,
+                            api_subscription_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__receipts__subscribe_to_read_receipts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_read_receipts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::receipts::ReadReceipt>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::receipts::subscribe_to_read_receipts(
+                            api_stream,
+                            api_room_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_list__subscribe_to_room_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_room_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::room_list::RoomListUpdate>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            let api_filter =
+                <crate::matrix::room_list::RoomListFilterKind>::sse_decode(&mut deserializer);
+            let api_page_size = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::room_list::subscribe_to_room_list(
+                            api_stream,
+                            api_subscription_id,
+                            api_filter,
+                            api_page_size,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__members__subscribe_to_room_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_room_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::members::RoomMembersUpdate>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::members::subscribe_to_room_members(
+                            api_stream,
+                            api_room_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__subscribe_to_timeline_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_timeline_updates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::timelines::MessageUpdate>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            let api_throttle_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::timelines::subscribe_to_timeline_updates(
+                            api_stream,
+                            api_room_id,
+                            api_subscription_id,
+                            api_throttle_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__matrix__typing__subscribe_to_typing_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_to_typing_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream = <StreamSink<
+                Vec<crate::matrix::user_serach::User>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::matrix::typing::subscribe_to_typing_users(
+                            api_stream,
+                            api_room_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init__test_server_connectivity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_server_connectivity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::init::test_server_connectivity())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__moderation__unban_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unban_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::moderation::unban_user(
+                        api_room_id,
+                        api_user_id,
+                        api_reason,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__ignored_users__unignore_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unignore_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::ignored_users::unignore_user(api_user_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__presence__unsubscribe_from_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_from_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::matrix::presence::unsubscribe_from_presence(api_subscription_id);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__timelines__unsubscribe_from_timeline_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_from_timeline_updates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_subscription_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::matrix::timelines::unsubscribe_from_timeline_updates(
+                        api_room_id,
+                        api_subscription_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__matrix__room_settings__upgrade_room_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upgrade_room",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room_id = <String>::sse_decode(&mut deserializer);
+            let api_new_version = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::matrix::room_settings::upgrade_room(api_room_id, api_new_version)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for Certificate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for FieldsFormatterForFiles {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
     for RustOpaqueMoi<
         flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
     >
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::knocking::KnockRequestInfo>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::timelines::MessageUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::receipts::ReadReceipt>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::room_list::RoomListUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::members::RoomMembersUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::matrix::user_serach::User>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::matrix::timelines::PaginationStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::matrix::presence::UserPresence,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::matrix::client::ClientConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionPath = <String>::sse_decode(deserializer);
+        let mut var_homeserverUrl = <String>::sse_decode(deserializer);
+        let mut var_rootCertificates = <Option<Vec<Certificate>>>::sse_decode(deserializer);
+        let mut var_proxy = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::client::ClientConfig {
+            session_path: var_sessionPath,
+            homeserver_url: var_homeserverUrl,
+            root_certificates: var_rootCertificates,
+            proxy: var_proxy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::platform::ClientError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_msg = <String>::sse_decode(deserializer);
+                let mut var_details = <Option<String>>::sse_decode(deserializer);
+                return crate::api::platform::ClientError::Generic {
+                    msg: var_msg,
+                    details: var_details,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::CreateRoomParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_alias = <Option<String>>::sse_decode(deserializer);
+        let mut var_visibility = <crate::matrix::rooms::RoomVisibility>::sse_decode(deserializer);
+        let mut var_isEncrypted = <bool>::sse_decode(deserializer);
+        let mut var_historyVisibility =
+            <Option<crate::matrix::rooms::RoomHistoryVisibility>>::sse_decode(deserializer);
+        let mut var_guestAccess = <Option<bool>>::sse_decode(deserializer);
+        let mut var_powerLevelOverrides =
+            <Option<crate::matrix::rooms::PowerLevelOverrides>>::sse_decode(deserializer);
+        let mut var_roomVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_spaceParentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_invite = <Vec<String>>::sse_decode(deserializer);
+        let mut var_isDirect = <bool>::sse_decode(deserializer);
+        let mut var_isSpace = <bool>::sse_decode(deserializer);
+        return crate::matrix::rooms::CreateRoomParams {
+            name: var_name,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            alias: var_alias,
+            visibility: var_visibility,
+            is_encrypted: var_isEncrypted,
+            history_visibility: var_historyVisibility,
+            guest_access: var_guestAccess,
+            power_level_overrides: var_powerLevelOverrides,
+            room_version: var_roomVersion,
+            space_parent_id: var_spaceParentId,
+            invite: var_invite,
+            is_direct: var_isDirect,
+            is_space: var_isSpace,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::EventPowerLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventType = <String>::sse_decode(deserializer);
+        let mut var_powerLevel = <i64>::sse_decode(deserializer);
+        return crate::matrix::rooms::EventPowerLevel {
+            event_type: var_eventType,
+            power_level: var_powerLevel,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i8().unwrap()
+    }
+}
+
+impl SseDecode for crate::matrix::invites::InviteDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_roomName = <Option<String>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_memberCount = <u64>::sse_decode(deserializer);
+        let mut var_isDm = <bool>::sse_decode(deserializer);
+        let mut var_inviter = <Option<crate::matrix::user_serach::User>>::sse_decode(deserializer);
+        return crate::matrix::invites::InviteDetails {
+            room_id: var_roomId,
+            room_name: var_roomName,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            member_count: var_memberCount,
+            is_dm: var_isDm,
+            inviter: var_inviter,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::knocking::KnockRequestInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_reason = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isSeen = <bool>::sse_decode(deserializer);
+        return crate::matrix::knocking::KnockRequestInfo {
+            event_id: var_eventId,
+            user_id: var_userId,
+            display_name: var_displayName,
+            avatar_url: var_avatarUrl,
+            reason: var_reason,
+            timestamp: var_timestamp,
+            is_seen: var_isSeen,
+        };
+    }
+}
+
+impl SseDecode for Vec<Certificate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Certificate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::rooms::EventPowerLevel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::rooms::EventPowerLevel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::invites::InviteDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::invites::InviteDetails>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::knocking::KnockRequestInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::knocking::KnockRequestInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::timelines::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::timelines::Message>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::timelines::MessageUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::timelines::MessageUpdate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::directory::PublicRoom> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::directory::PublicRoom>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::receipts::ReadReceipt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::receipts::ReadReceipt>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::room_list::RoomListFilterKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::room_list::RoomListFilterKind>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::room_list::RoomListUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::room_list::RoomListUpdate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::members::RoomMemberInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::members::RoomMemberInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::members::RoomMembersUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::members::RoomMembersUpdate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::tags::RoomTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::tags::RoomTag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::rooms::RoomUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::rooms::RoomUpdate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::spaces::SpaceHierarchyRoom> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::spaces::SpaceHierarchyRoom>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::platform::TraceLogPacks> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::platform::TraceLogPacks>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::user_serach::User> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::user_serach::User>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::matrix::rooms::UserPowerLevel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::matrix::rooms::UserPowerLevel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::logger::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_tag = <String>::sse_decode(deserializer);
+        return crate::api::logger::LogEntry {
+            level: var_level,
+            message: var_message,
+            timestamp: var_timestamp,
+            tag: var_tag,
+        };
+    }
+}
+
+impl SseDecode for crate::api::tracing::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::tracing::LogLevel::Error,
+            1 => crate::api::tracing::LogLevel::Warn,
+            2 => crate::api::tracing::LogLevel::Info,
+            3 => crate::api::tracing::LogLevel::Debug,
+            4 => crate::api::tracing::LogLevel::Trace,
+            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::members::MemberMembership {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::members::MemberMembership::Join,
+            1 => crate::matrix::members::MemberMembership::Invite,
+            2 => crate::matrix::members::MemberMembership::Knock,
+            3 => crate::matrix::members::MemberMembership::Leave,
+            4 => crate::matrix::members::MemberMembership::Ban,
+            _ => unreachable!("Invalid variant for MemberMembership: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::members::MemberRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::members::MemberRole::Administrator,
+            1 => crate::matrix::members::MemberRole::Moderator,
+            2 => crate::matrix::members::MemberRole::User,
+            _ => unreachable!("Invalid variant for MemberRole: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::timelines::Message {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_sender = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_messageType = <crate::matrix::timelines::MessageType>::sse_decode(deserializer);
+        let mut var_readReceipts =
+            <Vec<crate::matrix::receipts::ReadReceipt>>::sse_decode(deserializer);
+        return crate::matrix::timelines::Message {
+            event_id: var_eventId,
+            sender: var_sender,
+            content: var_content,
+            timestamp: var_timestamp,
+            message_type: var_messageType,
+            read_receipts: var_readReceipts,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::timelines::MessageType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::matrix::timelines::MessageType::Message;
+            }
+            1 => {
+                return crate::matrix::timelines::MessageType::DateDivider;
+            }
+            2 => {
+                let mut var_ownReceiptEventId = <Option<String>>::sse_decode(deserializer);
+                return crate::matrix::timelines::MessageType::ReadMarker {
+                    own_receipt_event_id: var_ownReceiptEventId,
+                };
+            }
+            3 => {
+                return crate::matrix::timelines::MessageType::TimelineStart;
+            }
+            4 => {
+                let mut var_replacementRoomId = <String>::sse_decode(deserializer);
+                return crate::matrix::timelines::MessageType::Tombstone {
+                    replacement_room_id: var_replacementRoomId,
+                };
+            }
+            5 => {
+                let mut var_predecessorRoomId = <String>::sse_decode(deserializer);
+                return crate::matrix::timelines::MessageType::Predecessor {
+                    predecessor_room_id: var_predecessorRoomId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::timelines::MessageUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messageUpdateType =
+            <crate::matrix::timelines::MessageUpdateType>::sse_decode(deserializer);
+        let mut var_messages =
+            <Option<Vec<crate::matrix::timelines::Message>>>::sse_decode(deserializer);
+        let mut var_index = <Option<usize>>::sse_decode(deserializer);
+        let mut var_length = <Option<usize>>::sse_decode(deserializer);
+        return crate::matrix::timelines::MessageUpdate {
+            message_update_type: var_messageUpdateType,
+            messages: var_messages,
+            index: var_index,
+            length: var_length,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::timelines::MessageUpdateType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::timelines::MessageUpdateType::Reset,
+            1 => crate::matrix::timelines::MessageUpdateType::Truncate,
+            2 => crate::matrix::timelines::MessageUpdateType::Remove,
+            3 => crate::matrix::timelines::MessageUpdateType::Set,
+            4 => crate::matrix::timelines::MessageUpdateType::Insert,
+            5 => crate::matrix::timelines::MessageUpdateType::PopBack,
+            6 => crate::matrix::timelines::MessageUpdateType::PopFront,
+            7 => crate::matrix::timelines::MessageUpdateType::PushBack,
+            8 => crate::matrix::timelines::MessageUpdateType::PushFront,
+            9 => crate::matrix::timelines::MessageUpdateType::Clear,
+            10 => crate::matrix::timelines::MessageUpdateType::Append,
+            _ => unreachable!("Invalid variant for MessageUpdateType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::timelines::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::timelines::Message>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::rooms::PowerLevelOverrides> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::rooms::PowerLevelOverrides>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::receipts::ReadReceipt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::receipts::ReadReceipt>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::rooms::RoomHistoryVisibility> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::rooms::RoomHistoryVisibility>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::room_settings::RoomJoinRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::room_settings::RoomJoinRule>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::rooms::RoomSuccessor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::rooms::RoomSuccessor>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::platform::TracingFileConfiguration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::platform::TracingFileConfiguration>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::rooms::UpdateType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::rooms::UpdateType>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::matrix::user_serach::User> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::matrix::user_serach::User>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<Certificate>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<Certificate>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::matrix::timelines::Message>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::matrix::timelines::Message>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::matrix::rooms::RoomUpdate>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::matrix::rooms::RoomUpdate>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::timelines::PaginationStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::timelines::PaginationStatus::Idle,
+            1 => crate::matrix::timelines::PaginationStatus::Paginating,
+            2 => crate::matrix::timelines::PaginationStatus::ReachedStart,
+            _ => unreachable!("Invalid variant for PaginationStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::moderation::PowerLevelAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::matrix::moderation::PowerLevelAction::Ban;
+            }
+            1 => {
+                return crate::matrix::moderation::PowerLevelAction::Invite;
+            }
+            2 => {
+                return crate::matrix::moderation::PowerLevelAction::Kick;
+            }
+            3 => {
+                return crate::matrix::moderation::PowerLevelAction::Redact;
+            }
+            4 => {
+                return crate::matrix::moderation::PowerLevelAction::EventsDefault;
+            }
+            5 => {
+                return crate::matrix::moderation::PowerLevelAction::StateDefault;
+            }
+            6 => {
+                return crate::matrix::moderation::PowerLevelAction::UsersDefault;
+            }
+            7 => {
+                let mut var_eventType = <String>::sse_decode(deserializer);
+                return crate::matrix::moderation::PowerLevelAction::Event {
+                    event_type: var_eventType,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::PowerLevelOverrides {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ban = <Option<i64>>::sse_decode(deserializer);
+        let mut var_invite = <Option<i64>>::sse_decode(deserializer);
+        let mut var_kick = <Option<i64>>::sse_decode(deserializer);
+        let mut var_redact = <Option<i64>>::sse_decode(deserializer);
+        let mut var_eventsDefault = <Option<i64>>::sse_decode(deserializer);
+        let mut var_stateDefault = <Option<i64>>::sse_decode(deserializer);
+        let mut var_usersDefault = <Option<i64>>::sse_decode(deserializer);
+        let mut var_events = <Vec<crate::matrix::rooms::EventPowerLevel>>::sse_decode(deserializer);
+        let mut var_users = <Vec<crate::matrix::rooms::UserPowerLevel>>::sse_decode(deserializer);
+        return crate::matrix::rooms::PowerLevelOverrides {
+            ban: var_ban,
+            invite: var_invite,
+            kick: var_kick,
+            redact: var_redact,
+            events_default: var_eventsDefault,
+            state_default: var_stateDefault,
+            users_default: var_usersDefault,
+            events: var_events,
+            users: var_users,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::presence::PresenceKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::presence::PresenceKind::Online,
+            1 => crate::matrix::presence::PresenceKind::Unavailable,
+            2 => crate::matrix::presence::PresenceKind::Offline,
+            _ => unreachable!("Invalid variant for PresenceKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::directory::PublicRoom {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_canonicalAlias = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_numJoinedMembers = <u64>::sse_decode(deserializer);
+        let mut var_worldReadable = <bool>::sse_decode(deserializer);
+        let mut var_guestCanJoin = <bool>::sse_decode(deserializer);
+        let mut var_isSpace = <bool>::sse_decode(deserializer);
+        return crate::matrix::directory::PublicRoom {
+            room_id: var_roomId,
+            canonical_alias: var_canonicalAlias,
+            name: var_name,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            num_joined_members: var_numJoinedMembers,
+            world_readable: var_worldReadable,
+            guest_can_join: var_guestCanJoin,
+            is_space: var_isSpace,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::directory::PublicRoomsPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rooms = <Vec<crate::matrix::directory::PublicRoom>>::sse_decode(deserializer);
+        let mut var_nextBatch = <Option<String>>::sse_decode(deserializer);
+        let mut var_prevBatch = <Option<String>>::sse_decode(deserializer);
+        let mut var_totalRoomCountEstimate = <Option<u64>>::sse_decode(deserializer);
+        return crate::matrix::directory::PublicRoomsPage {
+            rooms: var_rooms,
+            next_batch: var_nextBatch,
+            prev_batch: var_prevBatch,
+            total_room_count_estimate: var_totalRoomCountEstimate,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::receipts::ReadReceipt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <Option<u64>>::sse_decode(deserializer);
+        return crate::matrix::receipts::ReadReceipt {
+            user_id: var_userId,
+            event_id: var_eventId,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::receipts::ReceiptKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::receipts::ReceiptKind::Public,
+            1 => crate::matrix::receipts::ReceiptKind::Private,
+            2 => crate::matrix::receipts::ReceiptKind::FullyRead,
+            _ => unreachable!("Invalid variant for ReceiptKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::directory::RoomAliases {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_canonicalAlias = <Option<String>>::sse_decode(deserializer);
+        let mut var_altAliases = <Vec<String>>::sse_decode(deserializer);
+        return crate::matrix::directory::RoomAliases {
+            canonical_alias: var_canonicalAlias,
+            alt_aliases: var_altAliases,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::RoomHistoryVisibility {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::rooms::RoomHistoryVisibility::Invited,
+            1 => crate::matrix::rooms::RoomHistoryVisibility::Joined,
+            2 => crate::matrix::rooms::RoomHistoryVisibility::Shared,
+            3 => crate::matrix::rooms::RoomHistoryVisibility::WorldReadable,
+            _ => unreachable!("Invalid variant for RoomHistoryVisibility: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::room_settings::RoomJoinRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::matrix::room_settings::RoomJoinRule::Public;
+            }
+            1 => {
+                return crate::matrix::room_settings::RoomJoinRule::Invite;
+            }
+            2 => {
+                return crate::matrix::room_settings::RoomJoinRule::Knock;
+            }
+            3 => {
+                let mut var_allowedRoomIds = <Vec<String>>::sse_decode(deserializer);
+                return crate::matrix::room_settings::RoomJoinRule::Restricted {
+                    allowed_room_ids: var_allowedRoomIds,
+                };
+            }
+            4 => {
+                let mut var_allowedRoomIds = <Vec<String>>::sse_decode(deserializer);
+                return crate::matrix::room_settings::RoomJoinRule::KnockRestricted {
+                    allowed_room_ids: var_allowedRoomIds,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::room_list::RoomListFilterCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::room_list::RoomListFilterCategory::Group,
+            1 => crate::matrix::room_list::RoomListFilterCategory::People,
+            _ => unreachable!("Invalid variant for RoomListFilterCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::room_list::RoomListFilterKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_filters =
+                    <Vec<crate::matrix::room_list::RoomListFilterKind>>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::All {
+                    filters: var_filters,
+                };
+            }
+            1 => {
+                let mut var_filters =
+                    <Vec<crate::matrix::room_list::RoomListFilterKind>>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::Any {
+                    filters: var_filters,
+                };
+            }
+            2 => {
+                return crate::matrix::room_list::RoomListFilterKind::NonSpace;
+            }
+            3 => {
+                return crate::matrix::room_list::RoomListFilterKind::NonLeft;
+            }
+            4 => {
+                return crate::matrix::room_list::RoomListFilterKind::Joined;
+            }
+            5 => {
+                return crate::matrix::room_list::RoomListFilterKind::Unread;
+            }
+            6 => {
+                return crate::matrix::room_list::RoomListFilterKind::Favourite;
+            }
+            7 => {
+                return crate::matrix::room_list::RoomListFilterKind::LowPriority;
+            }
+            8 => {
+                return crate::matrix::room_list::RoomListFilterKind::NonLowPriority;
+            }
+            9 => {
+                return crate::matrix::room_list::RoomListFilterKind::Invite;
+            }
+            10 => {
+                let mut var_expect =
+                    <crate::matrix::room_list::RoomListFilterCategory>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::Category {
+                    expect: var_expect,
+                };
+            }
+            11 => {
+                return crate::matrix::room_list::RoomListFilterKind::None;
+            }
+            12 => {
+                let mut var_pattern = <String>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::NormalizedMatchRoomName {
+                    pattern: var_pattern,
+                };
+            }
+            13 => {
+                let mut var_pattern = <String>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::FuzzyMatchRoomName {
+                    pattern: var_pattern,
+                };
+            }
+            14 => {
+                return crate::matrix::room_list::RoomListFilterKind::DeduplicateVersions;
+            }
+            15 => {
+                let mut var_name = <String>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::Tag { name: var_name };
+            }
+            16 => {
+                let mut var_spaceId = <String>::sse_decode(deserializer);
+                return crate::matrix::room_list::RoomListFilterKind::Space {
+                    space_id: var_spaceId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::room_list::RoomListUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_updateType =
+            <crate::matrix::room_list::RoomListUpdateType>::sse_decode(deserializer);
+        let mut var_rooms =
+            <Option<Vec<crate::matrix::rooms::RoomUpdate>>>::sse_decode(deserializer);
+        let mut var_index = <Option<usize>>::sse_decode(deserializer);
+        let mut var_length = <Option<usize>>::sse_decode(deserializer);
+        return crate::matrix::room_list::RoomListUpdate {
+            update_type: var_updateType,
+            rooms: var_rooms,
+            index: var_index,
+            length: var_length,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::room_list::RoomListUpdateType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::room_list::RoomListUpdateType::Reset,
+            1 => crate::matrix::room_list::RoomListUpdateType::Truncate,
+            2 => crate::matrix::room_list::RoomListUpdateType::Remove,
+            3 => crate::matrix::room_list::RoomListUpdateType::Set,
+            4 => crate::matrix::room_list::RoomListUpdateType::Insert,
+            5 => crate::matrix::room_list::RoomListUpdateType::PopBack,
+            6 => crate::matrix::room_list::RoomListUpdateType::PopFront,
+            7 => crate::matrix::room_list::RoomListUpdateType::PushBack,
+            8 => crate::matrix::room_list::RoomListUpdateType::PushFront,
+            9 => crate::matrix::room_list::RoomListUpdateType::Clear,
+            10 => crate::matrix::room_list::RoomListUpdateType::Append,
+            _ => unreachable!("Invalid variant for RoomListUpdateType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::members::RoomMemberInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_membership =
+            <crate::matrix::members::MemberMembership>::sse_decode(deserializer);
+        let mut var_powerLevel = <i64>::sse_decode(deserializer);
+        let mut var_role = <crate::matrix::members::MemberRole>::sse_decode(deserializer);
+        return crate::matrix::members::RoomMemberInfo {
+            user_id: var_userId,
+            display_name: var_displayName,
+            avatar_url: var_avatarUrl,
+            membership: var_membership,
+            power_level: var_powerLevel,
+            role: var_role,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::members::RoomMembersPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_members =
+            <Vec<crate::matrix::members::RoomMemberInfo>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::matrix::members::RoomMembersPage {
+            members: var_members,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::members::RoomMembersUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_members =
+                    <Vec<crate::matrix::members::RoomMemberInfo>>::sse_decode(deserializer);
+                return crate::matrix::members::RoomMembersUpdate::Reset {
+                    members: var_members,
+                };
+            }
+            1 => {
+                let mut var_member =
+                    <crate::matrix::members::RoomMemberInfo>::sse_decode(deserializer);
+                return crate::matrix::members::RoomMembersUpdate::Upsert { member: var_member };
+            }
+            2 => {
+                let mut var_userId = <String>::sse_decode(deserializer);
+                return crate::matrix::members::RoomMembersUpdate::Remove {
+                    user_id: var_userId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::matrix::moderation::RoomPermissions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ownPowerLevel = <i64>::sse_decode(deserializer);
+        let mut var_canSendMessage = <bool>::sse_decode(deserializer);
+        let mut var_canRedactOwn = <bool>::sse_decode(deserializer);
+        let mut var_canRedactOthers = <bool>::sse_decode(deserializer);
+        let mut var_canInvite = <bool>::sse_decode(deserializer);
+        let mut var_canKick = <bool>::sse_decode(deserializer);
+        let mut var_canBan = <bool>::sse_decode(deserializer);
+        let mut var_canChangeSettings = <bool>::sse_decode(deserializer);
+        let mut var_canChangePowerLevels = <bool>::sse_decode(deserializer);
+        return crate::matrix::moderation::RoomPermissions {
+            own_power_level: var_ownPowerLevel,
+            can_send_message: var_canSendMessage,
+            can_redact_own: var_canRedactOwn,
+            can_redact_others: var_canRedactOthers,
+            can_invite: var_canInvite,
+            can_kick: var_canKick,
+            can_ban: var_canBan,
+            can_change_settings: var_canChangeSettings,
+            can_change_power_levels: var_canChangePowerLevels,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::moderation::RoomPowerLevelsInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ban = <i64>::sse_decode(deserializer);
+        let mut var_invite = <i64>::sse_decode(deserializer);
+        let mut var_kick = <i64>::sse_decode(deserializer);
+        let mut var_redact = <i64>::sse_decode(deserializer);
+        let mut var_eventsDefault = <i64>::sse_decode(deserializer);
+        let mut var_stateDefault = <i64>::sse_decode(deserializer);
+        let mut var_usersDefault = <i64>::sse_decode(deserializer);
+        let mut var_events = <Vec<crate::matrix::rooms::EventPowerLevel>>::sse_decode(deserializer);
+        let mut var_users = <Vec<crate::matrix::rooms::UserPowerLevel>>::sse_decode(deserializer);
+        return crate::matrix::moderation::RoomPowerLevelsInfo {
+            ban: var_ban,
+            invite: var_invite,
+            kick: var_kick,
+            redact: var_redact,
+            events_default: var_eventsDefault,
+            state_default: var_stateDefault,
+            users_default: var_usersDefault,
+            events: var_events,
+            users: var_users,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::joining::RoomPreviewInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_canonicalAlias = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_numJoinedMembers = <u64>::sse_decode(deserializer);
+        let mut var_isSpace = <bool>::sse_decode(deserializer);
+        let mut var_isWorldReadable = <Option<bool>>::sse_decode(deserializer);
+        let mut var_isDirect = <Option<bool>>::sse_decode(deserializer);
+        let mut var_membership =
+            <Option<crate::matrix::rooms::UpdateType>>::sse_decode(deserializer);
+        return crate::matrix::joining::RoomPreviewInfo {
+            room_id: var_roomId,
+            canonical_alias: var_canonicalAlias,
+            name: var_name,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            num_joined_members: var_numJoinedMembers,
+            is_space: var_isSpace,
+            is_world_readable: var_isWorldReadable,
+            is_direct: var_isDirect,
+            membership: var_membership,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::RoomSuccessor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_reason = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::rooms::RoomSuccessor {
+            room_id: var_roomId,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::tags::RoomTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_order = <Option<f64>>::sse_decode(deserializer);
+        return crate::matrix::tags::RoomTag {
+            name: var_name,
+            order: var_order,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::RoomUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_rawName = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_isDm = <Option<bool>>::sse_decode(deserializer);
+        let mut var_updateType = <crate::matrix::rooms::UpdateType>::sse_decode(deserializer);
+        let mut var_unreadNotifications = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unreadHighlight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unreadMentions = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unreadMessages = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isMarkedUnread = <bool>::sse_decode(deserializer);
+        let mut var_message = <Option<crate::matrix::timelines::Message>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::matrix::tags::RoomTag>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_canonicalAlias = <Option<String>>::sse_decode(deserializer);
+        let mut var_isEncrypted = <bool>::sse_decode(deserializer);
+        let mut var_historyVisibility =
+            <Option<crate::matrix::rooms::RoomHistoryVisibility>>::sse_decode(deserializer);
+        let mut var_joinRule =
+            <Option<crate::matrix::room_settings::RoomJoinRule>>::sse_decode(deserializer);
+        let mut var_successor =
+            <Option<crate::matrix::rooms::RoomSuccessor>>::sse_decode(deserializer);
+        let mut var_predecessorRoomId = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::rooms::RoomUpdate {
+            room_id: var_roomId,
+            raw_name: var_rawName,
+            display_name: var_displayName,
+            is_dm: var_isDm,
+            update_type: var_updateType,
+            unread_notifications: var_unreadNotifications,
+            unread_highlight: var_unreadHighlight,
+            unread_mentions: var_unreadMentions,
+            unread_messages: var_unreadMessages,
+            is_marked_unread: var_isMarkedUnread,
+            message: var_message,
+            tags: var_tags,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            canonical_alias: var_canonicalAlias,
+            is_encrypted: var_isEncrypted,
+            history_visibility: var_historyVisibility,
+            join_rule: var_joinRule,
+            successor: var_successor,
+            predecessor_room_id: var_predecessorRoomId,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::RoomVisibility {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::rooms::RoomVisibility::Public,
+            1 => crate::matrix::rooms::RoomVisibility::Private,
+            2 => crate::matrix::rooms::RoomVisibility::Knock,
+            _ => unreachable!("Invalid variant for RoomVisibility: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::spaces::SpaceHierarchyPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rooms =
+            <Vec<crate::matrix::spaces::SpaceHierarchyRoom>>::sse_decode(deserializer);
+        let mut var_nextBatch = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::spaces::SpaceHierarchyPage {
+            rooms: var_rooms,
+            next_batch: var_nextBatch,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::spaces::SpaceHierarchyRoom {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_roomId = <String>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_topic = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_canonicalAlias = <Option<String>>::sse_decode(deserializer);
+        let mut var_numJoinedMembers = <u64>::sse_decode(deserializer);
+        let mut var_isSpace = <bool>::sse_decode(deserializer);
+        let mut var_suggested = <bool>::sse_decode(deserializer);
+        let mut var_children = <Vec<String>>::sse_decode(deserializer);
+        let mut var_membership =
+            <Option<crate::matrix::rooms::UpdateType>>::sse_decode(deserializer);
+        return crate::matrix::spaces::SpaceHierarchyRoom {
+            room_id: var_roomId,
+            name: var_name,
+            topic: var_topic,
+            avatar_url: var_avatarUrl,
+            canonical_alias: var_canonicalAlias,
+            num_joined_members: var_numJoinedMembers,
+            is_space: var_isSpace,
+            suggested: var_suggested,
+            children: var_children,
+            membership: var_membership,
+        };
+    }
+}
+
+impl SseDecode for crate::api::platform::TraceLogPacks {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::platform::TraceLogPacks::EventCache,
+            1 => crate::api::platform::TraceLogPacks::SendQueue,
+            2 => crate::api::platform::TraceLogPacks::Timeline,
+            3 => crate::api::platform::TraceLogPacks::NotificationClient,
+            _ => unreachable!("Invalid variant for TraceLogPacks: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::platform::TracingConfiguration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_logLevel = <crate::api::tracing::LogLevel>::sse_decode(deserializer);
+        let mut var_traceLogPacks =
+            <Vec<crate::api::platform::TraceLogPacks>>::sse_decode(deserializer);
+        let mut var_extraTargets = <Vec<String>>::sse_decode(deserializer);
+        let mut var_writeToStdoutOrSystem = <bool>::sse_decode(deserializer);
+        let mut var_writeToFiles =
+            <Option<crate::api::platform::TracingFileConfiguration>>::sse_decode(deserializer);
+        return crate::api::platform::TracingConfiguration {
+            log_level: var_logLevel,
+            trace_log_packs: var_traceLogPacks,
+            extra_targets: var_extraTargets,
+            write_to_stdout_or_system: var_writeToStdoutOrSystem,
+            write_to_files: var_writeToFiles,
+        };
+    }
+}
+
+impl SseDecode for crate::api::platform::TracingFileConfiguration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_filePrefix = <String>::sse_decode(deserializer);
+        let mut var_fileSuffix = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxFiles = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::platform::TracingFileConfiguration {
+            path: var_path,
+            file_prefix: var_filePrefix,
+            file_suffix: var_fileSuffix,
+            max_files: var_maxFiles,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::matrix::rooms::UpdateType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::matrix::rooms::UpdateType::Joined,
+            1 => crate::matrix::rooms::UpdateType::Left,
+            2 => crate::matrix::rooms::UpdateType::Invited,
+            3 => crate::matrix::rooms::UpdateType::Knocked,
+            4 => crate::matrix::rooms::UpdateType::Banned,
+            _ => unreachable!("Invalid variant for UpdateType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::user_serach::User {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::user_serach::User {
+            user_id: var_userId,
+            display_name: var_displayName,
+            avatar_url: var_avatarUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::rooms::UserPowerLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_powerLevel = <i64>::sse_decode(deserializer);
+        return crate::matrix::rooms::UserPowerLevel {
+            user_id: var_userId,
+            power_level: var_powerLevel,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::presence::UserPresence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_presence = <crate::matrix::presence::PresenceKind>::sse_decode(deserializer);
+        let mut var_lastActive = <Option<u64>>::sse_decode(deserializer);
+        let mut var_currentlyActive = <Option<bool>>::sse_decode(deserializer);
+        let mut var_statusMessage = <Option<String>>::sse_decode(deserializer);
+        return crate::matrix::presence::UserPresence {
+            user_id: var_userId,
+            presence: var_presence,
+            last_active: var_lastActive,
+            currently_active: var_currentlyActive,
+            status_message: var_statusMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::matrix::user_serach::UserSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_users = <Vec<crate::matrix::user_serach::User>>::sse_decode(deserializer);
+        let mut var_limited = <bool>::sse_decode(deserializer);
+        return crate::matrix::user_serach::UserSearchResult {
+            users: var_users,
+            limited: var_limited,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__matrix__rooms__CreateRoomParams_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__matrix__rooms__PowerLevelOverrides_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__matrix__rooms__RoomVisibility_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__logger___log_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__matrix__invites__accept_invite_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__matrix__knocking__accept_knock_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__matrix__directory__add_room_alias_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__matrix__spaces__add_room_to_space_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__matrix__moderation__ban_user_impl(port, ptr, rust_vec_len, data_len),
+        11 => {
+            wire__crate__api__init__check_matrix_sdk_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        12 => wire__crate__matrix__client__configure_client_impl(port, ptr, rust_vec_len, data_len),
+        13 => {
+            wire__crate__matrix__rooms__create_direct_room_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__matrix__rooms__create_group_room_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__logger__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__matrix__rooms__create_room_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__matrix__spaces__create_space_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__matrix__invites__decline_invite_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__matrix__knocking__decline_knock_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__matrix__room_settings__enable_room_encryption_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__matrix__rooms__get_all_rooms_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__matrix__ignored_users__get_ignored_users_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => {
+            wire__crate__matrix__invites__get_invite_details_impl(port, ptr, rust_vec_len, data_len)
+        }
+        24 => wire__crate__matrix__invites__get_invites_impl(port, ptr, rust_vec_len, data_len),
+        25 => {
+            wire__crate__matrix__spaces__get_joined_spaces_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__init__get_matrix_config_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__matrix__timelines__get_older_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__matrix__direct_messages__get_or_create_dm_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__matrix__profile__get_own_profile_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__matrix__receipts__get_own_read_receipt_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => {
+            wire__crate__matrix__directory__get_room_aliases_impl(port, ptr, rust_vec_len, data_len)
+        }
+        32 => {
+            wire__crate__matrix__members__get_room_members_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__matrix__moderation__get_room_permissions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__matrix__moderation__get_room_power_levels_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => {
+            wire__crate__matrix__joining__get_room_preview_impl(port, ptr, rust_vec_len, data_len)
+        }
+        36 => {
+            wire__crate__matrix__spaces__get_space_hierarchy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        37 => wire__crate__api__init__get_sync_operations_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__matrix__timelines__get_timeline_items_by_room_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => {
+            wire__crate__matrix__presence__get_user_presence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        40 => {
+            wire__crate__matrix__profile__get_user_profile_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => {
+            wire__crate__matrix__ignored_users__ignore_user_impl(port, ptr, rust_vec_len, data_len)
+        }
+        42 => wire__crate__api__init__init_app_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__platform__init_platform_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__matrix__moderation__invite_user_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__matrix__authentication__is_client_authenticated_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__matrix__directory__is_room_published_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__matrix__rooms__join_room_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__matrix__joining__join_room_by_id_or_alias_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__matrix__moderation__kick_user_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__matrix__knocking__knock_room_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__matrix__rooms__leave_room_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__logger__log_debug_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__logger__log_error_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__tracing__log_event_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__logger__log_info_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__logger__log_warn_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__matrix__authentication__login_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__matrix__authentication__logout_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__matrix__knocking__mark_knock_requests_as_seen_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => {
+            wire__crate__matrix__receipts__mark_room_as_read_impl(port, ptr, rust_vec_len, data_len)
+        }
+        62 => wire__crate__matrix__presence__new_presence_subscription_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__matrix__room_list__new_room_list_subscription_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__matrix__timelines__new_timeline_subscription_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__matrix__timelines__paginate_event_timeline_backwards_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__matrix__timelines__paginate_event_timeline_forwards_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__matrix__timelines__preload_timelines_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__matrix__authentication__register_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__platform__reload_tracing_file_writer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__matrix__profile__remove_avatar_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__matrix__directory__remove_room_alias_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__matrix__room_settings__remove_room_avatar_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__matrix__spaces__remove_room_from_space_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__matrix__tags__remove_room_tag_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__matrix__reporting__report_event_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__matrix__reporting__report_room_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__matrix__reporting__report_user_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__matrix__timelines__return_to_live_timeline_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => wire__crate__matrix__room_list__room_list_load_next_page_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => wire__crate__matrix__room_list__room_list_reset_to_one_page_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__matrix__directory__search_public_rooms_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__matrix__members__search_room_members_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        83 => {
+            wire__crate__matrix__user_serach__search_users_impl(port, ptr, rust_vec_len, data_len)
+        }
+        84 => wire__crate__matrix__rooms__send_message_impl(port, ptr, rust_vec_len, data_len),
+        85 => {
+            wire__crate__matrix__receipts__send_read_receipt_impl(port, ptr, rust_vec_len, data_len)
+        }
+        86 => wire__crate__matrix__moderation__set_action_power_level_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => wire__crate__matrix__profile__set_avatar_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__matrix__directory__set_canonical_alias_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => {
+            wire__crate__matrix__profile__set_display_name_impl(port, ptr, rust_vec_len, data_len)
+        }
+        90 => {
+            wire__crate__matrix__presence__set_own_presence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        91 => {
+            wire__crate__matrix__receipts__set_read_markers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        92 => wire__crate__matrix__room_settings__set_room_avatar_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        93 => wire__crate__matrix__tags__set_room_favourite_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__matrix__room_settings__set_room_history_visibility_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        95 => wire__crate__matrix__room_settings__set_room_join_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        96 => wire__crate__matrix__room_list__set_room_list_filter_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => {
+            wire__crate__matrix__tags__set_room_low_priority_impl(port, ptr, rust_vec_len, data_len)
+        }
+        98 => wire__crate__matrix__receipts__set_room_marked_unread_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__matrix__room_settings__set_room_name_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__matrix__directory__set_room_published_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__matrix__tags__set_room_tag_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__matrix__room_settings__set_room_topic_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        103 => wire__crate__matrix__typing__set_typing_impl(port, ptr, rust_vec_len, data_len),
+        104 => wire__crate__matrix__moderation__set_user_power_level_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__matrix__sync_service__start_sync_service_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => wire__crate__matrix__timelines__subscribe_to_event_timeline_updates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        108 => wire__crate__matrix__knocking__subscribe_to_knock_requests_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        109 => wire__crate__matrix__timelines__subscribe_to_pagination_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__matrix__presence__subscribe_to_presence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__matrix__receipts__subscribe_to_read_receipts_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__matrix__room_list__subscribe_to_room_list_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__matrix__members__subscribe_to_room_members_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__crate__matrix__timelines__subscribe_to_timeline_updates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__matrix__typing__subscribe_to_typing_users_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => {
+            wire__crate__api__init__test_server_connectivity_impl(port, ptr, rust_vec_len, data_len)
+        }
+        117 => wire__crate__matrix__moderation__unban_user_impl(port, ptr, rust_vec_len, data_len),
+        118 => wire__crate__matrix__ignored_users__unignore_user_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => wire__crate__matrix__presence__unsubscribe_from_presence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        120 => wire__crate__matrix__timelines__unsubscribe_from_timeline_updates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => {
+            wire__crate__matrix__room_settings__upgrade_room_impl(port, ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Certificate> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Certificate> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Certificate>> for Certificate {
+    fn into_into_dart(self) -> FrbWrapper<Certificate> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<FieldsFormatterForFiles> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<FieldsFormatterForFiles>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<FieldsFormatterForFiles>>
+    for FieldsFormatterForFiles
+{
+    fn into_into_dart(self) -> FrbWrapper<FieldsFormatterForFiles> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::client::ClientConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.session_path.into_into_dart().into_dart(),
+            self.homeserver_url.into_into_dart().into_dart(),
+            self.root_certificates.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::client::ClientConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::client::ClientConfig>
+    for crate::matrix::client::ClientConfig
+{
+    fn into_into_dart(self) -> crate::matrix::client::ClientConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::platform::ClientError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::platform::ClientError::Generic { msg, details } => [
+                0.into_dart(),
+                msg.into_into_dart().into_dart(),
+                details.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::platform::ClientError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::ClientError>
+    for crate::api::platform::ClientError
+{
+    fn into_into_dart(self) -> crate::api::platform::ClientError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::CreateRoomParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.alias.into_into_dart().into_dart(),
+            self.visibility.into_into_dart().into_dart(),
+            self.is_encrypted.into_into_dart().into_dart(),
+            self.history_visibility.into_into_dart().into_dart(),
+            self.guest_access.into_into_dart().into_dart(),
+            self.power_level_overrides.into_into_dart().into_dart(),
+            self.room_version.into_into_dart().into_dart(),
+            self.space_parent_id.into_into_dart().into_dart(),
+            self.invite.into_into_dart().into_dart(),
+            self.is_direct.into_into_dart().into_dart(),
+            self.is_space.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::CreateRoomParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::CreateRoomParams>
+    for crate::matrix::rooms::CreateRoomParams
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::CreateRoomParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::EventPowerLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_type.into_into_dart().into_dart(),
+            self.power_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::EventPowerLevel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::EventPowerLevel>
+    for crate::matrix::rooms::EventPowerLevel
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::EventPowerLevel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::invites::InviteDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.room_name.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.member_count.into_into_dart().into_dart(),
+            self.is_dm.into_into_dart().into_dart(),
+            self.inviter.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::invites::InviteDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::invites::InviteDetails>
+    for crate::matrix::invites::InviteDetails
+{
+    fn into_into_dart(self) -> crate::matrix::invites::InviteDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::knocking::KnockRequestInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.user_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.is_seen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::knocking::KnockRequestInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::knocking::KnockRequestInfo>
+    for crate::matrix::knocking::KnockRequestInfo
+{
+    fn into_into_dart(self) -> crate::matrix::knocking::KnockRequestInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logger::LogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.tag.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logger::LogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::LogEntry>
+    for crate::api::logger::LogEntry
+{
+    fn into_into_dart(self) -> crate::api::logger::LogEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::tracing::LogLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Error => 0.into_dart(),
+            Self::Warn => 1.into_dart(),
+            Self::Info => 2.into_dart(),
+            Self::Debug => 3.into_dart(),
+            Self::Trace => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::tracing::LogLevel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::tracing::LogLevel>
+    for crate::api::tracing::LogLevel
+{
+    fn into_into_dart(self) -> crate::api::tracing::LogLevel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::members::MemberMembership {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Join => 0.into_dart(),
+            Self::Invite => 1.into_dart(),
+            Self::Knock => 2.into_dart(),
+            Self::Leave => 3.into_dart(),
+            Self::Ban => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::members::MemberMembership
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::members::MemberMembership>
+    for crate::matrix::members::MemberMembership
+{
+    fn into_into_dart(self) -> crate::matrix::members::MemberMembership {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::members::MemberRole {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Administrator => 0.into_dart(),
+            Self::Moderator => 1.into_dart(),
+            Self::User => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::members::MemberRole
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::members::MemberRole>
+    for crate::matrix::members::MemberRole
+{
+    fn into_into_dart(self) -> crate::matrix::members::MemberRole {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::Message {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.sender.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.message_type.into_into_dart().into_dart(),
+            self.read_receipts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::timelines::Message
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::Message>
+    for crate::matrix::timelines::Message
+{
+    fn into_into_dart(self) -> crate::matrix::timelines::Message {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::matrix::timelines::MessageType::Message => [0.into_dart()].into_dart(),
+            crate::matrix::timelines::MessageType::DateDivider => [1.into_dart()].into_dart(),
+            crate::matrix::timelines::MessageType::ReadMarker {
+                own_receipt_event_id,
+            } => [
+                2.into_dart(),
+                own_receipt_event_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::matrix::timelines::MessageType::TimelineStart => [3.into_dart()].into_dart(),
+            crate::matrix::timelines::MessageType::Tombstone {
+                replacement_room_id,
+            } => [
+                4.into_dart(),
+                replacement_room_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::matrix::timelines::MessageType::Predecessor {
+                predecessor_room_id,
+            } => [
+                5.into_dart(),
+                predecessor_room_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::timelines::MessageType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageType>
+    for crate::matrix::timelines::MessageType
+{
+    fn into_into_dart(self) -> crate::matrix::timelines::MessageType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message_update_type.into_into_dart().into_dart(),
+            self.messages.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+            self.length.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::timelines::MessageUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageUpdate>
+    for crate::matrix::timelines::MessageUpdate
+{
+    fn into_into_dart(self) -> crate::matrix::timelines::MessageUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageUpdateType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reset => 0.into_dart(),
+            Self::Truncate => 1.into_dart(),
+            Self::Remove => 2.into_dart(),
+            Self::Set => 3.into_dart(),
+            Self::Insert => 4.into_dart(),
+            Self::PopBack => 5.into_dart(),
+            Self::PopFront => 6.into_dart(),
+            Self::PushBack => 7.into_dart(),
+            Self::PushFront => 8.into_dart(),
+            Self::Clear => 9.into_dart(),
+            Self::Append => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::timelines::MessageUpdateType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageUpdateType>
+    for crate::matrix::timelines::MessageUpdateType
+{
+    fn into_into_dart(self) -> crate::matrix::timelines::MessageUpdateType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::PaginationStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Idle => 0.into_dart(),
+            Self::Paginating => 1.into_dart(),
+            Self::ReachedStart => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::timelines::PaginationStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::PaginationStatus>
+    for crate::matrix::timelines::PaginationStatus
+{
+    fn into_into_dart(self) -> crate::matrix::timelines::PaginationStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::moderation::PowerLevelAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::matrix::moderation::PowerLevelAction::Ban => [0.into_dart()].into_dart(),
+            crate::matrix::moderation::PowerLevelAction::Invite => [1.into_dart()].into_dart(),
+            crate::matrix::moderation::PowerLevelAction::Kick => [2.into_dart()].into_dart(),
+            crate::matrix::moderation::PowerLevelAction::Redact => [3.into_dart()].into_dart(),
+            crate::matrix::moderation::PowerLevelAction::EventsDefault => {
+                [4.into_dart()].into_dart()
+            }
+            crate::matrix::moderation::PowerLevelAction::StateDefault => {
+                [5.into_dart()].into_dart()
+            }
+            crate::matrix::moderation::PowerLevelAction::UsersDefault => {
+                [6.into_dart()].into_dart()
+            }
+            crate::matrix::moderation::PowerLevelAction::Event { event_type } => {
+                [7.into_dart(), event_type.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::moderation::PowerLevelAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::moderation::PowerLevelAction>
+    for crate::matrix::moderation::PowerLevelAction
+{
+    fn into_into_dart(self) -> crate::matrix::moderation::PowerLevelAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::PowerLevelOverrides {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ban.into_into_dart().into_dart(),
+            self.invite.into_into_dart().into_dart(),
+            self.kick.into_into_dart().into_dart(),
+            self.redact.into_into_dart().into_dart(),
+            self.events_default.into_into_dart().into_dart(),
+            self.state_default.into_into_dart().into_dart(),
+            self.users_default.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+            self.users.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::PowerLevelOverrides
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::PowerLevelOverrides>
+    for crate::matrix::rooms::PowerLevelOverrides
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::PowerLevelOverrides {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::presence::PresenceKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Online => 0.into_dart(),
+            Self::Unavailable => 1.into_dart(),
+            Self::Offline => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::presence::PresenceKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::presence::PresenceKind>
+    for crate::matrix::presence::PresenceKind
+{
+    fn into_into_dart(self) -> crate::matrix::presence::PresenceKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::directory::PublicRoom {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.canonical_alias.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.num_joined_members.into_into_dart().into_dart(),
+            self.world_readable.into_into_dart().into_dart(),
+            self.guest_can_join.into_into_dart().into_dart(),
+            self.is_space.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::directory::PublicRoom
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::directory::PublicRoom>
+    for crate::matrix::directory::PublicRoom
+{
+    fn into_into_dart(self) -> crate::matrix::directory::PublicRoom {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::directory::PublicRoomsPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rooms.into_into_dart().into_dart(),
+            self.next_batch.into_into_dart().into_dart(),
+            self.prev_batch.into_into_dart().into_dart(),
+            self.total_room_count_estimate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::directory::PublicRoomsPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::directory::PublicRoomsPage>
+    for crate::matrix::directory::PublicRoomsPage
+{
+    fn into_into_dart(self) -> crate::matrix::directory::PublicRoomsPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::receipts::ReadReceipt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user_id.into_into_dart().into_dart(),
+            self.event_id.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::receipts::ReadReceipt
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::receipts::ReadReceipt>
+    for crate::matrix::receipts::ReadReceipt
+{
+    fn into_into_dart(self) -> crate::matrix::receipts::ReadReceipt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::receipts::ReceiptKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Public => 0.into_dart(),
+            Self::Private => 1.into_dart(),
+            Self::FullyRead => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::receipts::ReceiptKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::receipts::ReceiptKind>
+    for crate::matrix::receipts::ReceiptKind
+{
+    fn into_into_dart(self) -> crate::matrix::receipts::ReceiptKind {
+        self
     }
 }
-
-impl SseDecode
-    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::directory::RoomAliases {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.canonical_alias.into_into_dart().into_dart(),
+            self.alt_aliases.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::directory::RoomAliases
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::directory::RoomAliases>
+    for crate::matrix::directory::RoomAliases
+{
+    fn into_into_dart(self) -> crate::matrix::directory::RoomAliases {
+        self
     }
 }
-
-impl SseDecode
-    for StreamSink<
-        crate::matrix::timelines::MessageUpdate,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::RoomHistoryVisibility {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Invited => 0.into_dart(),
+            Self::Joined => 1.into_dart(),
+            Self::Shared => 2.into_dart(),
+            Self::WorldReadable => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::RoomHistoryVisibility
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::RoomHistoryVisibility>
+    for crate::matrix::rooms::RoomHistoryVisibility
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::RoomHistoryVisibility {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::room_settings::RoomJoinRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::matrix::room_settings::RoomJoinRule::Public => [0.into_dart()].into_dart(),
+            crate::matrix::room_settings::RoomJoinRule::Invite => [1.into_dart()].into_dart(),
+            crate::matrix::room_settings::RoomJoinRule::Knock => [2.into_dart()].into_dart(),
+            crate::matrix::room_settings::RoomJoinRule::Restricted { allowed_room_ids } => {
+                [3.into_dart(), allowed_room_ids.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_settings::RoomJoinRule::KnockRestricted { allowed_room_ids } => {
+                [4.into_dart(), allowed_room_ids.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::room_settings::RoomJoinRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::room_settings::RoomJoinRule>
+    for crate::matrix::room_settings::RoomJoinRule
+{
+    fn into_into_dart(self) -> crate::matrix::room_settings::RoomJoinRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::room_list::RoomListFilterCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Group => 0.into_dart(),
+            Self::People => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::room_list::RoomListFilterCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::room_list::RoomListFilterCategory>
+    for crate::matrix::room_list::RoomListFilterCategory
+{
+    fn into_into_dart(self) -> crate::matrix::room_list::RoomListFilterCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::room_list::RoomListFilterKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::matrix::room_list::RoomListFilterKind::All { filters } => {
+                [0.into_dart(), filters.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::Any { filters } => {
+                [1.into_dart(), filters.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::NonSpace => [2.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::NonLeft => [3.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::Joined => [4.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::Unread => [5.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::Favourite => [6.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::LowPriority => {
+                [7.into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::NonLowPriority => {
+                [8.into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::Invite => [9.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::Category { expect } => {
+                [10.into_dart(), expect.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::None => [11.into_dart()].into_dart(),
+            crate::matrix::room_list::RoomListFilterKind::NormalizedMatchRoomName { pattern } => {
+                [12.into_dart(), pattern.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::FuzzyMatchRoomName { pattern } => {
+                [13.into_dart(), pattern.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::DeduplicateVersions => {
+                [14.into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::Tag { name } => {
+                [15.into_dart(), name.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::room_list::RoomListFilterKind::Space { space_id } => {
+                [16.into_dart(), space_id.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::room_list::RoomListFilterKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::room_list::RoomListFilterKind>
+    for crate::matrix::room_list::RoomListFilterKind
+{
+    fn into_into_dart(self) -> crate::matrix::room_list::RoomListFilterKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::room_list::RoomListUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.update_type.into_into_dart().into_dart(),
+            self.rooms.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+            self.length.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::room_list::RoomListUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::room_list::RoomListUpdate>
+    for crate::matrix::room_list::RoomListUpdate
+{
+    fn into_into_dart(self) -> crate::matrix::room_list::RoomListUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::room_list::RoomListUpdateType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reset => 0.into_dart(),
+            Self::Truncate => 1.into_dart(),
+            Self::Remove => 2.into_dart(),
+            Self::Set => 3.into_dart(),
+            Self::Insert => 4.into_dart(),
+            Self::PopBack => 5.into_dart(),
+            Self::PopFront => 6.into_dart(),
+            Self::PushBack => 7.into_dart(),
+            Self::PushFront => 8.into_dart(),
+            Self::Clear => 9.into_dart(),
+            Self::Append => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::room_list::RoomListUpdateType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::room_list::RoomListUpdateType>
+    for crate::matrix::room_list::RoomListUpdateType
+{
+    fn into_into_dart(self) -> crate::matrix::room_list::RoomListUpdateType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::members::RoomMemberInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.membership.into_into_dart().into_dart(),
+            self.power_level.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::members::RoomMemberInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::members::RoomMemberInfo>
+    for crate::matrix::members::RoomMemberInfo
+{
+    fn into_into_dart(self) -> crate::matrix::members::RoomMemberInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::members::RoomMembersPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.members.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::members::RoomMembersPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::members::RoomMembersPage>
+    for crate::matrix::members::RoomMembersPage
+{
+    fn into_into_dart(self) -> crate::matrix::members::RoomMembersPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::members::RoomMembersUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::matrix::members::RoomMembersUpdate::Reset { members } => {
+                [0.into_dart(), members.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::members::RoomMembersUpdate::Upsert { member } => {
+                [1.into_dart(), member.into_into_dart().into_dart()].into_dart()
+            }
+            crate::matrix::members::RoomMembersUpdate::Remove { user_id } => {
+                [2.into_dart(), user_id.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::members::RoomMembersUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::members::RoomMembersUpdate>
+    for crate::matrix::members::RoomMembersUpdate
+{
+    fn into_into_dart(self) -> crate::matrix::members::RoomMembersUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::moderation::RoomPermissions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.own_power_level.into_into_dart().into_dart(),
+            self.can_send_message.into_into_dart().into_dart(),
+            self.can_redact_own.into_into_dart().into_dart(),
+            self.can_redact_others.into_into_dart().into_dart(),
+            self.can_invite.into_into_dart().into_dart(),
+            self.can_kick.into_into_dart().into_dart(),
+            self.can_ban.into_into_dart().into_dart(),
+            self.can_change_settings.into_into_dart().into_dart(),
+            self.can_change_power_levels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::moderation::RoomPermissions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::moderation::RoomPermissions>
+    for crate::matrix::moderation::RoomPermissions
+{
+    fn into_into_dart(self) -> crate::matrix::moderation::RoomPermissions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::moderation::RoomPowerLevelsInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ban.into_into_dart().into_dart(),
+            self.invite.into_into_dart().into_dart(),
+            self.kick.into_into_dart().into_dart(),
+            self.redact.into_into_dart().into_dart(),
+            self.events_default.into_into_dart().into_dart(),
+            self.state_default.into_into_dart().into_dart(),
+            self.users_default.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+            self.users.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::moderation::RoomPowerLevelsInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::moderation::RoomPowerLevelsInfo>
+    for crate::matrix::moderation::RoomPowerLevelsInfo
+{
+    fn into_into_dart(self) -> crate::matrix::moderation::RoomPowerLevelsInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::joining::RoomPreviewInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.canonical_alias.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.num_joined_members.into_into_dart().into_dart(),
+            self.is_space.into_into_dart().into_dart(),
+            self.is_world_readable.into_into_dart().into_dart(),
+            self.is_direct.into_into_dart().into_dart(),
+            self.membership.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::joining::RoomPreviewInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::joining::RoomPreviewInfo>
+    for crate::matrix::joining::RoomPreviewInfo
+{
+    fn into_into_dart(self) -> crate::matrix::joining::RoomPreviewInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::RoomSuccessor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::RoomSuccessor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::RoomSuccessor>
+    for crate::matrix::rooms::RoomSuccessor
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::RoomSuccessor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::tags::RoomTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode
-    for StreamSink<crate::matrix::rooms::RoomUpdate, flutter_rust_bridge::for_generated::SseCodec>
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::matrix::tags::RoomTag {}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::tags::RoomTag>
+    for crate::matrix::tags::RoomTag
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn into_into_dart(self) -> crate::matrix::tags::RoomTag {
+        self
     }
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::RoomUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.raw_name.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.is_dm.into_into_dart().into_dart(),
+            self.update_type.into_into_dart().into_dart(),
+            self.unread_notifications.into_into_dart().into_dart(),
+            self.unread_highlight.into_into_dart().into_dart(),
+            self.unread_mentions.into_into_dart().into_dart(),
+            self.unread_messages.into_into_dart().into_dart(),
+            self.is_marked_unread.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.canonical_alias.into_into_dart().into_dart(),
+            self.is_encrypted.into_into_dart().into_dart(),
+            self.history_visibility.into_into_dart().into_dart(),
+            self.join_rule.into_into_dart().into_dart(),
+            self.successor.into_into_dart().into_dart(),
+            self.predecessor_room_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::RoomUpdate
+{
 }
-
-impl SseDecode for crate::matrix::client::ClientConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sessionPath = <String>::sse_decode(deserializer);
-        let mut var_homeserverUrl = <String>::sse_decode(deserializer);
-        let mut var_rootCertificates = <Option<Vec<Certificate>>>::sse_decode(deserializer);
-        let mut var_proxy = <Option<String>>::sse_decode(deserializer);
-        return crate::matrix::client::ClientConfig {
-            session_path: var_sessionPath,
-            homeserver_url: var_homeserverUrl,
-            root_certificates: var_rootCertificates,
-            proxy: var_proxy,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::RoomUpdate>
+    for crate::matrix::rooms::RoomUpdate
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::RoomUpdate {
+        self
     }
 }
-
-impl SseDecode for crate::api::platform::ClientError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_msg = <String>::sse_decode(deserializer);
-                let mut var_details = <Option<String>>::sse_decode(deserializer);
-                return crate::api::platform::ClientError::Generic {
-                    msg: var_msg,
-                    details: var_details,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::RoomVisibility {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Public => 0.into_dart(),
+            Self::Private => 1.into_dart(),
+            Self::Knock => 2.into_dart(),
+            _ => unreachable!(),
         }
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::RoomVisibility
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::RoomVisibility>
+    for crate::matrix::rooms::RoomVisibility
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::RoomVisibility {
+        self
     }
 }
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::spaces::SpaceHierarchyPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rooms.into_into_dart().into_dart(),
+            self.next_batch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<Certificate> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Certificate>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::spaces::SpaceHierarchyPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::spaces::SpaceHierarchyPage>
+    for crate::matrix::spaces::SpaceHierarchyPage
+{
+    fn into_into_dart(self) -> crate::matrix::spaces::SpaceHierarchyPage {
+        self
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::spaces::SpaceHierarchyRoom {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.room_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+            self.canonical_alias.into_into_dart().into_dart(),
+            self.num_joined_members.into_into_dart().into_dart(),
+            self.is_space.into_into_dart().into_dart(),
+            self.suggested.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+            self.membership.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::matrix::timelines::Message> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::matrix::timelines::Message>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::spaces::SpaceHierarchyRoom
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::spaces::SpaceHierarchyRoom>
+    for crate::matrix::spaces::SpaceHierarchyRoom
+{
+    fn into_into_dart(self) -> crate::matrix::spaces::SpaceHierarchyRoom {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::platform::TraceLogPacks {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::EventCache => 0.into_dart(),
+            Self::SendQueue => 1.into_dart(),
+            Self::Timeline => 2.into_dart(),
+            Self::NotificationClient => 3.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::matrix::rooms::RoomUpdate> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::matrix::rooms::RoomUpdate>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::platform::TraceLogPacks
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TraceLogPacks>
+    for crate::api::platform::TraceLogPacks
+{
+    fn into_into_dart(self) -> crate::api::platform::TraceLogPacks {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::platform::TraceLogPacks> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::platform::TraceLogPacks>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::platform::TracingConfiguration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.log_level.into_into_dart().into_dart(),
+            self.trace_log_packs.into_into_dart().into_dart(),
+            self.extra_targets.into_into_dart().into_dart(),
+            self.write_to_stdout_or_system.into_into_dart().into_dart(),
+            self.write_to_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::matrix::user_serach::User> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::matrix::user_serach::User>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::platform::TracingConfiguration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TracingConfiguration>
+    for crate::api::platform::TracingConfiguration
+{
+    fn into_into_dart(self) -> crate::api::platform::TracingConfiguration {
+        self
     }
 }
-
-impl SseDecode for crate::api::logger::LogEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_level = <String>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_timestamp = <i64>::sse_decode(deserializer);
-        let mut var_tag = <String>::sse_decode(deserializer);
-        return crate::api::logger::LogEntry {
-            level: var_level,
-            message: var_message,
-            timestamp: var_timestamp,
-            tag: var_tag,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::platform::TracingFileConfiguration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.file_prefix.into_into_dart().into_dart(),
+            self.file_suffix.into_into_dart().into_dart(),
+            self.max_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::tracing::LogLevel {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::tracing::LogLevel::Error,
-            1 => crate::api::tracing::LogLevel::Warn,
-            2 => crate::api::tracing::LogLevel::Info,
-            3 => crate::api::tracing::LogLevel::Debug,
-            4 => crate::api::tracing::LogLevel::Trace,
-            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::platform::TracingFileConfiguration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TracingFileConfiguration>
+    for crate::api::platform::TracingFileConfiguration
+{
+    fn into_into_dart(self) -> crate::api::platform::TracingFileConfiguration {
+        self
     }
 }
-
-impl SseDecode for crate::matrix::timelines::Message {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_eventId = <String>::sse_decode(deserializer);
-        let mut var_sender = <String>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_timestamp = <u64>::sse_decode(deserializer);
-        let mut var_messageType = <crate::matrix::timelines::MessageType>::sse_decode(deserializer);
-        return crate::matrix::timelines::Message {
-            event_id: var_eventId,
-            sender: var_sender,
-            content: var_content,
-            timestamp: var_timestamp,
-            message_type: var_messageType,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::UpdateType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Joined => 0.into_dart(),
+            Self::Left => 1.into_dart(),
+            Self::Invited => 2.into_dart(),
+            Self::Knocked => 3.into_dart(),
+            Self::Banned => 4.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::matrix::timelines::MessageType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::matrix::timelines::MessageType::Message,
-            1 => crate::matrix::timelines::MessageType::DateDivider,
-            2 => crate::matrix::timelines::MessageType::ReadMarker,
-            3 => crate::matrix::timelines::MessageType::TimelineStart,
-            _ => unreachable!("Invalid variant for MessageType: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::UpdateType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::UpdateType>
+    for crate::matrix::rooms::UpdateType
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::UpdateType {
+        self
     }
 }
-
-impl SseDecode for crate::matrix::timelines::MessageUpdate {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_messageUpdateType =
-            <crate::matrix::timelines::MessageUpdateType>::sse_decode(deserializer);
-        let mut var_messages =
-            <Option<Vec<crate::matrix::timelines::Message>>>::sse_decode(deserializer);
-        let mut var_index = <Option<usize>>::sse_decode(deserializer);
-        let mut var_length = <Option<usize>>::sse_decode(deserializer);
-        return crate::matrix::timelines::MessageUpdate {
-            message_update_type: var_messageUpdateType,
-            messages: var_messages,
-            index: var_index,
-            length: var_length,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::user_serach::User {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.avatar_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::matrix::timelines::MessageUpdateType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::matrix::timelines::MessageUpdateType::Reset,
-            1 => crate::matrix::timelines::MessageUpdateType::Truncate,
-            2 => crate::matrix::timelines::MessageUpdateType::Remove,
-            3 => crate::matrix::timelines::MessageUpdateType::Set,
-            4 => crate::matrix::timelines::MessageUpdateType::Insert,
-            5 => crate::matrix::timelines::MessageUpdateType::PopBack,
-            6 => crate::matrix::timelines::MessageUpdateType::PopFront,
-            7 => crate::matrix::timelines::MessageUpdateType::PushBack,
-            8 => crate::matrix::timelines::MessageUpdateType::PushFront,
-            9 => crate::matrix::timelines::MessageUpdateType::Clear,
-            10 => crate::matrix::timelines::MessageUpdateType::Append,
-            _ => unreachable!("Invalid variant for MessageUpdateType: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::user_serach::User
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::user_serach::User>
+    for crate::matrix::user_serach::User
+{
+    fn into_into_dart(self) -> crate::matrix::user_serach::User {
+        self
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::UserPowerLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user_id.into_into_dart().into_dart(),
+            self.power_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<bool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::rooms::UserPowerLevel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::UserPowerLevel>
+    for crate::matrix::rooms::UserPowerLevel
+{
+    fn into_into_dart(self) -> crate::matrix::rooms::UserPowerLevel {
+        self
     }
 }
-
-impl SseDecode for Option<crate::matrix::timelines::Message> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::matrix::timelines::Message>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::presence::UserPresence {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.user_id.into_into_dart().into_dart(),
+            self.presence.into_into_dart().into_dart(),
+            self.last_active.into_into_dart().into_dart(),
+            self.currently_active.into_into_dart().into_dart(),
+            self.status_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<crate::api::platform::TracingFileConfiguration> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::platform::TracingFileConfiguration>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::presence::UserPresence
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::presence::UserPresence>
+    for crate::matrix::presence::UserPresence
+{
+    fn into_into_dart(self) -> crate::matrix::presence::UserPresence {
+        self
     }
 }
-
-impl SseDecode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::matrix::user_serach::UserSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.users.into_into_dart().into_dart(),
+            self.limited.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::matrix::user_serach::UserSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::matrix::user_serach::UserSearchResult>
+    for crate::matrix::user_serach::UserSearchResult
+{
+    fn into_into_dart(self) -> crate::matrix::user_serach::UserSearchResult {
+        self
     }
 }
 
-impl SseDecode for Option<usize> {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for Option<Vec<Certificate>> {
+impl SseEncode for Certificate {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<Certificate>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for Option<Vec<crate::matrix::timelines::Message>> {
+impl SseEncode for FieldsFormatterForFiles {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<crate::matrix::timelines::Message>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::matrix::rooms::RoomUpdate {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_roomId = <String>::sse_decode(deserializer);
-        let mut var_rawName = <Option<String>>::sse_decode(deserializer);
-        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
-        let mut var_isDm = <Option<bool>>::sse_decode(deserializer);
-        let mut var_updateType = <crate::matrix::rooms::UpdateType>::sse_decode(deserializer);
-        let mut var_unreadNotifications = <Option<u64>>::sse_decode(deserializer);
-        let mut var_unreadHighlight = <Option<u64>>::sse_decode(deserializer);
-        let mut var_unreadMentions = <Option<u64>>::sse_decode(deserializer);
-        let mut var_unreadMessages = <Option<u64>>::sse_decode(deserializer);
-        let mut var_message = <Option<crate::matrix::timelines::Message>>::sse_decode(deserializer);
-        return crate::matrix::rooms::RoomUpdate {
-            room_id: var_roomId,
-            raw_name: var_rawName,
-            display_name: var_displayName,
-            is_dm: var_isDm,
-            update_type: var_updateType,
-            unread_notifications: var_unreadNotifications,
-            unread_highlight: var_unreadHighlight,
-            unread_mentions: var_unreadMentions,
-            unread_messages: var_unreadMessages,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::platform::TraceLogPacks {
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::platform::TraceLogPacks::EventCache,
-            1 => crate::api::platform::TraceLogPacks::SendQueue,
-            2 => crate::api::platform::TraceLogPacks::Timeline,
-            3 => crate::api::platform::TraceLogPacks::NotificationClient,
-            _ => unreachable!("Invalid variant for TraceLogPacks: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for crate::api::platform::TracingConfiguration {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::knocking::KnockRequestInfo>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_logLevel = <crate::api::tracing::LogLevel>::sse_decode(deserializer);
-        let mut var_traceLogPacks =
-            <Vec<crate::api::platform::TraceLogPacks>>::sse_decode(deserializer);
-        let mut var_extraTargets = <Vec<String>>::sse_decode(deserializer);
-        let mut var_writeToStdoutOrSystem = <bool>::sse_decode(deserializer);
-        let mut var_writeToFiles =
-            <Option<crate::api::platform::TracingFileConfiguration>>::sse_decode(deserializer);
-        return crate::api::platform::TracingConfiguration {
-            log_level: var_logLevel,
-            trace_log_packs: var_traceLogPacks,
-            extra_targets: var_extraTargets,
-            write_to_stdout_or_system: var_writeToStdoutOrSystem,
-            write_to_files: var_writeToFiles,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::platform::TracingFileConfiguration {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::timelines::MessageUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_filePrefix = <String>::sse_decode(deserializer);
-        let mut var_fileSuffix = <Option<String>>::sse_decode(deserializer);
-        let mut var_maxFiles = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::platform::TracingFileConfiguration {
-            path: var_path,
-            file_prefix: var_filePrefix,
-            file_suffix: var_fileSuffix,
-            max_files: var_maxFiles,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for u16 {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::receipts::ReadReceipt>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::room_list::RoomListUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::members::RoomMembersUpdate>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode
+    for StreamSink<
+        Vec<crate::matrix::user_serach::User>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for () {
+impl SseEncode
+    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
 }
 
-impl SseDecode for crate::matrix::rooms::UpdateType {
+impl SseEncode
+    for StreamSink<
+        crate::matrix::timelines::PaginationStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::matrix::rooms::UpdateType::Joined,
-            1 => crate::matrix::rooms::UpdateType::Left,
-            2 => crate::matrix::rooms::UpdateType::Invited,
-            3 => crate::matrix::rooms::UpdateType::Knocked,
-            4 => crate::matrix::rooms::UpdateType::Banned,
-            _ => unreachable!("Invalid variant for UpdateType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::matrix::user_serach::User {
+impl SseEncode
+    for StreamSink<
+        crate::matrix::presence::UserPresence,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_userId = <String>::sse_decode(deserializer);
-        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
-        let mut var_avatarUrl = <Option<String>>::sse_decode(deserializer);
-        return crate::matrix::user_serach::User {
-            user_id: var_userId,
-            display_name: var_displayName,
-            avatar_url: var_avatarUrl,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::matrix::user_serach::UserSearchResult {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_users = <Vec<crate::matrix::user_serach::User>>::sse_decode(deserializer);
-        let mut var_limited = <bool>::sse_decode(deserializer);
-        return crate::matrix::user_serach::UserSearchResult {
-            users: var_users,
-            limited: var_limited,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for usize {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__platform__FieldsFormatterForFiles_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__logger___log_impl(port, ptr, rust_vec_len, data_len),
-        3 => {
-            wire__crate__api__init__check_matrix_sdk_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        4 => wire__crate__matrix__client__configure_client_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__matrix__rooms__create_direct_room_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__matrix__rooms__create_group_room_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__logger__create_log_stream_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__matrix__rooms__get_all_rooms_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__init__get_matrix_config_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__matrix__timelines__get_older_messages_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__init__get_sync_operations_status_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__matrix__timelines__get_timeline_items_by_room_id_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__init__init_app_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__platform__init_platform_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__matrix__authentication__is_client_authenticated_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__matrix__rooms__join_room_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__matrix__rooms__leave_room_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__logger__log_debug_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__logger__log_error_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__tracing__log_event_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__logger__log_info_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__logger__log_warn_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__matrix__authentication__login_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__matrix__authentication__logout_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__matrix__authentication__register_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__platform__reload_tracing_file_writer_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        28 => {
-            wire__crate__matrix__user_serach__search_users_impl(port, ptr, rust_vec_len, data_len)
-        }
-        29 => wire__crate__matrix__rooms__send_message_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__matrix__sync_service__start_sync_service_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        31 => wire__crate__matrix__rooms__subscribe_to_all_room_updates_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        32 => wire__crate__matrix__timelines__subscribe_to_timeline_updates_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        33 => {
-            wire__crate__api__init__test_server_connectivity_impl(port, ptr, rust_vec_len, data_len)
-        }
-        _ => unreachable!(),
+impl SseEncode for crate::matrix::client::ClientConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.session_path, serializer);
+        <String>::sse_encode(self.homeserver_url, serializer);
+        <Option<Vec<Certificate>>>::sse_encode(self.root_certificates, serializer);
+        <Option<String>>::sse_encode(self.proxy, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for crate::api::platform::ClientError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::platform::ClientError::Generic { msg, details } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(msg, serializer);
+                <Option<String>>::sse_encode(details, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::matrix::rooms::CreateRoomParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <Option<String>>::sse_encode(self.alias, serializer);
+        <crate::matrix::rooms::RoomVisibility>::sse_encode(self.visibility, serializer);
+        <bool>::sse_encode(self.is_encrypted, serializer);
+        <Option<crate::matrix::rooms::RoomHistoryVisibility>>::sse_encode(
+            self.history_visibility,
+            serializer,
+        );
+        <Option<bool>>::sse_encode(self.guest_access, serializer);
+        <Option<crate::matrix::rooms::PowerLevelOverrides>>::sse_encode(
+            self.power_level_overrides,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.room_version, serializer);
+        <Option<String>>::sse_encode(self.space_parent_id, serializer);
+        <Vec<String>>::sse_encode(self.invite, serializer);
+        <bool>::sse_encode(self.is_direct, serializer);
+        <bool>::sse_encode(self.is_space, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Certificate> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::matrix::rooms::EventPowerLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_type, serializer);
+        <i64>::sse_encode(self.power_level, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Certificate> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Certificate>> for Certificate {
-    fn into_into_dart(self) -> FrbWrapper<Certificate> {
-        self.into()
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<FieldsFormatterForFiles> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<FieldsFormatterForFiles>
-{
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<FieldsFormatterForFiles>>
-    for FieldsFormatterForFiles
-{
-    fn into_into_dart(self) -> FrbWrapper<FieldsFormatterForFiles> {
-        self.into()
+impl SseEncode for i8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i8(self).unwrap();
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::client::ClientConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.session_path.into_into_dart().into_dart(),
-            self.homeserver_url.into_into_dart().into_dart(),
-            self.root_certificates.into_into_dart().into_dart(),
-            self.proxy.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::matrix::invites::InviteDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.room_id, serializer);
+        <Option<String>>::sse_encode(self.room_name, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <u64>::sse_encode(self.member_count, serializer);
+        <bool>::sse_encode(self.is_dm, serializer);
+        <Option<crate::matrix::user_serach::User>>::sse_encode(self.inviter, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::client::ClientConfig
-{
+
+impl SseEncode for crate::matrix::knocking::KnockRequestInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id, serializer);
+        <String>::sse_encode(self.user_id, serializer);
+        <Option<String>>::sse_encode(self.display_name, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <Option<String>>::sse_encode(self.reason, serializer);
+        <Option<u64>>::sse_encode(self.timestamp, serializer);
+        <bool>::sse_encode(self.is_seen, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::client::ClientConfig>
-    for crate::matrix::client::ClientConfig
-{
-    fn into_into_dart(self) -> crate::matrix::client::ClientConfig {
-        self
+
+impl SseEncode for Vec<Certificate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Certificate>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::platform::ClientError {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::platform::ClientError::Generic { msg, details } => [
-                0.into_dart(),
-                msg.into_into_dart().into_dart(),
-                details.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::platform::ClientError
-{
+
+impl SseEncode for Vec<crate::matrix::rooms::EventPowerLevel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::rooms::EventPowerLevel>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::ClientError>
-    for crate::api::platform::ClientError
-{
-    fn into_into_dart(self) -> crate::api::platform::ClientError {
-        self
+
+impl SseEncode for Vec<crate::matrix::invites::InviteDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::invites::InviteDetails>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::logger::LogEntry {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.level.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.tag.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::matrix::knocking::KnockRequestInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::knocking::KnockRequestInfo>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logger::LogEntry {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::LogEntry>
-    for crate::api::logger::LogEntry
-{
-    fn into_into_dart(self) -> crate::api::logger::LogEntry {
-        self
+
+impl SseEncode for Vec<crate::matrix::timelines::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::timelines::Message>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::tracing::LogLevel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Error => 0.into_dart(),
-            Self::Warn => 1.into_dart(),
-            Self::Info => 2.into_dart(),
-            Self::Debug => 3.into_dart(),
-            Self::Trace => 4.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::matrix::timelines::MessageUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::timelines::MessageUpdate>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::tracing::LogLevel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::tracing::LogLevel>
-    for crate::api::tracing::LogLevel
-{
-    fn into_into_dart(self) -> crate::api::tracing::LogLevel {
-        self
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::Message {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.event_id.into_into_dart().into_dart(),
-            self.sender.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.message_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::matrix::directory::PublicRoom> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::directory::PublicRoom>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::timelines::Message
-{
+
+impl SseEncode for Vec<crate::matrix::receipts::ReadReceipt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::receipts::ReadReceipt>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::Message>
-    for crate::matrix::timelines::Message
-{
-    fn into_into_dart(self) -> crate::matrix::timelines::Message {
-        self
+
+impl SseEncode for Vec<crate::matrix::room_list::RoomListFilterKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::room_list::RoomListFilterKind>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Message => 0.into_dart(),
-            Self::DateDivider => 1.into_dart(),
-            Self::ReadMarker => 2.into_dart(),
-            Self::TimelineStart => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::matrix::room_list::RoomListUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::room_list::RoomListUpdate>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::timelines::MessageType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageType>
-    for crate::matrix::timelines::MessageType
-{
-    fn into_into_dart(self) -> crate::matrix::timelines::MessageType {
-        self
+
+impl SseEncode for Vec<crate::matrix::members::RoomMemberInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::members::RoomMemberInfo>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageUpdate {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.message_update_type.into_into_dart().into_dart(),
-            self.messages.into_into_dart().into_dart(),
-            self.index.into_into_dart().into_dart(),
-            self.length.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::matrix::members::RoomMembersUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::members::RoomMembersUpdate>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::timelines::MessageUpdate
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageUpdate>
-    for crate::matrix::timelines::MessageUpdate
-{
-    fn into_into_dart(self) -> crate::matrix::timelines::MessageUpdate {
-        self
+
+impl SseEncode for Vec<crate::matrix::tags::RoomTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::tags::RoomTag>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::timelines::MessageUpdateType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Reset => 0.into_dart(),
-            Self::Truncate => 1.into_dart(),
-            Self::Remove => 2.into_dart(),
-            Self::Set => 3.into_dart(),
-            Self::Insert => 4.into_dart(),
-            Self::PopBack => 5.into_dart(),
-            Self::PopFront => 6.into_dart(),
-            Self::PushBack => 7.into_dart(),
-            Self::PushFront => 8.into_dart(),
-            Self::Clear => 9.into_dart(),
-            Self::Append => 10.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::matrix::rooms::RoomUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::rooms::RoomUpdate>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::timelines::MessageUpdateType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::timelines::MessageUpdateType>
-    for crate::matrix::timelines::MessageUpdateType
-{
-    fn into_into_dart(self) -> crate::matrix::timelines::MessageUpdateType {
-        self
+
+impl SseEncode for Vec<crate::matrix::spaces::SpaceHierarchyRoom> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::spaces::SpaceHierarchyRoom>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::RoomUpdate {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.room_id.into_into_dart().into_dart(),
-            self.raw_name.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.is_dm.into_into_dart().into_dart(),
-            self.update_type.into_into_dart().into_dart(),
-            self.unread_notifications.into_into_dart().into_dart(),
-            self.unread_highlight.into_into_dart().into_dart(),
-            self.unread_mentions.into_into_dart().into_dart(),
-            self.unread_messages.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::platform::TraceLogPacks> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::platform::TraceLogPacks>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::rooms::RoomUpdate
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::RoomUpdate>
-    for crate::matrix::rooms::RoomUpdate
-{
-    fn into_into_dart(self) -> crate::matrix::rooms::RoomUpdate {
-        self
+
+impl SseEncode for Vec<crate::matrix::user_serach::User> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::user_serach::User>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::platform::TraceLogPacks {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::EventCache => 0.into_dart(),
-            Self::SendQueue => 1.into_dart(),
-            Self::Timeline => 2.into_dart(),
-            Self::NotificationClient => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<crate::matrix::rooms::UserPowerLevel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::matrix::rooms::UserPowerLevel>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::platform::TraceLogPacks
-{
+
+impl SseEncode for crate::api::logger::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.tag, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TraceLogPacks>
-    for crate::api::platform::TraceLogPacks
-{
-    fn into_into_dart(self) -> crate::api::platform::TraceLogPacks {
-        self
+
+impl SseEncode for crate::api::tracing::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::tracing::LogLevel::Error => 0,
+                crate::api::tracing::LogLevel::Warn => 1,
+                crate::api::tracing::LogLevel::Info => 2,
+                crate::api::tracing::LogLevel::Debug => 3,
+                crate::api::tracing::LogLevel::Trace => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::platform::TracingConfiguration {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.log_level.into_into_dart().into_dart(),
-            self.trace_log_packs.into_into_dart().into_dart(),
-            self.extra_targets.into_into_dart().into_dart(),
-            self.write_to_stdout_or_system.into_into_dart().into_dart(),
-            self.write_to_files.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::matrix::members::MemberMembership {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::members::MemberMembership::Join => 0,
+                crate::matrix::members::MemberMembership::Invite => 1,
+                crate::matrix::members::MemberMembership::Knock => 2,
+                crate::matrix::members::MemberMembership::Leave => 3,
+                crate::matrix::members::MemberMembership::Ban => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::platform::TracingConfiguration
-{
+
+impl SseEncode for crate::matrix::members::MemberRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::members::MemberRole::Administrator => 0,
+                crate::matrix::members::MemberRole::Moderator => 1,
+                crate::matrix::members::MemberRole::User => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TracingConfiguration>
-    for crate::api::platform::TracingConfiguration
-{
-    fn into_into_dart(self) -> crate::api::platform::TracingConfiguration {
-        self
+
+impl SseEncode for crate::matrix::timelines::Message {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id, serializer);
+        <String>::sse_encode(self.sender, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <u64>::sse_encode(self.timestamp, serializer);
+        <crate::matrix::timelines::MessageType>::sse_encode(self.message_type, serializer);
+        <Vec<crate::matrix::receipts::ReadReceipt>>::sse_encode(self.read_receipts, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::platform::TracingFileConfiguration {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.path.into_into_dart().into_dart(),
-            self.file_prefix.into_into_dart().into_dart(),
-            self.file_suffix.into_into_dart().into_dart(),
-            self.max_files.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::matrix::timelines::MessageType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::matrix::timelines::MessageType::Message => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::matrix::timelines::MessageType::DateDivider => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::matrix::timelines::MessageType::ReadMarker {
+                own_receipt_event_id,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <Option<String>>::sse_encode(own_receipt_event_id, serializer);
+            }
+            crate::matrix::timelines::MessageType::TimelineStart => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::matrix::timelines::MessageType::Tombstone {
+                replacement_room_id,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(replacement_room_id, serializer);
+            }
+            crate::matrix::timelines::MessageType::Predecessor {
+                predecessor_room_id,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(predecessor_room_id, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::matrix::timelines::MessageUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::matrix::timelines::MessageUpdateType>::sse_encode(
+            self.message_update_type,
+            serializer,
+        );
+        <Option<Vec<crate::matrix::timelines::Message>>>::sse_encode(self.messages, serializer);
+        <Option<usize>>::sse_encode(self.index, serializer);
+        <Option<usize>>::sse_encode(self.length, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::platform::TracingFileConfiguration
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::platform::TracingFileConfiguration>
-    for crate::api::platform::TracingFileConfiguration
-{
-    fn into_into_dart(self) -> crate::api::platform::TracingFileConfiguration {
-        self
+
+impl SseEncode for crate::matrix::timelines::MessageUpdateType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::timelines::MessageUpdateType::Reset => 0,
+                crate::matrix::timelines::MessageUpdateType::Truncate => 1,
+                crate::matrix::timelines::MessageUpdateType::Remove => 2,
+                crate::matrix::timelines::MessageUpdateType::Set => 3,
+                crate::matrix::timelines::MessageUpdateType::Insert => 4,
+                crate::matrix::timelines::MessageUpdateType::PopBack => 5,
+                crate::matrix::timelines::MessageUpdateType::PopFront => 6,
+                crate::matrix::timelines::MessageUpdateType::PushBack => 7,
+                crate::matrix::timelines::MessageUpdateType::PushFront => 8,
+                crate::matrix::timelines::MessageUpdateType::Clear => 9,
+                crate::matrix::timelines::MessageUpdateType::Append => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::rooms::UpdateType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Joined => 0.into_dart(),
-            Self::Left => 1.into_dart(),
-            Self::Invited => 2.into_dart(),
-            Self::Knocked => 3.into_dart(),
-            Self::Banned => 4.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::rooms::UpdateType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::rooms::UpdateType>
-    for crate::matrix::rooms::UpdateType
-{
-    fn into_into_dart(self) -> crate::matrix::rooms::UpdateType {
-        self
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::user_serach::User {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.user_id.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.avatar_url.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::user_serach::User
-{
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::user_serach::User>
-    for crate::matrix::user_serach::User
-{
-    fn into_into_dart(self) -> crate::matrix::user_serach::User {
-        self
+
+impl SseEncode for Option<i8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i8>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::matrix::user_serach::UserSearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.users.into_into_dart().into_dart(),
-            self.limited.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::matrix::timelines::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::timelines::Message>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::matrix::user_serach::UserSearchResult
-{
+
+impl SseEncode for Option<crate::matrix::rooms::PowerLevelOverrides> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::rooms::PowerLevelOverrides>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::matrix::user_serach::UserSearchResult>
-    for crate::matrix::user_serach::UserSearchResult
-{
-    fn into_into_dart(self) -> crate::matrix::user_serach::UserSearchResult {
-        self
+
+impl SseEncode for Option<crate::matrix::receipts::ReadReceipt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::receipts::ReadReceipt>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Option<crate::matrix::rooms::RoomHistoryVisibility> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::rooms::RoomHistoryVisibility>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Certificate {
+impl SseEncode for Option<crate::matrix::room_settings::RoomJoinRule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::room_settings::RoomJoinRule>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for FieldsFormatterForFiles {
+impl SseEncode for Option<crate::matrix::rooms::RoomSuccessor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::rooms::RoomSuccessor>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Certificate>>
-{
+impl SseEncode for Option<crate::api::platform::TracingFileConfiguration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::platform::TracingFileConfiguration>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<FieldsFormatterForFiles>,
-    >
-{
+impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::logger::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::matrix::timelines::MessageUpdate,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::matrix::rooms::RoomUpdate, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for Option<crate::matrix::rooms::UpdateType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::rooms::UpdateType>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::matrix::user_serach::User> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::matrix::user_serach::User>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::matrix::client::ClientConfig {
+impl SseEncode for Option<Vec<Certificate>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.session_path, serializer);
-        <String>::sse_encode(self.homeserver_url, serializer);
-        <Option<Vec<Certificate>>>::sse_encode(self.root_certificates, serializer);
-        <Option<String>>::sse_encode(self.proxy, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<Certificate>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::platform::ClientError {
+impl SseEncode for Option<Vec<crate::matrix::timelines::Message>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::platform::ClientError::Generic { msg, details } => {
-                <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(msg, serializer);
-                <Option<String>>::sse_encode(details, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::matrix::timelines::Message>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<Vec<crate::matrix::rooms::RoomUpdate>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::matrix::rooms::RoomUpdate>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::matrix::timelines::PaginationStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::timelines::PaginationStatus::Idle => 0,
+                crate::matrix::timelines::PaginationStatus::Paginating => 1,
+                crate::matrix::timelines::PaginationStatus::ReachedStart => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<Certificate> {
+impl SseEncode for crate::matrix::moderation::PowerLevelAction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Certificate>::sse_encode(item, serializer);
+        match self {
+            crate::matrix::moderation::PowerLevelAction::Ban => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::Invite => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::Kick => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::Redact => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::EventsDefault => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::StateDefault => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::UsersDefault => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::matrix::moderation::PowerLevelAction::Event { event_type } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(event_type, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::matrix::rooms::PowerLevelOverrides {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <Option<i64>>::sse_encode(self.ban, serializer);
+        <Option<i64>>::sse_encode(self.invite, serializer);
+        <Option<i64>>::sse_encode(self.kick, serializer);
+        <Option<i64>>::sse_encode(self.redact, serializer);
+        <Option<i64>>::sse_encode(self.events_default, serializer);
+        <Option<i64>>::sse_encode(self.state_default, serializer);
+        <Option<i64>>::sse_encode(self.users_default, serializer);
+        <Vec<crate::matrix::rooms::EventPowerLevel>>::sse_encode(self.events, serializer);
+        <Vec<crate::matrix::rooms::UserPowerLevel>>::sse_encode(self.users, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::matrix::timelines::Message> {
+impl SseEncode for crate::matrix::presence::PresenceKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::matrix::timelines::Message>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::presence::PresenceKind::Online => 0,
+                crate::matrix::presence::PresenceKind::Unavailable => 1,
+                crate::matrix::presence::PresenceKind::Offline => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::matrix::directory::PublicRoom {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.room_id, serializer);
+        <Option<String>>::sse_encode(self.canonical_alias, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <u64>::sse_encode(self.num_joined_members, serializer);
+        <bool>::sse_encode(self.world_readable, serializer);
+        <bool>::sse_encode(self.guest_can_join, serializer);
+        <bool>::sse_encode(self.is_space, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::matrix::rooms::RoomUpdate> {
+impl SseEncode for crate::matrix::directory::PublicRoomsPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::matrix::rooms::RoomUpdate>::sse_encode(item, serializer);
-        }
+        <Vec<crate::matrix::directory::PublicRoom>>::sse_encode(self.rooms, serializer);
+        <Option<String>>::sse_encode(self.next_batch, serializer);
+        <Option<String>>::sse_encode(self.prev_batch, serializer);
+        <Option<u64>>::sse_encode(self.total_room_count_estimate, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::platform::TraceLogPacks> {
+impl SseEncode for crate::matrix::receipts::ReadReceipt {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::platform::TraceLogPacks>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.user_id, serializer);
+        <String>::sse_encode(self.event_id, serializer);
+        <Option<u64>>::sse_encode(self.timestamp, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::matrix::user_serach::User> {
+impl SseEncode for crate::matrix::receipts::ReceiptKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::matrix::user_serach::User>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::receipts::ReceiptKind::Public => 0,
+                crate::matrix::receipts::ReceiptKind::Private => 1,
+                crate::matrix::receipts::ReceiptKind::FullyRead => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::logger::LogEntry {
+impl SseEncode for crate::matrix::directory::RoomAliases {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.level, serializer);
-        <String>::sse_encode(self.message, serializer);
-        <i64>::sse_encode(self.timestamp, serializer);
-        <String>::sse_encode(self.tag, serializer);
+        <Option<String>>::sse_encode(self.canonical_alias, serializer);
+        <Vec<String>>::sse_encode(self.alt_aliases, serializer);
     }
 }
 
-impl SseEncode for crate::api::tracing::LogLevel {
+impl SseEncode for crate::matrix::rooms::RoomHistoryVisibility {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::tracing::LogLevel::Error => 0,
-                crate::api::tracing::LogLevel::Warn => 1,
-                crate::api::tracing::LogLevel::Info => 2,
-                crate::api::tracing::LogLevel::Debug => 3,
-                crate::api::tracing::LogLevel::Trace => 4,
+                crate::matrix::rooms::RoomHistoryVisibility::Invited => 0,
+                crate::matrix::rooms::RoomHistoryVisibility::Joined => 1,
+                crate::matrix::rooms::RoomHistoryVisibility::Shared => 2,
+                crate::matrix::rooms::RoomHistoryVisibility::WorldReadable => 3,
                 _ => {
                     unimplemented!("");
                 }
@@ -2561,26 +9047,41 @@ impl SseEncode for crate::api::tracing::LogLevel {
     }
 }
 
-impl SseEncode for crate::matrix::timelines::Message {
+impl SseEncode for crate::matrix::room_settings::RoomJoinRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.event_id, serializer);
-        <String>::sse_encode(self.sender, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <u64>::sse_encode(self.timestamp, serializer);
-        <crate::matrix::timelines::MessageType>::sse_encode(self.message_type, serializer);
+        match self {
+            crate::matrix::room_settings::RoomJoinRule::Public => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::matrix::room_settings::RoomJoinRule::Invite => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::matrix::room_settings::RoomJoinRule::Knock => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::matrix::room_settings::RoomJoinRule::Restricted { allowed_room_ids } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<String>>::sse_encode(allowed_room_ids, serializer);
+            }
+            crate::matrix::room_settings::RoomJoinRule::KnockRestricted { allowed_room_ids } => {
+                <i32>::sse_encode(4, serializer);
+                <Vec<String>>::sse_encode(allowed_room_ids, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::matrix::timelines::MessageType {
+impl SseEncode for crate::matrix::room_list::RoomListFilterCategory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::matrix::timelines::MessageType::Message => 0,
-                crate::matrix::timelines::MessageType::DateDivider => 1,
-                crate::matrix::timelines::MessageType::ReadMarker => 2,
-                crate::matrix::timelines::MessageType::TimelineStart => 3,
+                crate::matrix::room_list::RoomListFilterCategory::Group => 0,
+                crate::matrix::room_list::RoomListFilterCategory::People => 1,
                 _ => {
                     unimplemented!("");
                 }
@@ -2590,35 +9091,105 @@ impl SseEncode for crate::matrix::timelines::MessageType {
     }
 }
 
-impl SseEncode for crate::matrix::timelines::MessageUpdate {
+impl SseEncode for crate::matrix::room_list::RoomListFilterKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::matrix::timelines::MessageUpdateType>::sse_encode(
-            self.message_update_type,
-            serializer,
-        );
-        <Option<Vec<crate::matrix::timelines::Message>>>::sse_encode(self.messages, serializer);
+        match self {
+            crate::matrix::room_list::RoomListFilterKind::All { filters } => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<crate::matrix::room_list::RoomListFilterKind>>::sse_encode(
+                    filters, serializer,
+                );
+            }
+            crate::matrix::room_list::RoomListFilterKind::Any { filters } => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<crate::matrix::room_list::RoomListFilterKind>>::sse_encode(
+                    filters, serializer,
+                );
+            }
+            crate::matrix::room_list::RoomListFilterKind::NonSpace => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::NonLeft => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Joined => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Unread => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Favourite => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::LowPriority => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::NonLowPriority => {
+                <i32>::sse_encode(8, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Invite => {
+                <i32>::sse_encode(9, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Category { expect } => {
+                <i32>::sse_encode(10, serializer);
+                <crate::matrix::room_list::RoomListFilterCategory>::sse_encode(expect, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::None => {
+                <i32>::sse_encode(11, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::NormalizedMatchRoomName { pattern } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(pattern, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::FuzzyMatchRoomName { pattern } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(pattern, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::DeduplicateVersions => {
+                <i32>::sse_encode(14, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Tag { name } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(name, serializer);
+            }
+            crate::matrix::room_list::RoomListFilterKind::Space { space_id } => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(space_id, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::matrix::room_list::RoomListUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::matrix::room_list::RoomListUpdateType>::sse_encode(self.update_type, serializer);
+        <Option<Vec<crate::matrix::rooms::RoomUpdate>>>::sse_encode(self.rooms, serializer);
         <Option<usize>>::sse_encode(self.index, serializer);
         <Option<usize>>::sse_encode(self.length, serializer);
     }
 }
 
-impl SseEncode for crate::matrix::timelines::MessageUpdateType {
+impl SseEncode for crate::matrix::room_list::RoomListUpdateType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::matrix::timelines::MessageUpdateType::Reset => 0,
-                crate::matrix::timelines::MessageUpdateType::Truncate => 1,
-                crate::matrix::timelines::MessageUpdateType::Remove => 2,
-                crate::matrix::timelines::MessageUpdateType::Set => 3,
-                crate::matrix::timelines::MessageUpdateType::Insert => 4,
-                crate::matrix::timelines::MessageUpdateType::PopBack => 5,
-                crate::matrix::timelines::MessageUpdateType::PopFront => 6,
-                crate::matrix::timelines::MessageUpdateType::PushBack => 7,
-                crate::matrix::timelines::MessageUpdateType::PushFront => 8,
-                crate::matrix::timelines::MessageUpdateType::Clear => 9,
-                crate::matrix::timelines::MessageUpdateType::Append => 10,
+                crate::matrix::room_list::RoomListUpdateType::Reset => 0,
+                crate::matrix::room_list::RoomListUpdateType::Truncate => 1,
+                crate::matrix::room_list::RoomListUpdateType::Remove => 2,
+                crate::matrix::room_list::RoomListUpdateType::Set => 3,
+                crate::matrix::room_list::RoomListUpdateType::Insert => 4,
+                crate::matrix::room_list::RoomListUpdateType::PopBack => 5,
+                crate::matrix::room_list::RoomListUpdateType::PopFront => 6,
+                crate::matrix::room_list::RoomListUpdateType::PushBack => 7,
+                crate::matrix::room_list::RoomListUpdateType::PushFront => 8,
+                crate::matrix::room_list::RoomListUpdateType::Clear => 9,
+                crate::matrix::room_list::RoomListUpdateType::Append => 10,
                 _ => {
                     unimplemented!("");
                 }
@@ -2628,93 +9199,108 @@ impl SseEncode for crate::matrix::timelines::MessageUpdateType {
     }
 }
 
-impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
-    }
-}
-
-impl SseEncode for Option<bool> {
+impl SseEncode for crate::matrix::members::RoomMemberInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.user_id, serializer);
+        <Option<String>>::sse_encode(self.display_name, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <crate::matrix::members::MemberMembership>::sse_encode(self.membership, serializer);
+        <i64>::sse_encode(self.power_level, serializer);
+        <crate::matrix::members::MemberRole>::sse_encode(self.role, serializer);
     }
 }
 
-impl SseEncode for Option<crate::matrix::timelines::Message> {
+impl SseEncode for crate::matrix::members::RoomMembersPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::matrix::timelines::Message>::sse_encode(value, serializer);
-        }
+        <Vec<crate::matrix::members::RoomMemberInfo>>::sse_encode(self.members, serializer);
+        <u32>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::platform::TracingFileConfiguration> {
+impl SseEncode for crate::matrix::members::RoomMembersUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::platform::TracingFileConfiguration>::sse_encode(value, serializer);
+        match self {
+            crate::matrix::members::RoomMembersUpdate::Reset { members } => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<crate::matrix::members::RoomMemberInfo>>::sse_encode(members, serializer);
+            }
+            crate::matrix::members::RoomMembersUpdate::Upsert { member } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::matrix::members::RoomMemberInfo>::sse_encode(member, serializer);
+            }
+            crate::matrix::members::RoomMembersUpdate::Remove { user_id } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(user_id, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::matrix::moderation::RoomPermissions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <i64>::sse_encode(self.own_power_level, serializer);
+        <bool>::sse_encode(self.can_send_message, serializer);
+        <bool>::sse_encode(self.can_redact_own, serializer);
+        <bool>::sse_encode(self.can_redact_others, serializer);
+        <bool>::sse_encode(self.can_invite, serializer);
+        <bool>::sse_encode(self.can_kick, serializer);
+        <bool>::sse_encode(self.can_ban, serializer);
+        <bool>::sse_encode(self.can_change_settings, serializer);
+        <bool>::sse_encode(self.can_change_power_levels, serializer);
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for crate::matrix::moderation::RoomPowerLevelsInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        <i64>::sse_encode(self.ban, serializer);
+        <i64>::sse_encode(self.invite, serializer);
+        <i64>::sse_encode(self.kick, serializer);
+        <i64>::sse_encode(self.redact, serializer);
+        <i64>::sse_encode(self.events_default, serializer);
+        <i64>::sse_encode(self.state_default, serializer);
+        <i64>::sse_encode(self.users_default, serializer);
+        <Vec<crate::matrix::rooms::EventPowerLevel>>::sse_encode(self.events, serializer);
+        <Vec<crate::matrix::rooms::UserPowerLevel>>::sse_encode(self.users, serializer);
     }
 }
 
-impl SseEncode for Option<usize> {
+impl SseEncode for crate::matrix::joining::RoomPreviewInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.room_id, serializer);
+        <Option<String>>::sse_encode(self.canonical_alias, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <u64>::sse_encode(self.num_joined_members, serializer);
+        <bool>::sse_encode(self.is_space, serializer);
+        <Option<bool>>::sse_encode(self.is_world_readable, serializer);
+        <Option<bool>>::sse_encode(self.is_direct, serializer);
+        <Option<crate::matrix::rooms::UpdateType>>::sse_encode(self.membership, serializer);
     }
 }
 
-impl SseEncode for Option<Vec<Certificate>> {
+impl SseEncode for crate::matrix::rooms::RoomSuccessor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Certificate>>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.room_id, serializer);
+        <Option<String>>::sse_encode(self.reason, serializer);
     }
 }
 
-impl SseEncode for Option<Vec<crate::matrix::timelines::Message>> {
+impl SseEncode for crate::matrix::tags::RoomTag {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::matrix::timelines::Message>>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.name, serializer);
+        <Option<f64>>::sse_encode(self.order, serializer);
     }
 }
 
@@ -2730,7 +9316,64 @@ impl SseEncode for crate::matrix::rooms::RoomUpdate {
         <Option<u64>>::sse_encode(self.unread_highlight, serializer);
         <Option<u64>>::sse_encode(self.unread_mentions, serializer);
         <Option<u64>>::sse_encode(self.unread_messages, serializer);
+        <bool>::sse_encode(self.is_marked_unread, serializer);
         <Option<crate::matrix::timelines::Message>>::sse_encode(self.message, serializer);
+        <Vec<crate::matrix::tags::RoomTag>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <Option<String>>::sse_encode(self.canonical_alias, serializer);
+        <bool>::sse_encode(self.is_encrypted, serializer);
+        <Option<crate::matrix::rooms::RoomHistoryVisibility>>::sse_encode(
+            self.history_visibility,
+            serializer,
+        );
+        <Option<crate::matrix::room_settings::RoomJoinRule>>::sse_encode(
+            self.join_rule,
+            serializer,
+        );
+        <Option<crate::matrix::rooms::RoomSuccessor>>::sse_encode(self.successor, serializer);
+        <Option<String>>::sse_encode(self.predecessor_room_id, serializer);
+    }
+}
+
+impl SseEncode for crate::matrix::rooms::RoomVisibility {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::matrix::rooms::RoomVisibility::Public => 0,
+                crate::matrix::rooms::RoomVisibility::Private => 1,
+                crate::matrix::rooms::RoomVisibility::Knock => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::matrix::spaces::SpaceHierarchyPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::matrix::spaces::SpaceHierarchyRoom>>::sse_encode(self.rooms, serializer);
+        <Option<String>>::sse_encode(self.next_batch, serializer);
+    }
+}
+
+impl SseEncode for crate::matrix::spaces::SpaceHierarchyRoom {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.room_id, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.topic, serializer);
+        <Option<String>>::sse_encode(self.avatar_url, serializer);
+        <Option<String>>::sse_encode(self.canonical_alias, serializer);
+        <u64>::sse_encode(self.num_joined_members, serializer);
+        <bool>::sse_encode(self.is_space, serializer);
+        <bool>::sse_encode(self.suggested, serializer);
+        <Vec<String>>::sse_encode(self.children, serializer);
+        <Option<crate::matrix::rooms::UpdateType>>::sse_encode(self.membership, serializer);
     }
 }
 
@@ -2837,6 +9480,25 @@ impl SseEncode for crate::matrix::user_serach::User {
     }
 }
 
+impl SseEncode for crate::matrix::rooms::UserPowerLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.user_id, serializer);
+        <i64>::sse_encode(self.power_level, serializer);
+    }
+}
+
+impl SseEncode for crate::matrix::presence::UserPresence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.user_id, serializer);
+        <crate::matrix::presence::PresenceKind>::sse_encode(self.presence, serializer);
+        <Option<u64>>::sse_encode(self.last_active, serializer);
+        <Option<bool>>::sse_encode(self.currently_active, serializer);
+        <Option<String>>::sse_encode(self.status_message, serializer);
+    }
+}
+
 impl SseEncode for crate::matrix::user_serach::UserSearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
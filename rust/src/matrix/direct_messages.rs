@@ -0,0 +1,95 @@
+use flutter_rust_bridge::frb;
+use matrix_sdk::{room::Invite, ruma::UserId, Client, Room, RoomState};
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    matrix::sync_service::GLOBAL_APP,
+};
+
+/// Whether `invite` was sent as a DM. Until it's accepted the room isn't in
+/// `m.direct`, the flag only lives on our membership event.
+#[frb(ignore)]
+pub fn is_direct_invite(invite: &Invite) -> bool {
+    invite
+        .invitee
+        .event()
+        .original_content()
+        .and_then(|content| content.is_direct)
+        .unwrap_or(false)
+}
+
+/// Find a DM with `user_id` we are joined or invited to, preferring joined
+/// rooms.
+#[frb(ignore)]
+pub async fn find_dm_room(client: &Client, user_id: &UserId) -> Option<Room> {
+    if let Some(room) = client.get_dm_room(user_id) {
+        return Some(room);
+    }
+
+    for room in client.invited_rooms() {
+        let Ok(invite) = room.invite_details().await else {
+            continue;
+        };
+        let from_user = invite
+            .inviter
+            .as_ref()
+            .is_some_and(|inviter| inviter.user_id() == user_id);
+        if from_user && is_direct_invite(&invite) {
+            return Some(room);
+        }
+    }
+
+    None
+}
+
+/// Join `room`, adding it to `m.direct` if it was an invite sent as a DM so
+/// it shows up as one on all our devices.
+#[frb(ignore)]
+pub async fn join_and_mark_dm(room: &Room) -> Result<(), String> {
+    let was_direct_invite = match room.state() {
+        RoomState::Invited => room
+            .invite_details()
+            .await
+            .is_ok_and(|invite| is_direct_invite(&invite)),
+        _ => false,
+    };
+
+    room.join().await.map_err(|e| e.to_string())?;
+
+    if was_direct_invite && !room.is_direct().await.unwrap_or(false) {
+        if let Err(e) = room.set_is_direct(true).await {
+            log_warn(format!("Failed to mark {} as a DM: {}", room.room_id(), e));
+        }
+    }
+    Ok(())
+}
+
+/// Get the DM room with `user_id`, accepting a pending DM invite from them or
+/// creating a new DM if there is none. Returns the room ID.
+pub fn get_or_create_dm(user_id: String) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+
+            if let Some(room) = find_dm_room(&app.client, &user_id).await {
+                if room.state() == RoomState::Invited {
+                    join_and_mark_dm(&room).await?;
+                }
+                return Ok(room.room_id().to_string());
+            }
+
+            // Unlike a plain `create_room`, this also records the room in
+            // `m.direct`.
+            let room = app
+                .client
+                .create_dm(&user_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(room.room_id().to_string())
+        })
+    })
+}
@@ -0,0 +1,228 @@
+use matrix_sdk::ruma::{
+    api::client::{directory::get_public_rooms_filtered, room::Visibility},
+    directory::Filter,
+    room::RoomType,
+    RoomAliasId, ServerName, UInt,
+};
+
+use crate::{
+    api::{logger::log_error, platform::GLOBAL_RUNTIME},
+    matrix::{rooms::get_room, sync_service::GLOBAL_APP},
+};
+
+/// A room of a public room directory.
+pub struct PublicRoom {
+    pub room_id: String,
+    pub canonical_alias: Option<String>,
+    pub name: Option<String>,
+    pub topic: Option<String>,
+    pub avatar_url: Option<String>,
+    pub num_joined_members: u64,
+    pub world_readable: bool,
+    pub guest_can_join: bool,
+    pub is_space: bool,
+}
+
+pub struct PublicRoomsPage {
+    pub rooms: Vec<PublicRoom>,
+    /// Token to pass as `since` to get the next page, `None` on the last one.
+    pub next_batch: Option<String>,
+    pub prev_batch: Option<String>,
+    pub total_room_count_estimate: Option<u64>,
+}
+
+pub struct RoomAliases {
+    pub canonical_alias: Option<String>,
+    pub alt_aliases: Vec<String>,
+}
+
+/// Browse the public room directory of our homeserver, or of `server` if set.
+///
+/// Pass the `next_batch` of a page as `since` to get the following one.
+pub fn search_public_rooms(
+    search_term: Option<String>,
+    server: Option<String>,
+    limit: Option<u32>,
+    since: Option<String>,
+) -> Result<PublicRoomsPage, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+
+            let mut filter = Filter::new();
+            filter.generic_search_term = search_term;
+
+            let mut request = get_public_rooms_filtered::v3::Request::new();
+            request.filter = filter;
+            request.server = server
+                .map(|server| ServerName::parse(&server))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            request.limit = limit.map(UInt::from);
+            request.since = since;
+
+            let response = app
+                .client
+                .public_rooms_filtered(request)
+                .await
+                .map_err(|e| {
+                    log_error(format!("Public room directory search failed: {}", e));
+                    e.to_string()
+                })?;
+
+            let rooms = response
+                .chunk
+                .into_iter()
+                .map(|room| PublicRoom {
+                    room_id: room.room_id.to_string(),
+                    canonical_alias: room.canonical_alias.map(|alias| alias.to_string()),
+                    name: room.name,
+                    topic: room.topic,
+                    avatar_url: room.avatar_url.map(|uri| uri.to_string()),
+                    num_joined_members: room.num_joined_members.into(),
+                    world_readable: room.world_readable,
+                    guest_can_join: room.guest_can_join,
+                    is_space: room.room_type == Some(RoomType::Space),
+                })
+                .collect();
+
+            Ok(PublicRoomsPage {
+                rooms,
+                next_batch: response.next_batch,
+                prev_batch: response.prev_batch,
+                total_room_count_estimate: response.total_room_count_estimate.map(u64::from),
+            })
+        })
+    })
+}
+
+/// Publish a room in, or remove it from, our homeserver's room directory.
+pub fn set_room_published(room_id: String, published: bool) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let visibility = if published {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            };
+            room.privacy_settings()
+                .update_room_visibility(visibility)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn is_room_published(room_id: String) -> Result<bool, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let visibility = room
+                .privacy_settings()
+                .get_room_visibility()
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(visibility == Visibility::Public)
+        })
+    })
+}
+
+pub fn get_room_aliases(room_id: String) -> Result<RoomAliases, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            Ok(RoomAliases {
+                canonical_alias: room.canonical_alias().map(|alias| alias.to_string()),
+                alt_aliases: room
+                    .alt_aliases()
+                    .into_iter()
+                    .map(|alias| alias.to_string())
+                    .collect(),
+            })
+        })
+    })
+}
+
+/// Point `alias` (e.g. `#room:example.org`) to a room in the directory of the
+/// alias' homeserver. Returns `false` if the alias is already taken.
+pub fn add_room_alias(room_id: String, alias: String) -> Result<bool, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let alias = RoomAliasId::parse(&alias).map_err(|e| e.to_string())?;
+
+            room.privacy_settings()
+                .publish_room_alias_in_room_directory(&alias)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Remove `alias` from the directory. Returns `false` if it didn't exist.
+pub fn remove_room_alias(room_id: String, alias: String) -> Result<bool, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let alias = RoomAliasId::parse(&alias).map_err(|e| e.to_string())?;
+
+            room.privacy_settings()
+                .remove_room_alias_from_room_directory(&alias)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Set the `m.room.canonical_alias` state of a room. The aliases must already
+/// point to the room, see [`add_room_alias`].
+pub fn set_canonical_alias(
+    room_id: String,
+    alias: Option<String>,
+    alt_aliases: Vec<String>,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let alias = alias
+                .map(|alias| RoomAliasId::parse(&alias))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            let alt_aliases = alt_aliases
+                .iter()
+                .map(|alias| RoomAliasId::parse(alias).map_err(|e| e.to_string()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            room.privacy_settings()
+                .update_canonical_alias(alias, alt_aliases)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
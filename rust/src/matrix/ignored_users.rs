@@ -0,0 +1,168 @@
+use std::{collections::BTreeSet, sync::Mutex};
+
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    ruma::{
+        events::{
+            ignored_user_list::IgnoredUserListEventContent,
+            room::member::{MembershipState, StrippedRoomMemberEvent},
+            AnySyncTimelineEvent,
+        },
+        OwnedUserId, RoomVersionId, UserId,
+    },
+    Client, Room,
+};
+use matrix_sdk_ui::timeline::default_event_filter;
+
+use crate::{
+    api::{
+        logger::{log_info, log_warn},
+        platform::GLOBAL_RUNTIME,
+    },
+    matrix::sync_service::GLOBAL_APP,
+};
+
+/// Mirror of `m.ignored_user_list`, for the timeline event filter which can't
+/// wait on the store.
+static IGNORED_USERS: Mutex<BTreeSet<OwnedUserId>> = Mutex::new(BTreeSet::new());
+
+#[frb(ignore)]
+pub fn is_user_ignored(user_id: &UserId) -> bool {
+    IGNORED_USERS.lock().unwrap().contains(user_id)
+}
+
+/// Timeline event filter hiding the events of ignored users on top of the
+/// SDK's default filter.
+///
+/// The SDK clears the timelines when the ignore list changes, so already
+/// loaded events are filtered again too.
+#[frb(ignore)]
+pub fn filter_ignored_users_events(
+    event: &AnySyncTimelineEvent,
+    room_version: &RoomVersionId,
+) -> bool {
+    default_event_filter(event, room_version) && !is_user_ignored(event.sender())
+}
+
+/// Decline the pending invites sent by ignored users.
+async fn decline_ignored_invites(client: &Client) {
+    for room in client.invited_rooms() {
+        let Ok(invite) = room.invite_details().await else {
+            continue;
+        };
+        if invite
+            .inviter
+            .is_some_and(|inviter| is_user_ignored(inviter.user_id()))
+        {
+            decline_ignored_invite(&room).await;
+        }
+    }
+}
+
+async fn decline_ignored_invite(room: &Room) {
+    log_info(format!(
+        "Declining invite to {} from an ignored user",
+        room.room_id()
+    ));
+    if let Err(e) = room.leave().await {
+        log_warn(format!(
+            "Failed to decline invite to {}: {}",
+            room.room_id(),
+            e
+        ));
+    }
+}
+
+/// Keep [`IGNORED_USERS`] in sync with the account data, and auto-decline
+/// invites from ignored users, both pending and incoming.
+#[frb(ignore)]
+pub fn spawn_ignored_users_task(client: Client) {
+    client.add_event_handler(
+        |event: StrippedRoomMemberEvent, room: Room, client: Client| async move {
+            let is_own_invite = client.user_id() == Some(&*event.state_key)
+                && event.content.membership == MembershipState::Invite;
+            if is_own_invite && is_user_ignored(&event.sender) {
+                decline_ignored_invite(&room).await;
+            }
+        },
+    );
+
+    tokio::spawn(async move {
+        let mut ignored_users = client.subscribe_to_ignore_user_list_changes();
+        let mut user_ids = ignored_users.get();
+        loop {
+            *IGNORED_USERS.lock().unwrap() = user_ids
+                .iter()
+                .filter_map(|user_id| UserId::parse(user_id).ok())
+                .collect();
+            decline_ignored_invites(&client).await;
+
+            match ignored_users.next().await {
+                Some(next) => user_ids = next,
+                None => break,
+            }
+        }
+    });
+}
+
+/// Ignore a user: their messages are hidden and their invites declined.
+pub fn ignore_user(user_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            app.client
+                .account()
+                .ignore_user(&user_id)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn unignore_user(user_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            app.client
+                .account()
+                .unignore_user(&user_id)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn get_ignored_users() -> Result<Vec<String>, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let Some(raw) = app
+                .client
+                .account()
+                .account_data::<IgnoredUserListEventContent>()
+                .await
+                .map_err(|e| e.to_string())?
+            else {
+                return Ok(Vec::new());
+            };
+
+            let content = raw.deserialize().map_err(|e| e.to_string())?;
+            Ok(content
+                .ignored_users
+                .into_keys()
+                .map(|user_id| user_id.to_string())
+                .collect())
+        })
+    })
+}
@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+use futures::future::join_all;
+use matrix_sdk::{ruma::api::client::membership::leave_room, Room, RoomState};
+
+use crate::{
+    api::{
+        logger::{log_info, log_warn},
+        platform::GLOBAL_RUNTIME,
+    },
+    matrix::{
+        direct_messages::{is_direct_invite, join_and_mark_dm},
+        rooms::get_room,
+        sync_service::GLOBAL_APP,
+        user_serach::User,
+    },
+};
+
+/// How long `decline_invite` waits for the sync to confirm a leave sent with a
+/// reason.
+const LEAVE_SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Everything needed to show an invite in the invites inbox.
+pub struct InviteDetails {
+    pub room_id: String,
+    pub room_name: Option<String>,
+    pub topic: Option<String>,
+    pub avatar_url: Option<String>,
+    pub member_count: u64,
+    pub is_dm: bool,
+    pub inviter: Option<User>,
+}
+
+#[frb(ignore)]
+pub async fn get_invite_details_data(room: &Room) -> Result<InviteDetails, String> {
+    if room.state() != RoomState::Invited {
+        return Err("Room is not an invite".to_string());
+    }
+
+    let invite = room.invite_details().await.map_err(|e| e.to_string())?;
+
+    let is_dm = is_direct_invite(&invite);
+
+    let inviter = invite.inviter.map(|inviter| User {
+        user_id: inviter.user_id().to_string(),
+        display_name: inviter.display_name().map(|name| name.to_owned()),
+        avatar_url: inviter.avatar_url().map(|uri| uri.to_string()),
+    });
+
+    // Invites only come with the stripped state of the room, so the local
+    // member count is usually 0; the room summary from the server has it.
+    let member_count = match room
+        .client()
+        .get_room_preview(room.room_id().into(), Vec::new())
+        .await
+    {
+        Ok(preview) => preview.num_joined_members,
+        Err(e) => {
+            log_warn(format!(
+                "Failed to get the preview of invite {}: {}",
+                room.room_id(),
+                e
+            ));
+            room.joined_members_count()
+        }
+    };
+
+    Ok(InviteDetails {
+        room_id: room.room_id().to_string(),
+        room_name: room.cached_display_name().map(|name| name.to_string()),
+        topic: room.topic(),
+        avatar_url: room.avatar_url().map(|uri| uri.to_string()),
+        member_count,
+        is_dm,
+        inviter,
+    })
+}
+
+pub fn get_invite_details(room_id: String) -> Result<InviteDetails, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            get_invite_details_data(room).await
+        })
+    })
+}
+
+/// List all the pending invites, for the invites inbox.
+pub fn get_invites() -> Result<Vec<InviteDetails>, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+
+            // Each invite needs a room preview from the server, fetch them
+            // all at once rather than one after the other.
+            let rooms = app.client.invited_rooms();
+            let details = join_all(rooms.iter().map(get_invite_details_data)).await;
+
+            let mut invites = Vec::with_capacity(details.len());
+            for (room, invite) in rooms.iter().zip(details) {
+                match invite {
+                    Ok(invite) => invites.push(invite),
+                    Err(e) => log_warn(format!(
+                        "Failed to get invite details of {}: {}",
+                        room.room_id(),
+                        e
+                    )),
+                }
+            }
+            Ok(invites)
+        })
+    })
+}
+
+pub fn accept_invite(room_id: String) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            if room.state() != RoomState::Invited {
+                return Err("Room is not an invite".to_string());
+            }
+
+            join_and_mark_dm(room).await?;
+            Ok(room_id.to_string())
+        })
+    })
+}
+
+/// Wait for the sync to move `room` out of the invited state, so callers see
+/// the same local state as after `Room::leave`.
+async fn wait_until_left(room: &Room) {
+    let mut room_info = room.subscribe_info();
+    let left = async {
+        while room.state() == RoomState::Invited {
+            if room_info.next().await.is_none() {
+                break;
+            }
+        }
+    };
+    if tokio::time::timeout(LEAVE_SYNC_TIMEOUT, left)
+        .await
+        .is_err()
+    {
+        log_warn(format!(
+            "Left {} but the sync didn't report it yet",
+            room.room_id()
+        ));
+    }
+}
+
+/// Decline an invite, optionally telling the inviter why and ignoring them so
+/// they can't invite us again.
+pub fn decline_invite(
+    room_id: String,
+    reason: Option<String>,
+    ignore_inviter: bool,
+) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let room = &get_room(&room_id)?;
+            if room.state() != RoomState::Invited {
+                return Err("Room is not an invite".to_string());
+            }
+
+            // Look the inviter up before leaving, the invite is gone afterwards.
+            let inviter = if ignore_inviter {
+                room.invite_details()
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .inviter
+                    .map(|inviter| inviter.user_id().to_owned())
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => {
+                    // `Room::leave` can't carry a reason, send the request
+                    // ourselves and let the sync update the room's state.
+                    let mut request = leave_room::v3::Request::new(room.room_id().to_owned());
+                    request.reason = Some(reason);
+                    app.client.send(request).await.map_err(|e| e.to_string())?;
+                    wait_until_left(room).await;
+                }
+                None => room.leave().await.map_err(|e| e.to_string())?,
+            }
+
+            if let Some(inviter) = inviter {
+                app.client
+                    .account()
+                    .ignore_user(&inviter)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                log_info(format!("Ignored inviter {} of {}", inviter, room_id));
+            }
+
+            Ok(room_id.to_string())
+        })
+    })
+}
@@ -0,0 +1,186 @@
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    ruma::{
+        matrix_uri::MatrixId, room::RoomType, MatrixToUri, MatrixUri, OwnedRoomOrAliasId,
+        OwnedServerName, RoomOrAliasId, ServerName,
+    },
+    RoomState,
+};
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    matrix::{rooms::UpdateType, sync_service::GLOBAL_APP},
+};
+
+/// What a room looks like before joining it.
+pub struct RoomPreviewInfo {
+    pub room_id: String,
+    pub canonical_alias: Option<String>,
+    pub name: Option<String>,
+    pub topic: Option<String>,
+    pub avatar_url: Option<String>,
+    pub num_joined_members: u64,
+    pub is_space: bool,
+    pub is_world_readable: Option<bool>,
+    pub is_direct: Option<bool>,
+    /// Our membership in the room, if we know the room already.
+    pub membership: Option<UpdateType>,
+}
+
+/// Parse the room to join out of `target`, which can be a room ID
+/// (`!room:server`), an alias (`#room:server`), a `https://matrix.to/#/…`
+/// link or a `matrix:` URI.
+///
+/// Servers found in the link are returned along with `via_servers`.
+#[frb(ignore)]
+pub fn parse_room_target(
+    target: &str,
+    via_servers: Vec<String>,
+) -> Result<(OwnedRoomOrAliasId, Vec<OwnedServerName>), String> {
+    let mut servers = via_servers
+        .iter()
+        .map(|server| ServerName::parse(server).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let target = target.trim();
+    let (id, uri_servers) = if let Ok(uri) = MatrixToUri::parse(target) {
+        (uri.id().clone(), uri.via().to_vec())
+    } else if let Ok(uri) = MatrixUri::parse(target) {
+        (uri.id().clone(), uri.via().to_vec())
+    } else {
+        let id = RoomOrAliasId::parse(target).map_err(|e| e.to_string())?;
+        return Ok((id, servers));
+    };
+
+    let id: OwnedRoomOrAliasId = match id {
+        MatrixId::Room(room_id) => room_id.into(),
+        MatrixId::RoomAlias(alias) => alias.into(),
+        MatrixId::Event(room_or_alias_id, _) => room_or_alias_id,
+        _ => return Err("Link doesn't point to a room".to_string()),
+    };
+
+    for server in uri_servers {
+        if !servers.contains(&server) {
+            servers.push(server);
+        }
+    }
+    Ok((id, servers))
+}
+
+/// Join a room by ID, alias or link (see [`parse_room_target`]), including
+/// rooms the client doesn't know yet. Returns the ID of the joined room.
+pub fn join_room_by_id_or_alias(
+    target: String,
+    via_servers: Vec<String>,
+) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let (id, servers) = parse_room_target(&target, via_servers)?;
+
+            let room = app
+                .client
+                .join_room_by_id_or_alias(&id, &servers)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(room.room_id().to_string())
+        })
+    })
+}
+
+/// Preview a room by ID, alias or link before joining it.
+pub fn get_room_preview(
+    target: String,
+    via_servers: Vec<String>,
+) -> Result<RoomPreviewInfo, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let (id, servers) = parse_room_target(&target, via_servers)?;
+
+            let preview = app
+                .client
+                .get_room_preview(&id, servers)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(RoomPreviewInfo {
+                room_id: preview.room_id.to_string(),
+                canonical_alias: preview.canonical_alias.map(|alias| alias.to_string()),
+                name: preview.name,
+                topic: preview.topic,
+                avatar_url: preview.avatar_url.map(|uri| uri.to_string()),
+                num_joined_members: preview.num_joined_members,
+                is_space: preview.room_type == Some(RoomType::Space),
+                is_world_readable: preview.is_world_readable,
+                is_direct: preview.is_direct,
+                membership: preview.state.map(|state| match state {
+                    RoomState::Joined => UpdateType::Joined,
+                    RoomState::Invited => UpdateType::Invited,
+                    RoomState::Knocked => UpdateType::Knocked,
+                    RoomState::Banned => UpdateType::Banned,
+                    RoomState::Left => UpdateType::Left,
+                }),
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_room_target;
+
+    #[test]
+    fn test_parse_room_id_and_alias() {
+        let (id, servers) =
+            parse_room_target("!abc:example.org", vec!["other.org".to_owned()]).unwrap();
+        assert_eq!(id.as_str(), "!abc:example.org");
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].as_str(), "other.org");
+
+        let (id, servers) = parse_room_target(" #room:example.org ", Vec::new()).unwrap();
+        assert_eq!(id.as_str(), "#room:example.org");
+        assert!(servers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_matrix_to_link() {
+        let (id, servers) = parse_room_target(
+            "https://matrix.to/#/!abc:example.org?via=example.org&via=other.org",
+            vec!["other.org".to_owned()],
+        )
+        .unwrap();
+        assert_eq!(id.as_str(), "!abc:example.org");
+        assert_eq!(
+            servers.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+            vec!["other.org", "example.org"]
+        );
+
+        let (id, _) =
+            parse_room_target("https://matrix.to/#/%23room:example.org", Vec::new()).unwrap();
+        assert_eq!(id.as_str(), "#room:example.org");
+    }
+
+    #[test]
+    fn test_parse_matrix_uri() {
+        let (id, servers) = parse_room_target(
+            "matrix:r/room:example.org/e/event?via=example.org",
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(id.as_str(), "#room:example.org");
+        assert_eq!(servers[0].as_str(), "example.org");
+    }
+
+    #[test]
+    fn test_parse_rejects_user_links() {
+        assert!(parse_room_target("https://matrix.to/#/@alice:example.org", Vec::new()).is_err());
+        assert!(parse_room_target("not a room", Vec::new()).is_err());
+    }
+}
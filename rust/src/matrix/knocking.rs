@@ -0,0 +1,149 @@
+use futures::{pin_mut, StreamExt};
+use matrix_sdk::ruma::UserId;
+
+use crate::{
+    api::{logger::log_error, platform::GLOBAL_RUNTIME},
+    frb_generated::StreamSink,
+    matrix::{joining::parse_room_target, rooms::get_room, sync_service::GLOBAL_APP},
+};
+
+/// A pending request to join a room with the `knock` join rule.
+pub struct KnockRequestInfo {
+    pub event_id: String,
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub reason: Option<String>,
+    pub timestamp: Option<u64>,
+    /// Whether a moderator already marked the request as seen.
+    pub is_seen: bool,
+}
+
+/// Ask to join a room by ID, alias or link. Returns the ID of the knocked
+/// room; it shows up with the `Knocked` update type until a moderator answers.
+pub fn knock_room(
+    target: String,
+    reason: Option<String>,
+    via_servers: Vec<String>,
+) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let (id, servers) = parse_room_target(&target, via_servers)?;
+
+            let room = app
+                .client
+                .knock(id, reason, servers)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(room.room_id().to_string())
+        })
+    })
+}
+
+/// Stream the pending knock requests of a room; every item is the full list.
+///
+/// Only useful for members allowed to invite, others get an empty list.
+pub async fn subscribe_to_knock_requests(
+    stream: StreamSink<Vec<KnockRequestInfo>>,
+    room_id: String,
+) -> Result<(), String> {
+    let room = get_room(&room_id)?;
+
+    let (requests, task) = room.subscribe_to_knock_requests().await.map_err(|e| {
+        log_error(format!("Failed to subscribe to knock requests: {}", e));
+        e.to_string()
+    })?;
+    pin_mut!(requests);
+
+    while let Some(requests) = requests.next().await {
+        let requests = requests
+            .into_iter()
+            .map(|request| KnockRequestInfo {
+                event_id: request.event_id.to_string(),
+                user_id: request.member_info.user_id.to_string(),
+                display_name: request.member_info.display_name.clone(),
+                avatar_url: request
+                    .member_info
+                    .avatar_url
+                    .as_ref()
+                    .map(|uri| uri.to_string()),
+                reason: request.member_info.reason.clone(),
+                timestamp: request.timestamp.map(u64::from),
+                is_seen: request.is_seen,
+            })
+            .collect();
+
+        if stream.add(requests).is_err() {
+            break;
+        }
+    }
+
+    task.abort();
+    Ok(())
+}
+
+/// Accept a knock request by inviting its sender.
+pub fn accept_knock_request(room_id: String, user_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            room.invite_user_by_id(&user_id)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Decline a knock request, optionally banning its sender so they can't knock
+/// again.
+pub fn decline_knock_request(
+    room_id: String,
+    user_id: String,
+    reason: Option<String>,
+    ban: bool,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+
+            if ban {
+                room.ban_user(&user_id, reason.as_deref()).await
+            } else {
+                room.kick_user(&user_id, reason.as_deref()).await
+            }
+            .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Mark the knock requests of `user_ids` as seen, for the unseen badge.
+pub fn mark_knock_requests_as_seen(room_id: String, user_ids: Vec<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let user_ids = user_ids
+                .iter()
+                .map(|user_id| UserId::parse(user_id).map_err(|e| e.to_string()))
+                .collect::<Result<Vec<_>, _>>()?;
+            room.mark_knock_requests_as_seen(&user_ids)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
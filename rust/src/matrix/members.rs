@@ -0,0 +1,281 @@
+use std::collections::BTreeSet;
+
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    room::RoomMember,
+    ruma::{
+        events::room::member::{MembershipState, SyncRoomMemberEvent},
+        UserId,
+    },
+    Room, RoomMemberships,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    frb_generated::StreamSink,
+    matrix::{rooms::get_room, sync_service::GLOBAL_APP},
+};
+
+pub enum MemberMembership {
+    Join,
+    Invite,
+    Knock,
+    Leave,
+    Ban,
+}
+
+/// The role shown for a power level, using the usual 100/50 thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRole {
+    Administrator,
+    Moderator,
+    User,
+}
+
+impl MemberRole {
+    #[frb(ignore)]
+    pub fn from_power_level(power_level: i64) -> Self {
+        if power_level >= 100 {
+            MemberRole::Administrator
+        } else if power_level >= 50 {
+            MemberRole::Moderator
+        } else {
+            MemberRole::User
+        }
+    }
+}
+
+pub struct RoomMemberInfo {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub membership: MemberMembership,
+    pub power_level: i64,
+    pub role: MemberRole,
+}
+
+/// A change of the member list streamed by [`subscribe_to_room_members`].
+pub enum RoomMembersUpdate {
+    /// The whole sorted list, sent first and again once the full list has
+    /// been fetched.
+    Reset { members: Vec<RoomMemberInfo> },
+    /// Someone joined or was invited, or a member changed their profile.
+    Upsert { member: RoomMemberInfo },
+    /// Someone left, or was kicked or banned.
+    Remove { user_id: String },
+}
+
+pub struct RoomMembersPage {
+    pub members: Vec<RoomMemberInfo>,
+    /// Number of members matching, across all pages.
+    pub total: u32,
+}
+
+fn get_member_info(member: &RoomMember) -> RoomMemberInfo {
+    let membership = match member.membership() {
+        MembershipState::Join => MemberMembership::Join,
+        MembershipState::Invite => MemberMembership::Invite,
+        MembershipState::Knock => MemberMembership::Knock,
+        MembershipState::Ban => MemberMembership::Ban,
+        _ => MemberMembership::Leave,
+    };
+    let power_level = member.power_level();
+
+    RoomMemberInfo {
+        user_id: member.user_id().to_string(),
+        display_name: member.display_name().map(|name| name.to_owned()),
+        avatar_url: member.avatar_url().map(|uri| uri.to_string()),
+        membership,
+        power_level,
+        role: MemberRole::from_power_level(power_level),
+    }
+}
+
+/// Joined and invited members of `room`, admins first then by name.
+///
+/// Only reads the store, the full list is fetched once by
+/// [`load_room_members`] when the list is opened, since large rooms are lazy
+/// loaded.
+async fn get_sorted_members(room: &Room) -> Result<Vec<RoomMemberInfo>, String> {
+    let members = room
+        .members_no_sync(RoomMemberships::ACTIVE)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut members: Vec<_> = members.iter().map(get_member_info).collect();
+    members.sort_by_cached_key(|member| {
+        (
+            -member.power_level,
+            member
+                .display_name
+                .as_deref()
+                .unwrap_or(&member.user_id)
+                .to_lowercase(),
+        )
+    });
+    Ok(members)
+}
+
+/// Fetch the full member list from the server if we only have the lazy
+/// loaded members.
+async fn load_room_members(room: &Room) -> Result<(), String> {
+    if room.are_members_synced() {
+        return Ok(());
+    }
+    room.sync_members().await.map_err(|e| e.to_string())
+}
+
+/// A page of the joined and invited members of a room, admins first then by
+/// name.
+///
+/// The first page (`offset` 0) fetches the full member list from the server if
+/// it wasn't yet, the following ones only read the store.
+pub fn get_room_members(
+    room_id: String,
+    offset: u32,
+    limit: u32,
+) -> Result<RoomMembersPage, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            if offset == 0 {
+                load_room_members(room).await?;
+            }
+            let members = get_sorted_members(room).await?;
+            let total = members.len() as u32;
+            let members = members
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect();
+            Ok(RoomMembersPage { members, total })
+        })
+    })
+}
+
+/// Members whose user ID or display name contains `query`, case insensitive.
+///
+/// Searches the members in the store, see [`get_room_members`].
+pub fn search_room_members(
+    room_id: String,
+    query: String,
+    limit: u32,
+) -> Result<RoomMembersPage, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let query = query.to_lowercase();
+            let members: Vec<_> = get_sorted_members(room)
+                .await?
+                .into_iter()
+                .filter(|member| {
+                    member.user_id.to_lowercase().contains(&query)
+                        || member
+                            .display_name
+                            .as_ref()
+                            .is_some_and(|name| name.to_lowercase().contains(&query))
+                })
+                .collect();
+            let total = members.len() as u32;
+            let members = members.into_iter().take(limit as usize).collect();
+            Ok(RoomMembersPage { members, total })
+        })
+    })
+}
+
+/// The update for the member `user_id` after one of their member events.
+async fn get_member_update(room: &Room, user_id: &UserId) -> Result<RoomMembersUpdate, String> {
+    let member = room
+        .get_member_no_sync(user_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(match member {
+        Some(member)
+            if matches!(
+                member.membership(),
+                MembershipState::Join | MembershipState::Invite
+            ) =>
+        {
+            RoomMembersUpdate::Upsert {
+                member: get_member_info(&member),
+            }
+        }
+        _ => RoomMembersUpdate::Remove {
+            user_id: user_id.to_string(),
+        },
+    })
+}
+
+/// Stream the changes of the joined and invited members of a room.
+///
+/// The first item is a `Reset` with the sorted list from the store, followed
+/// by another `Reset` once the full list has been fetched. Afterwards, every
+/// item holds the members who joined, left or changed their profile; keeping
+/// the list sorted is up to the caller.
+pub async fn subscribe_to_room_members(
+    stream: StreamSink<Vec<RoomMembersUpdate>>,
+    room_id: String,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room = get_room(&room_id)?;
+    let room_id = room.room_id();
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let handle = app
+        .client
+        .add_room_event_handler(room_id, move |event: SyncRoomMemberEvent| {
+            let sender = sender.clone();
+            async move {
+                let _ = sender.send(event.state_key().to_owned());
+            }
+        });
+    let _guard = app.client.event_handler_drop_guard(handle);
+
+    let reset = RoomMembersUpdate::Reset {
+        members: get_sorted_members(&room).await?,
+    };
+    if stream.add(vec![reset]).is_err() {
+        return Ok(());
+    }
+    if !room.are_members_synced() {
+        match load_room_members(&room).await {
+            Ok(()) => {
+                let reset = RoomMembersUpdate::Reset {
+                    members: get_sorted_members(&room).await?,
+                };
+                if stream.add(vec![reset]).is_err() {
+                    return Ok(());
+                }
+            }
+            Err(e) => log_warn(format!("Failed to load members of {}: {}", room_id, e)),
+        }
+    }
+
+    while let Some(user_id) = receiver.recv().await {
+        // A sync can carry many member events, send one batch for all of them.
+        let mut user_ids = BTreeSet::from([user_id]);
+        while let Ok(user_id) = receiver.try_recv() {
+            user_ids.insert(user_id);
+        }
+
+        let mut updates = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            updates.push(get_member_update(&room, &user_id).await?);
+        }
+        if stream.add(updates).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
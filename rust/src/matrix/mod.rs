@@ -1,9 +1,26 @@
 pub mod authentication;
 pub mod client;
+pub mod direct_messages;
+pub mod directory;
+pub mod ignored_users;
+pub mod invites;
+pub mod joining;
+pub mod knocking;
+pub mod members;
+pub mod moderation;
+pub mod presence;
+pub mod profile;
+pub mod receipts;
+pub mod reporting;
+pub mod room_list;
+pub mod room_settings;
 pub mod rooms;
+pub mod spaces;
 pub mod status;
 pub mod sync_service;
+pub mod tags;
 pub mod timelines;
+pub mod typing;
 pub mod user_serach;
 
 // Re-export types that the generated code needs
@@ -0,0 +1,267 @@
+use matrix_sdk::{
+    ruma::{
+        events::{
+            room::power_levels::{RoomPowerLevels, RoomPowerLevelsEventContent},
+            MessageLikeEventType, StateEventType, TimelineEventType,
+        },
+        Int, UserId,
+    },
+    Room,
+};
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    matrix::{
+        rooms::{get_room, EventPowerLevel, UserPowerLevel},
+        sync_service::GLOBAL_APP,
+    },
+};
+
+/// The power levels of a room, with the defaults filled in.
+pub struct RoomPowerLevelsInfo {
+    pub ban: i64,
+    pub invite: i64,
+    pub kick: i64,
+    pub redact: i64,
+    pub events_default: i64,
+    pub state_default: i64,
+    pub users_default: i64,
+    pub events: Vec<EventPowerLevel>,
+    pub users: Vec<UserPowerLevel>,
+}
+
+/// An action whose required power level can be changed.
+pub enum PowerLevelAction {
+    Ban,
+    Invite,
+    Kick,
+    Redact,
+    EventsDefault,
+    StateDefault,
+    UsersDefault,
+    /// Sending a specific event type, e.g. `m.room.name`.
+    Event {
+        event_type: String,
+    },
+}
+
+/// What the current user is allowed to do in a room, to hide the rest in the
+/// UI.
+pub struct RoomPermissions {
+    pub own_power_level: i64,
+    pub can_send_message: bool,
+    pub can_redact_own: bool,
+    pub can_redact_others: bool,
+    pub can_invite: bool,
+    pub can_kick: bool,
+    pub can_ban: bool,
+    /// Change the name, topic and avatar.
+    pub can_change_settings: bool,
+    pub can_change_power_levels: bool,
+}
+
+async fn get_power_levels(room: &Room) -> Result<RoomPowerLevels, String> {
+    room.power_levels().await.map_err(|e| e.to_string())
+}
+
+async fn send_power_levels(room: &Room, power_levels: RoomPowerLevels) -> Result<(), String> {
+    room.send_state_event(RoomPowerLevelsEventContent::from(power_levels))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn invite_user(room_id: String, user_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            room.invite_user_by_id(&user_id)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn kick_user(room_id: String, user_id: String, reason: Option<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            room.kick_user(&user_id, reason.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn ban_user(room_id: String, user_id: String, reason: Option<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            room.ban_user(&user_id, reason.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn unban_user(room_id: String, user_id: String, reason: Option<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            room.unban_user(&user_id, reason.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn get_room_power_levels(room_id: String) -> Result<RoomPowerLevelsInfo, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let power_levels = get_power_levels(room).await?;
+
+            Ok(RoomPowerLevelsInfo {
+                ban: power_levels.ban.into(),
+                invite: power_levels.invite.into(),
+                kick: power_levels.kick.into(),
+                redact: power_levels.redact.into(),
+                events_default: power_levels.events_default.into(),
+                state_default: power_levels.state_default.into(),
+                users_default: power_levels.users_default.into(),
+                events: power_levels
+                    .events
+                    .iter()
+                    .map(|(event_type, power_level)| EventPowerLevel {
+                        event_type: event_type.to_string(),
+                        power_level: (*power_level).into(),
+                    })
+                    .collect(),
+                users: power_levels
+                    .users
+                    .iter()
+                    .map(|(user_id, power_level)| UserPowerLevel {
+                        user_id: user_id.to_string(),
+                        power_level: (*power_level).into(),
+                    })
+                    .collect(),
+            })
+        })
+    })
+}
+
+/// Set the power level of `user_id`; setting it to the users default removes
+/// the user from the list.
+pub fn set_user_power_level(
+    room_id: String,
+    user_id: String,
+    power_level: i64,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            let power_level = Int::new(power_level).ok_or("Invalid power level")?;
+
+            let mut power_levels = get_power_levels(&room).await?;
+            if power_level == power_levels.users_default {
+                power_levels.users.remove(&user_id);
+            } else {
+                power_levels.users.insert(user_id, power_level);
+            }
+            send_power_levels(&room, power_levels).await
+        })
+    })
+}
+
+/// Set the power level needed for `action`.
+pub fn set_action_power_level(
+    room_id: String,
+    action: PowerLevelAction,
+    power_level: i64,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let power_level = Int::new(power_level).ok_or("Invalid power level")?;
+
+            let mut power_levels = get_power_levels(room).await?;
+            match action {
+                PowerLevelAction::Ban => power_levels.ban = power_level,
+                PowerLevelAction::Invite => power_levels.invite = power_level,
+                PowerLevelAction::Kick => power_levels.kick = power_level,
+                PowerLevelAction::Redact => power_levels.redact = power_level,
+                PowerLevelAction::EventsDefault => power_levels.events_default = power_level,
+                PowerLevelAction::StateDefault => power_levels.state_default = power_level,
+                PowerLevelAction::UsersDefault => power_levels.users_default = power_level,
+                PowerLevelAction::Event { event_type } => {
+                    power_levels
+                        .events
+                        .insert(TimelineEventType::from(event_type), power_level);
+                }
+            }
+            send_power_levels(room, power_levels).await
+        })
+    })
+}
+
+pub fn get_room_permissions(room_id: String) -> Result<RoomPermissions, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let room = &get_room(&room_id)?;
+            let user_id = app.client.user_id().ok_or("Client not logged in")?;
+            let power_levels = get_power_levels(room).await?;
+
+            let can_change_settings = [
+                StateEventType::RoomName,
+                StateEventType::RoomTopic,
+                StateEventType::RoomAvatar,
+            ]
+            .into_iter()
+            .all(|event_type| power_levels.user_can_send_state(user_id, event_type));
+
+            Ok(RoomPermissions {
+                own_power_level: power_levels.for_user(user_id).into(),
+                can_send_message: power_levels
+                    .user_can_send_message(user_id, MessageLikeEventType::RoomMessage),
+                can_redact_own: power_levels.user_can_redact_own_event(user_id),
+                can_redact_others: power_levels.user_can_redact_event_of_other(user_id),
+                can_invite: power_levels.user_can_invite(user_id),
+                can_kick: power_levels.user_can_kick(user_id),
+                can_ban: power_levels.user_can_ban(user_id),
+                can_change_settings,
+                can_change_power_levels: power_levels
+                    .user_can_send_state(user_id, StateEventType::RoomPowerLevels),
+            })
+        })
+    })
+}
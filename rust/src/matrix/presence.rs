@@ -0,0 +1,237 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use matrix_sdk::{
+    ruma::{
+        api::client::presence::{get_presence, set_presence},
+        events::presence::PresenceEvent,
+        presence::PresenceState,
+        OwnedUserId, UserId,
+    },
+    Client,
+};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    frb_generated::StreamSink,
+    matrix::sync_service::GLOBAL_APP,
+};
+
+/// The sliding sync doesn't carry presence, so subscriptions also poll it.
+const PRESENCE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(0);
+/// Stops the running `subscribe_to_presence` calls, by subscription ID.
+static PRESENCE_SUBSCRIPTIONS: Mutex<BTreeMap<u64, oneshot::Sender<()>>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceKind {
+    Online,
+    Unavailable,
+    Offline,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserPresence {
+    pub user_id: String,
+    pub presence: PresenceKind,
+    /// When the user was last active, in milliseconds since the epoch.
+    pub last_active: Option<u64>,
+    pub currently_active: Option<bool>,
+    pub status_message: Option<String>,
+}
+
+impl UserPresence {
+    /// Whether the presence changed in a way worth telling the UI about;
+    /// `last_active` moves with every poll so it's left out.
+    fn differs_from(&self, other: &UserPresence) -> bool {
+        self.presence != other.presence
+            || self.currently_active != other.currently_active
+            || self.status_message != other.status_message
+    }
+}
+
+fn get_presence_kind(presence: &PresenceState) -> PresenceKind {
+    match presence {
+        PresenceState::Online => PresenceKind::Online,
+        PresenceState::Unavailable => PresenceKind::Unavailable,
+        _ => PresenceKind::Offline,
+    }
+}
+
+fn get_last_active(last_active_ago: Option<Duration>) -> Option<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+    Some(now.checked_sub(last_active_ago?)?.as_millis() as u64)
+}
+
+async fn fetch_presence(client: &Client, user_id: &UserId) -> Result<UserPresence, String> {
+    let response = client
+        .send(get_presence::v3::Request::new(user_id.to_owned()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(UserPresence {
+        user_id: user_id.to_string(),
+        presence: get_presence_kind(&response.presence),
+        last_active: get_last_active(response.last_active_ago),
+        currently_active: response.currently_active,
+        status_message: response.status_msg,
+    })
+}
+
+pub fn get_user_presence(user_id: String) -> Result<UserPresence, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+            fetch_presence(&app.client, &user_id).await
+        })
+    })
+}
+
+/// Allocate the ID of a presence subscription, to pass to
+/// [`subscribe_to_presence`] and later to [`unsubscribe_from_presence`].
+pub fn new_presence_subscription_id() -> u64 {
+    NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Stream the presence of `user_ids`, e.g. our DM partners. Every item is the
+/// presence of one user, sent once at first and then when it changes.
+///
+/// The subscription polls the server until [`unsubscribe_from_presence`] is
+/// called with `subscription_id`, or until sending an update fails because
+/// the stream was closed on the Dart side.
+pub async fn subscribe_to_presence(
+    stream: StreamSink<UserPresence>,
+    user_ids: Vec<String>,
+    subscription_id: u64,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let user_ids = user_ids
+        .iter()
+        .map(|user_id| UserId::parse(user_id).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<OwnedUserId>, _>>()?;
+
+    let (stop_sender, mut stop_receiver) = oneshot::channel();
+    {
+        let mut subscriptions = PRESENCE_SUBSCRIPTIONS.lock().unwrap();
+        if subscriptions.contains_key(&subscription_id) {
+            return Err("Subscription ID already in use".to_string());
+        }
+        subscriptions.insert(subscription_id, stop_sender);
+    }
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let handle = app.client.add_event_handler(move |event: PresenceEvent| {
+        let sender = sender.clone();
+        async move {
+            let _ = sender.send(UserPresence {
+                user_id: event.sender.to_string(),
+                presence: get_presence_kind(&event.content.presence),
+                last_active: get_last_active(
+                    event
+                        .content
+                        .last_active_ago
+                        .map(|ago| Duration::from_millis(ago.into())),
+                ),
+                currently_active: event.content.currently_active,
+                status_message: event.content.status_msg,
+            });
+        }
+    });
+    let _guard = app.client.event_handler_drop_guard(handle);
+
+    let mut last_sent: HashMap<String, UserPresence> = HashMap::new();
+    let mut poll = tokio::time::interval(PRESENCE_POLL_INTERVAL);
+
+    'poll: loop {
+        let updates = tokio::select! {
+            _ = &mut stop_receiver => break 'poll,
+            Some(presence) = receiver.recv() => vec![presence],
+            _ = poll.tick() => {
+                let mut updates = Vec::with_capacity(user_ids.len());
+                for user_id in &user_ids {
+                    match fetch_presence(&app.client, user_id).await {
+                        Ok(presence) => updates.push(presence),
+                        Err(e) => log_warn(format!("Failed to get presence of {}: {}", user_id, e)),
+                    }
+                }
+                updates
+            }
+        };
+
+        for presence in updates {
+            if !user_ids
+                .iter()
+                .any(|user_id| user_id.as_str() == presence.user_id)
+            {
+                continue;
+            }
+            if last_sent
+                .get(&presence.user_id)
+                .is_some_and(|last| !presence.differs_from(last))
+            {
+                continue;
+            }
+            last_sent.insert(presence.user_id.clone(), presence.clone());
+            if stream.add(presence).is_err() {
+                break 'poll;
+            }
+        }
+    }
+
+    PRESENCE_SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .remove(&subscription_id);
+    Ok(())
+}
+
+/// Stop a presence subscription started by [`subscribe_to_presence`].
+pub fn unsubscribe_from_presence(subscription_id: u64) {
+    if let Some(stop_sender) = PRESENCE_SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .remove(&subscription_id)
+    {
+        let _ = stop_sender.send(());
+    }
+}
+
+/// Set our own presence and status message; `None` clears the message.
+pub fn set_own_presence(
+    presence: PresenceKind,
+    status_message: Option<String>,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = app.client.user_id().ok_or("Client not logged in")?;
+
+            let presence = match presence {
+                PresenceKind::Online => PresenceState::Online,
+                PresenceKind::Unavailable => PresenceState::Unavailable,
+                PresenceKind::Offline => PresenceState::Offline,
+            };
+            let mut request = set_presence::v3::Request::new(user_id.to_owned(), presence);
+            request.status_msg = status_message;
+
+            app.client.send(request).await.map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
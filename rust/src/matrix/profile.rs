@@ -0,0 +1,92 @@
+use matrix_sdk::ruma::UserId;
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    matrix::{sync_service::GLOBAL_APP, user_serach::User},
+};
+
+/// Fetch the global profile of any user, not their per-room one.
+pub fn get_user_profile(user_id: String) -> Result<User, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+
+            let profile = app
+                .client
+                .account()
+                .fetch_user_profile_of(&user_id)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(User {
+                user_id: user_id.to_string(),
+                display_name: profile.displayname,
+                avatar_url: profile.avatar_url.map(|uri| uri.to_string()),
+            })
+        })
+    })
+}
+
+pub fn get_own_profile() -> Result<User, String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let user_id = app.client.user_id().ok_or("Client not logged in")?;
+    get_user_profile(user_id.to_string())
+}
+
+/// Set our display name, `None` removes it.
+pub fn set_display_name(display_name: Option<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            app.client
+                .account()
+                .set_display_name(display_name.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Upload `data` and make it our avatar. `mime_type` is e.g. `image/png`.
+/// Returns the `mxc://` URI of the avatar.
+pub fn set_avatar(data: Vec<u8>, mime_type: String) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let mime_type: mime::Mime = mime_type.parse().map_err(|_| "Invalid mime type")?;
+            let avatar_url = app
+                .client
+                .account()
+                .upload_avatar(&mime_type, data)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(avatar_url.to_string())
+        })
+    })
+}
+
+pub fn remove_avatar() -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            app.client
+                .account()
+                .set_avatar_url(None)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
@@ -0,0 +1,215 @@
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    room::Receipts,
+    ruma::{
+        api::client::receipt::create_receipt::v3::ReceiptType,
+        events::receipt::{ReceiptThread, SyncReceiptEvent},
+        EventId, RoomId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    frb_generated::StreamSink,
+    matrix::{rooms::get_room, sync_service::GLOBAL_APP, timelines::get_or_create_timeline},
+};
+
+/// Someone's public or private read receipt, i.e. how far they have read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadReceipt {
+    pub user_id: String,
+    pub event_id: String,
+    pub timestamp: Option<u64>,
+}
+
+pub enum ReceiptKind {
+    /// `m.read`, visible to the other members.
+    Public,
+    /// `m.read.private`, only visible to us.
+    Private,
+    /// `m.fully_read`, our own read marker.
+    FullyRead,
+}
+
+#[frb(ignore)]
+impl From<ReceiptKind> for ReceiptType {
+    fn from(kind: ReceiptKind) -> Self {
+        match kind {
+            ReceiptKind::Public => ReceiptType::Read,
+            ReceiptKind::Private => ReceiptType::ReadPrivate,
+            ReceiptKind::FullyRead => ReceiptType::FullyRead,
+        }
+    }
+}
+
+/// Send a single receipt or read marker up to `event_id`.
+pub fn send_read_receipt(
+    room_id: String,
+    event_id: String,
+    kind: ReceiptKind,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let event_id = EventId::parse(&event_id).map_err(|e| e.to_string())?;
+
+            room.send_single_receipt(kind.into(), ReceiptThread::Unthreaded, event_id)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Move the `m.fully_read` marker and the read receipt of a room in one
+/// request. Missing event IDs leave the corresponding marker untouched.
+pub fn set_read_markers(
+    room_id: String,
+    fully_read_event_id: Option<String>,
+    read_receipt_event_id: Option<String>,
+    private_read_receipt: bool,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let fully_read = fully_read_event_id
+                .map(|event_id| EventId::parse(&event_id))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            let read_receipt = read_receipt_event_id
+                .map(|event_id| EventId::parse(&event_id))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+
+            let receipts = Receipts::new().fully_read_marker(fully_read);
+            let receipts = if private_read_receipt {
+                receipts.private_read_receipt(read_receipt)
+            } else {
+                receipts.public_read_receipt(read_receipt)
+            };
+
+            room.send_multiple_receipts(receipts)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Mark the whole room as read: send a receipt for the latest event of its
+/// timeline and clear the "marked as unread" flag.
+///
+/// The unread counts in `RoomUpdate` follow through the room update streams.
+pub fn mark_room_as_read(room_id: String, kind: ReceiptKind) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let (timeline, _) = get_or_create_timeline(room_id.as_str()).await?;
+            timeline
+                .mark_as_read(kind.into())
+                .await
+                .map_err(|e| e.to_string())?;
+
+            room.set_unread_flag(false).await.map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Set or clear the `m.marked_unread` flag of a room.
+pub fn set_room_marked_unread(room_id: String, unread: bool) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            room.set_unread_flag(unread)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Our own read receipt in a room, where the unread divider goes. Unlike the
+/// `ReadMarker` timeline item, it is known even when the event isn't loaded.
+pub fn get_own_read_receipt(room_id: String) -> Result<Option<ReadReceipt>, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = app.client.user_id().ok_or("Client not logged in")?;
+            let (timeline, _) = get_or_create_timeline(&room_id).await?;
+
+            Ok(timeline
+                .latest_user_read_receipt(user_id)
+                .await
+                .map(|(event_id, receipt)| ReadReceipt {
+                    user_id: user_id.to_string(),
+                    event_id: event_id.to_string(),
+                    timestamp: receipt.ts.map(|ts| u64::from(ts.get())),
+                }))
+        })
+    })
+}
+
+/// Stream the read receipts of a room as they come in with the sync; every
+/// item holds the receipts that moved, ours included.
+///
+/// The receipts of loaded messages are also updated in `Message::read_receipts`
+/// through the timeline streams.
+pub async fn subscribe_to_read_receipts(
+    stream: StreamSink<Vec<ReadReceipt>>,
+    room_id: String,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let handle = app
+        .client
+        .add_room_event_handler(&room_id, move |event: SyncReceiptEvent| {
+            let sender = sender.clone();
+            async move {
+                let mut receipts = Vec::new();
+                for (event_id, by_type) in event.content.0 {
+                    // Both `m.read` and `m.read.private`, we only get our own
+                    // private ones.
+                    for (_, by_user) in by_type {
+                        for (user_id, receipt) in by_user {
+                            receipts.push(ReadReceipt {
+                                user_id: user_id.to_string(),
+                                event_id: event_id.to_string(),
+                                timestamp: receipt.ts.map(|ts| u64::from(ts.get())),
+                            });
+                        }
+                    }
+                }
+                let _ = sender.send(receipts);
+            }
+        });
+    let _guard = app.client.event_handler_drop_guard(handle);
+
+    while let Some(receipts) = receiver.recv().await {
+        if receipts.is_empty() {
+            continue;
+        }
+        if stream.add(receipts).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use matrix_sdk::{
+    room::ReportedContentScore,
+    ruma::{api::client::reporting::report_user, EventId, UserId},
+};
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    matrix::{rooms::get_room, sync_service::GLOBAL_APP},
+};
+
+/// Report an event, e.g. a message from the timeline, to the homeserver
+/// admins. `score` goes from -100 for the most offensive to 0 for inoffensive.
+pub fn report_event(
+    room_id: String,
+    event_id: String,
+    reason: Option<String>,
+    score: Option<i8>,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            let event_id = EventId::parse(&event_id).map_err(|e| e.to_string())?;
+
+            let score = score
+                .map(|score| {
+                    ReportedContentScore::new(score).ok_or("Score must be between -100 and 0")
+                })
+                .transpose()?;
+
+            room.report_content(event_id, score, reason)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+/// Report a whole room to the homeserver admins.
+pub fn report_room(room_id: String, reason: Option<String>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            room.report_room(reason).await.map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+/// Report a user to the homeserver admins.
+pub fn report_user(user_id: String, reason: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
+
+            let request = report_user::v3::Request::new(user_id, reason);
+            app.client.send(request).await.map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
@@ -0,0 +1,375 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use flutter_rust_bridge::frb;
+use futures::{pin_mut, StreamExt};
+use matrix_sdk::{ruma::RoomId, Room};
+use matrix_sdk_ui::{
+    eyeball_im::VectorDiff,
+    room_list_service::{
+        filters::{
+            new_filter_all, new_filter_any, new_filter_category, new_filter_deduplicate_versions,
+            new_filter_favourite, new_filter_fuzzy_match_room_name, new_filter_invite,
+            new_filter_joined, new_filter_non_left, new_filter_none,
+            new_filter_normalized_match_room_name, new_filter_not, new_filter_unread,
+            BoxedFilterFn, RoomCategory,
+        },
+        RoomListDynamicEntriesController,
+    },
+};
+
+use crate::{
+    api::logger::{log_debug, log_warn},
+    frb_generated::StreamSink,
+    matrix::{
+        rooms::{get_room_update_data, RoomInfos, RoomUpdate},
+        sync_service::GLOBAL_APP,
+    },
+};
+
+/// Number of rooms per page when the caller doesn't pick one.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A room list subscribed to with [`subscribe_to_room_list`].
+#[frb(ignore)]
+struct RoomListSubscription {
+    /// Used to change the filter and paging of the list afterwards.
+    controller: RoomListDynamicEntriesController,
+    /// The current filter, applied again when the data it reads changes.
+    filter: RoomListFilterKind,
+}
+
+/// Room lists by subscription ID, removed once their stream ends.
+#[frb(ignore)]
+static ROOM_LISTS: Mutex<BTreeMap<u64, RoomListSubscription>> = Mutex::new(BTreeMap::new());
+
+#[derive(Clone)]
+pub enum RoomListFilterCategory {
+    Group,
+    People,
+}
+
+/// Filters applied to the room list, they can be combined with `All` and
+/// `Any`.
+#[derive(Clone)]
+pub enum RoomListFilterKind {
+    All {
+        filters: Vec<RoomListFilterKind>,
+    },
+    Any {
+        filters: Vec<RoomListFilterKind>,
+    },
+    NonSpace,
+    NonLeft,
+    Joined,
+    Unread,
+    Favourite,
+    LowPriority,
+    NonLowPriority,
+    Invite,
+    Category {
+        expect: RoomListFilterCategory,
+    },
+    None,
+    NormalizedMatchRoomName {
+        pattern: String,
+    },
+    FuzzyMatchRoomName {
+        pattern: String,
+    },
+    DeduplicateVersions,
+    /// Rooms carrying the given `m.tag`, e.g. a custom `u.work` tag.
+    Tag {
+        name: String,
+    },
+    /// Children of the given space, see `spaces::get_joined_spaces`.
+    Space {
+        space_id: String,
+    },
+}
+
+#[frb(ignore)]
+impl RoomListFilterKind {
+    /// Whether the filter reads the tags or space children cached in
+    /// `RoomInfos`, which the SDK doesn't notify the room list about.
+    fn uses_room_infos(&self) -> bool {
+        match self {
+            Self::All { filters } | Self::Any { filters } => {
+                filters.iter().any(Self::uses_room_infos)
+            }
+            Self::Tag { .. } | Self::Space { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Build the SDK filter. `room_infos` backs the filters needing data the
+    /// SDK doesn't cache on the room, like custom tags.
+    fn into_filter(self, room_infos: &RoomInfos) -> BoxedFilterFn {
+        match self {
+            Self::All { filters } => Box::new(new_filter_all(
+                filters
+                    .into_iter()
+                    .map(|filter| filter.into_filter(room_infos))
+                    .collect(),
+            )),
+            Self::Any { filters } => Box::new(new_filter_any(
+                filters
+                    .into_iter()
+                    .map(|filter| filter.into_filter(room_infos))
+                    .collect(),
+            )),
+            Self::NonSpace => Box::new(|room: &Room| !room.is_space()),
+            Self::NonLeft => Box::new(new_filter_non_left()),
+            Self::Joined => Box::new(new_filter_joined()),
+            Self::Unread => Box::new(new_filter_unread()),
+            Self::Favourite => Box::new(new_filter_favourite()),
+            Self::LowPriority => Box::new(|room: &Room| room.is_low_priority()),
+            Self::NonLowPriority => Box::new(new_filter_not(Box::new(|room: &Room| {
+                room.is_low_priority()
+            }))),
+            Self::Invite => Box::new(new_filter_invite()),
+            Self::Category { expect } => Box::new(new_filter_category(match expect {
+                RoomListFilterCategory::Group => RoomCategory::Group,
+                RoomListFilterCategory::People => RoomCategory::People,
+            })),
+            Self::None => Box::new(new_filter_none()),
+            Self::NormalizedMatchRoomName { pattern } => {
+                Box::new(new_filter_normalized_match_room_name(&pattern))
+            }
+            Self::FuzzyMatchRoomName { pattern } => {
+                Box::new(new_filter_fuzzy_match_room_name(&pattern))
+            }
+            Self::DeduplicateVersions => Box::new(new_filter_deduplicate_versions()),
+            Self::Tag { name } => {
+                let room_infos = room_infos.clone();
+                Box::new(move |room: &Room| {
+                    room_infos
+                        .lock()
+                        .unwrap()
+                        .get(room.room_id())
+                        .is_some_and(|info| info.tags.contains(&name))
+                })
+            }
+            Self::Space { space_id } => {
+                let Ok(space_id) = RoomId::parse(&space_id) else {
+                    log_warn(format!(
+                        "Invalid space ID in room list filter: {}",
+                        space_id
+                    ));
+                    return Box::new(new_filter_none());
+                };
+                let room_infos = room_infos.clone();
+                Box::new(move |room: &Room| {
+                    room_infos
+                        .lock()
+                        .unwrap()
+                        .get(&space_id)
+                        .is_some_and(|info| {
+                            info.space_children.iter().any(|id| id == room.room_id())
+                        })
+                })
+            }
+        }
+    }
+}
+
+pub enum RoomListUpdateType {
+    Reset,
+    Truncate,
+    Remove,
+    Set,
+    Insert,
+    PopBack,
+    PopFront,
+    PushBack,
+    PushFront,
+    Clear,
+    Append,
+}
+
+/// A change of the room list, mirroring the SDK's `VectorDiff`s so the indices
+/// match the SDK's ordering.
+pub struct RoomListUpdate {
+    pub update_type: RoomListUpdateType,
+    pub rooms: Option<Vec<RoomUpdate>>,
+    pub index: Option<usize>,
+    pub length: Option<usize>,
+}
+
+#[frb(ignore)]
+pub async fn get_room_list_update_from_diff(diff: VectorDiff<Room>) -> RoomListUpdate {
+    async fn rooms_from(rooms: impl IntoIterator<Item = Room>) -> Vec<RoomUpdate> {
+        let mut updates = Vec::new();
+        for room in rooms {
+            updates.push(get_room_update_data(&room).await);
+        }
+        updates
+    }
+
+    let (update_type, rooms, index, length) = match diff {
+        VectorDiff::Append { values } => (
+            RoomListUpdateType::Append,
+            Some(rooms_from(values).await),
+            None,
+            None,
+        ),
+        VectorDiff::Clear => (RoomListUpdateType::Clear, None, None, None),
+        VectorDiff::PushFront { value } => (
+            RoomListUpdateType::PushFront,
+            Some(rooms_from([value]).await),
+            None,
+            None,
+        ),
+        VectorDiff::PushBack { value } => (
+            RoomListUpdateType::PushBack,
+            Some(rooms_from([value]).await),
+            None,
+            None,
+        ),
+        VectorDiff::PopFront => (RoomListUpdateType::PopFront, None, None, None),
+        VectorDiff::PopBack => (RoomListUpdateType::PopBack, None, None, None),
+        VectorDiff::Insert { index, value } => (
+            RoomListUpdateType::Insert,
+            Some(rooms_from([value]).await),
+            Some(index),
+            None,
+        ),
+        VectorDiff::Set { index, value } => (
+            RoomListUpdateType::Set,
+            Some(rooms_from([value]).await),
+            Some(index),
+            None,
+        ),
+        VectorDiff::Remove { index } => (RoomListUpdateType::Remove, None, Some(index), None),
+        VectorDiff::Truncate { length } => (RoomListUpdateType::Truncate, None, None, Some(length)),
+        VectorDiff::Reset { values } => (
+            RoomListUpdateType::Reset,
+            Some(rooms_from(values).await),
+            None,
+            None,
+        ),
+    };
+
+    RoomListUpdate {
+        update_type,
+        rooms,
+        index,
+        length,
+    }
+}
+
+/// Allocate the ID of a room list subscription, to pass to
+/// [`subscribe_to_room_list`] and to the functions driving that list.
+pub fn new_room_list_subscription_id() -> u64 {
+    NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Subscribe to the room list, filtered by `filter` and loaded `page_size`
+/// rooms at a time.
+///
+/// Rooms are sorted by recency (latest activity first, then by name), exactly
+/// like in the SDK. Each item of the stream is the batch of updates of one
+/// room list change. Use [`set_room_list_filter`], [`room_list_load_next_page`]
+/// and [`room_list_reset_to_one_page`] with the same `subscription_id` to
+/// drive the list afterwards.
+pub async fn subscribe_to_room_list(
+    stream: StreamSink<Vec<RoomListUpdate>>,
+    subscription_id: u64,
+    filter: RoomListFilterKind,
+    page_size: Option<u32>,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let all_rooms = app
+        .sync_service
+        .room_list_service()
+        .all_rooms()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let (entries, controller) =
+        all_rooms.entries_with_dynamic_adapters(page_size.unwrap_or(DEFAULT_PAGE_SIZE) as usize);
+    controller.set_filter(filter.clone().into_filter(&app.room_list.room_infos));
+    ROOM_LISTS
+        .lock()
+        .unwrap()
+        .insert(subscription_id, RoomListSubscription { controller, filter });
+
+    pin_mut!(entries);
+
+    while let Some(diffs) = entries.next().await {
+        let mut updates = Vec::with_capacity(diffs.len());
+        for diff in diffs {
+            updates.push(get_room_list_update_from_diff(diff).await);
+        }
+
+        log_debug(format!("Sending {} room list updates", updates.len()));
+        if stream.add(updates).is_err() {
+            break;
+        }
+    }
+
+    ROOM_LISTS.lock().unwrap().remove(&subscription_id);
+    Ok(())
+}
+
+/// Replace the filter of a subscribed room list.
+pub fn set_room_list_filter(
+    subscription_id: u64,
+    filter: RoomListFilterKind,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let mut room_lists = ROOM_LISTS.lock().unwrap();
+    let room_list = room_lists
+        .get_mut(&subscription_id)
+        .ok_or("Room list not subscribed")?;
+    if !room_list
+        .controller
+        .set_filter(filter.clone().into_filter(&app.room_list.room_infos))
+    {
+        log_warn("Room list filter wasn't applied".to_string());
+    }
+    room_list.filter = filter;
+    Ok(())
+}
+
+/// Apply the filters reading `room_infos` again, after the tags or space
+/// children cached there changed.
+#[frb(ignore)]
+pub fn refresh_room_list_filters(room_infos: &RoomInfos) {
+    for room_list in ROOM_LISTS.lock().unwrap().values() {
+        if room_list.filter.uses_room_infos()
+            && !room_list
+                .controller
+                .set_filter(room_list.filter.clone().into_filter(room_infos))
+        {
+            log_warn("Room list filter wasn't applied".to_string());
+        }
+    }
+}
+
+/// Load one more page of rooms, for infinite scrolling.
+pub fn room_list_load_next_page(subscription_id: u64) -> Result<(), String> {
+    let room_lists = ROOM_LISTS.lock().unwrap();
+    let room_list = room_lists
+        .get(&subscription_id)
+        .ok_or("Room list not subscribed")?;
+    room_list.controller.add_one_page();
+    Ok(())
+}
+
+/// Shrink the room list back to its first page.
+pub fn room_list_reset_to_one_page(subscription_id: u64) -> Result<(), String> {
+    let room_lists = ROOM_LISTS.lock().unwrap();
+    let room_list = room_lists
+        .get(&subscription_id)
+        .ok_or("Room list not subscribed")?;
+    room_list.controller.reset_to_one_page();
+    Ok(())
+}
@@ -0,0 +1,219 @@
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    ruma::{
+        api::client::room::upgrade_room,
+        events::room::{
+            history_visibility::HistoryVisibility,
+            join_rules::{AllowRule, JoinRule, Restricted},
+        },
+        RoomId, RoomVersionId,
+    },
+    Room,
+};
+
+use crate::{
+    api::platform::GLOBAL_RUNTIME,
+    matrix::{
+        rooms::{get_room, RoomHistoryVisibility},
+        sync_service::GLOBAL_APP,
+    },
+};
+
+/// Who can join a room.
+pub enum RoomJoinRule {
+    Public,
+    Invite,
+    Knock,
+    /// Members of one of `allowed_room_ids` can join without an invite.
+    Restricted {
+        allowed_room_ids: Vec<String>,
+    },
+    /// Like `Restricted`, everyone else can knock.
+    KnockRestricted {
+        allowed_room_ids: Vec<String>,
+    },
+}
+
+#[frb(ignore)]
+pub fn get_room_history_visibility(room: &Room) -> Option<RoomHistoryVisibility> {
+    match room.history_visibility()? {
+        HistoryVisibility::Invited => Some(RoomHistoryVisibility::Invited),
+        HistoryVisibility::Joined => Some(RoomHistoryVisibility::Joined),
+        HistoryVisibility::Shared => Some(RoomHistoryVisibility::Shared),
+        HistoryVisibility::WorldReadable => Some(RoomHistoryVisibility::WorldReadable),
+        _ => None,
+    }
+}
+
+fn get_allowed_room_ids(allow: &[AllowRule]) -> Vec<String> {
+    allow
+        .iter()
+        .filter_map(|rule| match rule {
+            AllowRule::RoomMembership(membership) => Some(membership.room_id.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[frb(ignore)]
+pub fn get_room_join_rule(room: &Room) -> Option<RoomJoinRule> {
+    match room.join_rule()? {
+        JoinRule::Public => Some(RoomJoinRule::Public),
+        JoinRule::Invite => Some(RoomJoinRule::Invite),
+        JoinRule::Knock => Some(RoomJoinRule::Knock),
+        JoinRule::Restricted(restricted) => Some(RoomJoinRule::Restricted {
+            allowed_room_ids: get_allowed_room_ids(&restricted.allow),
+        }),
+        JoinRule::KnockRestricted(restricted) => Some(RoomJoinRule::KnockRestricted {
+            allowed_room_ids: get_allowed_room_ids(&restricted.allow),
+        }),
+        _ => None,
+    }
+}
+
+fn parse_allowed_rooms(room_ids: Vec<String>) -> Result<Vec<AllowRule>, String> {
+    room_ids
+        .iter()
+        .map(|room_id| {
+            RoomId::parse(room_id)
+                .map(AllowRule::room_membership)
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+pub fn set_room_name(room_id: String, name: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            room.set_name(name).await.map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+pub fn set_room_topic(room_id: String, topic: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            room.set_room_topic(&topic)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+/// Upload `data` and make it the room avatar. `mime_type` is e.g. `image/png`.
+pub fn set_room_avatar(room_id: String, data: Vec<u8>, mime_type: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let mime_type: mime::Mime = mime_type.parse().map_err(|_| "Invalid mime type")?;
+            room.upload_avatar(&mime_type, data, None)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+pub fn remove_room_avatar(room_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            room.remove_avatar().await.map_err(|e| e.to_string())?;
+            Ok(())
+        })
+    })
+}
+
+/// Turn on end-to-end encryption. It can't be turned off again.
+pub fn enable_room_encryption(room_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            room.enable_encryption().await.map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Change who can read the history; only applies to messages sent afterwards.
+pub fn set_room_history_visibility(
+    room_id: String,
+    history_visibility: RoomHistoryVisibility,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            room.privacy_settings()
+                .update_room_history_visibility(history_visibility.into())
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn set_room_join_rule(room_id: String, join_rule: RoomJoinRule) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let join_rule = match join_rule {
+                RoomJoinRule::Public => JoinRule::Public,
+                RoomJoinRule::Invite => JoinRule::Invite,
+                RoomJoinRule::Knock => JoinRule::Knock,
+                RoomJoinRule::Restricted { allowed_room_ids } => {
+                    JoinRule::Restricted(Restricted::new(parse_allowed_rooms(allowed_room_ids)?))
+                }
+                RoomJoinRule::KnockRestricted { allowed_room_ids } => JoinRule::KnockRestricted(
+                    Restricted::new(parse_allowed_rooms(allowed_room_ids)?),
+                ),
+            };
+            room.privacy_settings()
+                .update_join_rule(join_rule)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Upgrade the room to `new_version`. The server creates the new room and
+/// tombstones this one; returns the ID of the new room.
+pub fn upgrade_room(room_id: String, new_version: String) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let room = get_room(&room_id)?;
+            let new_version =
+                RoomVersionId::try_from(new_version.as_str()).map_err(|e| e.to_string())?;
+
+            let request = upgrade_room::v3::Request::new(room.room_id().to_owned(), new_version);
+            let response = app.client.send(request).await.map_err(|e| e.to_string())?;
+            Ok(response.replacement_room.to_string())
+        })
+    })
+}
@@ -4,22 +4,48 @@ use flutter_rust_bridge::frb;
 
 use imbl::Vector;
 use matrix_sdk::{
-    ruma::{OwnedRoomId, RoomId},
+    ruma::{
+        api::client::room::{
+            create_room::v3::{CreationContent, Request as CreateRoomRequest, RoomPreset},
+            Visibility,
+        },
+        events::{
+            room::{
+                avatar::RoomAvatarEventContent,
+                encryption::RoomEncryptionEventContent,
+                guest_access::{GuestAccess, RoomGuestAccessEventContent},
+                history_visibility::{HistoryVisibility, RoomHistoryVisibilityEventContent},
+                join_rules::{JoinRule, RoomJoinRulesEventContent},
+                power_levels::RoomPowerLevelsEventContent,
+            },
+            space::{child::SpaceChildEventContent, parent::SpaceParentEventContent},
+            AnySyncMessageLikeEvent, AnySyncTimelineEvent, InitialStateEvent,
+            SyncMessageLikeEvent,
+        },
+        room::RoomType,
+        serde::Raw,
+        OwnedMxcUri, OwnedRoomId, RoomId, RoomVersionId, UserId,
+    },
     Client, Room, RoomState,
 };
-use matrix_sdk_ui::{sync_service::SyncService, timeline::RoomExt};
+use matrix_sdk_ui::{eyeball_im::VectorDiff, sync_service::SyncService};
 use std::sync::Mutex;
+use tokio::sync::broadcast::{self, error::RecvError};
 
 use crate::{
     api::{
-        logger::{log_error, log_info, log_warn},
+        logger::{log_error, log_warn},
         platform::GLOBAL_RUNTIME,
     },
     frb_generated::StreamSink,
     matrix::{
+        direct_messages::join_and_mark_dm,
+        room_list::{get_room_list_update_from_diff, RoomListUpdate},
+        room_settings::{get_room_history_visibility, get_room_join_rule, RoomJoinRule},
         status::StatusHandle,
         sync_service::GLOBAL_APP,
-        timelines::{get_message_from_timeline_item, Message, MessageType},
+        tags::{get_room_tags, RoomTag},
+        timelines::{Message, MessageType},
     },
 };
 
@@ -27,6 +53,10 @@ use crate::{
 
 pub type Rooms = Arc<Mutex<Vector<Room>>>;
 
+/// Diffs applied to [`Rooms`], in the order of the room list service.
+#[frb(ignore)]
+pub type RoomDiffs = broadcast::Sender<Vec<VectorDiff<Room>>>;
+
 #[derive(Clone)]
 #[frb(ignore)]
 pub struct RoomList {
@@ -34,10 +64,13 @@ pub struct RoomList {
 
     pub rooms: Rooms,
 
+    /// Sends every batch of diffs applied to `rooms`, while `rooms` is locked.
+    pub diffs: RoomDiffs,
+
     client: Client,
 
     /// Extra information about rooms.
-    room_infos: RoomInfos,
+    pub room_infos: RoomInfos,
 
     /// The current room that's subscribed to in the room list's sliding sync.
     current_room_subscription: Option<Room>,
@@ -50,6 +83,7 @@ impl RoomList {
     pub fn new(
         client: Client,
         rooms: Rooms,
+        diffs: RoomDiffs,
         room_infos: RoomInfos,
         sync_service: Arc<SyncService>,
         status_handle: StatusHandle,
@@ -57,6 +91,7 @@ impl RoomList {
         Self {
             client,
             rooms,
+            diffs,
             status_handle,
             room_infos,
             current_room_subscription: None,
@@ -86,6 +121,12 @@ pub struct ExtraRoomInfo {
 
     /// Is the room a DM?
     pub is_dm: Option<bool>,
+
+    /// Names of the room's tags.
+    pub tags: Vec<String>,
+
+    /// Rooms listed in the space's `m.space.child` events, empty for rooms.
+    pub space_children: Vec<OwnedRoomId>,
 }
 
 pub enum UpdateType {
@@ -106,14 +147,57 @@ pub struct RoomUpdate {
     pub unread_highlight: Option<u64>,
     pub unread_mentions: Option<u64>,
     pub unread_messages: Option<u64>,
+    pub is_marked_unread: bool,
     pub message: Option<Message>,
+    pub tags: Vec<RoomTag>,
+    pub topic: Option<String>,
+    pub avatar_url: Option<String>,
+    pub canonical_alias: Option<String>,
+    /// Is end-to-end encryption enabled? `false` while the encryption state
+    /// hasn't been synced yet.
+    pub is_encrypted: bool,
+    pub history_visibility: Option<RoomHistoryVisibility>,
+    pub join_rule: Option<RoomJoinRule>,
+    /// Set once the room was upgraded, the UI should offer to join it.
+    pub successor: Option<RoomSuccessor>,
+    /// The room this one was upgraded from.
+    pub predecessor_room_id: Option<String>,
 }
 
-async fn get_room_update_data(room: &Room) -> RoomUpdate {
+pub struct RoomSuccessor {
+    pub room_id: String,
+    pub reason: Option<String>,
+}
+
+/// Build the last message of a room from the SDK's cached latest event,
+/// without touching the network or a timeline.
+fn get_latest_message(room: &Room) -> Option<Message> {
+    let latest_event = room.latest_event()?;
+    let event = latest_event.event().raw().deserialize().ok()?;
+
+    let content = match &event {
+        AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomMessage(
+            SyncMessageLikeEvent::Original(message),
+        )) => message.content.body().to_owned(),
+        _ => "".to_string(),
+    };
+
+    Some(Message {
+        event_id: event.event_id().to_string(),
+        sender: event.sender().to_string(),
+        content,
+        timestamp: u64::from(event.origin_server_ts().0),
+        message_type: MessageType::Message,
+        read_receipts: Vec::new(),
+    })
+}
+
+#[frb(ignore)]
+pub async fn get_room_update_data(room: &Room) -> RoomUpdate {
     let room_id = room.room_id().to_string();
     let raw_name = room.name().map(|name| name.to_string());
     let display_name = room.cached_display_name().map(|name| name.to_string());
-    let is_dm = room.is_direct().await.map_err(|e| false).unwrap();
+    let is_dm = room.is_direct().await.unwrap_or(false);
     let unread_notification_count = room.unread_notification_counts().notification_count;
     let unread_highlight_count = room.unread_notification_counts().highlight_count;
     let unread_mentions_count = room.num_unread_mentions();
@@ -126,36 +210,6 @@ async fn get_room_update_data(room: &Room) -> RoomUpdate {
         RoomState::Left => UpdateType::Left,
     };
 
-    let last_event = room.latest_event_item().await;
-    let mut message = Message {
-        event_id: "".to_string(),
-        sender: "".to_string(),
-        content: "".to_string(),
-        timestamp: 0,
-        message_type: MessageType::Message,
-    };
-    if last_event.is_some() {
-        let last_event = last_event.unwrap();
-        let id = &last_event.event_id().expect("").to_string();
-        let mut message_content = "".to_string();
-        let content = &last_event.content();
-        if content.is_message() {
-            let m_c = content.as_message().unwrap().body();
-            message_content = m_c.to_string();
-        }
-        let sender = &last_event.sender().to_string();
-        let timestamp = u64::from(last_event.timestamp().0);
-        message = Message {
-            event_id: id.clone(),
-            content: message_content,
-            message_type: MessageType::Message,
-            sender: sender.clone(),
-            timestamp: timestamp,
-        };
-    } else {
-        log_error(format!("No last event found for room {:?}", room_id));
-    }
-
     return RoomUpdate {
         room_id,
         raw_name,
@@ -166,10 +220,35 @@ async fn get_room_update_data(room: &Room) -> RoomUpdate {
         unread_highlight: Some(unread_highlight_count),
         unread_mentions: Some(unread_mentions_count),
         unread_messages: Some(unread_messages),
-        message: Some(message),
+        is_marked_unread: room.is_marked_unread(),
+        message: get_latest_message(room),
+        tags: get_room_tags(room).await,
+        topic: room.topic(),
+        avatar_url: room.avatar_url().map(|uri| uri.to_string()),
+        canonical_alias: room.canonical_alias().map(|alias| alias.to_string()),
+        is_encrypted: room.encryption_state().is_encrypted(),
+        history_visibility: get_room_history_visibility(room),
+        join_rule: get_room_join_rule(room),
+        successor: room.successor_room().map(|successor| RoomSuccessor {
+            room_id: successor.room_id.to_string(),
+            reason: successor.reason,
+        }),
+        predecessor_room_id: room
+            .predecessor_room()
+            .map(|predecessor| predecessor.room_id.to_string()),
     };
 }
 
+/// Look up a room of the global client by ID.
+#[frb(ignore)]
+pub fn get_room(room_id: &str) -> Result<Room, String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id = RoomId::parse(room_id).map_err(|e| e.to_string())?;
+    app.client
+        .get_room(&room_id)
+        .ok_or("Room not found".to_string())
+}
+
 pub fn get_all_rooms() -> Vec<RoomUpdate> {
     let runtime = GLOBAL_RUNTIME
         .get() // get the runtime
@@ -196,69 +275,53 @@ pub fn get_all_rooms() -> Vec<RoomUpdate> {
     })
 }
 
-pub fn subscribe_to_all_room_updates(stream: StreamSink<RoomUpdate>) {
+/// Stream the room list as diffs, with the same ordering and indices as the
+/// SDK's room list.
+///
+/// The first batch is a `Reset` with the current rooms, the following ones
+/// must be applied on top of it, in order.
+pub fn subscribe_to_all_room_updates(stream: StreamSink<Vec<RoomListUpdate>>) {
     let runtime = GLOBAL_RUNTIME
         .get() // get the runtime
         .expect("No global runtime found");
 
     runtime.block_on(async {
-        match GLOBAL_APP.get() {
-            Some(app) => loop {
-                match &app.client.subscribe_to_all_room_updates().recv().await {
-                    Ok(updates) => {
-                        log_info(format!("Received room update: {:?}", updates));
-                        for room_id in &updates.joined {
-                            match &app.client.get_room(&room_id.0) {
-                                Some(room) => {
-                                    let mut update = get_room_update_data(room).await;
-                                    update.update_type = UpdateType::Joined;
-                                    let _ = stream.add(update);
-                                }
-
-                                None => {
-                                    log_warn(format!("Room not found: {}", room_id.0));
-                                }
-                            }
-                        }
-
-                        for room_id in &updates.invited {
-                            match &app.client.get_room(&room_id.0) {
-                                Some(room) => {
-                                    let mut update = get_room_update_data(room).await;
-                                    update.update_type = UpdateType::Invited;
-                                    let _ = stream.add(update);
-                                }
-
-                                None => {
-                                    log_warn(format!("Room not found: {}", room_id.0));
-                                }
-                            }
-                        }
-
-                        for room_id in &updates.left {
-                            match &app.client.get_room(&room_id.0) {
-                                Some(room) => {
-                                    let mut update = get_room_update_data(room).await;
-                                    update.update_type = UpdateType::Left;
-                                    let _ = stream.add(update);
-                                }
-
-                                None => {
-                                    log_warn(format!("Room not found: {}", room_id.0));
-                                }
-                            }
-                        }
-                        
-                    }
-                    Err(e) => {
-                        log_error(format!("Error receiving room updates: {}", e));
-                    }
-                }
-            },
-            None => {
-                log_error(format!("No global app found"));
-                return;
+        let Some(app) = GLOBAL_APP.get() else {
+            log_error("No global app found".to_string());
+            return;
+        };
+
+        // Snapshot and subscribe under the lock, so no diff is missed or
+        // applied twice.
+        let (rooms, mut receiver) = {
+            let rooms = app.room_list.rooms.lock().unwrap();
+            (rooms.clone(), app.room_list.diffs.subscribe())
+        };
+        let mut diffs = vec![VectorDiff::Reset { values: rooms }];
+
+        loop {
+            let mut updates = Vec::with_capacity(diffs.len());
+            for diff in diffs {
+                updates.push(get_room_list_update_from_diff(diff).await);
             }
+            if stream.add(updates).is_err() {
+                break;
+            }
+
+            diffs = match receiver.recv().await {
+                Ok(diffs) => diffs,
+                Err(RecvError::Lagged(skipped)) => {
+                    log_warn(format!(
+                        "Room list subscriber missed {skipped} updates, resetting"
+                    ));
+                    let rooms = app.room_list.rooms.lock().unwrap();
+                    receiver = app.room_list.diffs.subscribe();
+                    vec![VectorDiff::Reset {
+                        values: rooms.clone(),
+                    }]
+                }
+                Err(RecvError::Closed) => break,
+            };
         }
     });
 }
@@ -269,10 +332,7 @@ pub fn send_message(room_id: String, content: String) -> Result<String, String>
             .get()
             .expect("Global runtime not initialized");
         runtime.block_on(async {
-            let app = GLOBAL_APP.get().expect("Global app not initialized");
-
-            let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-            let room = &app.client.get_room(&room_id).ok_or("Room not found")?;
+            let room = &get_room(&room_id)?;
 
             let event_id = room
                 .send(
@@ -289,6 +349,10 @@ pub fn send_message(room_id: String, content: String) -> Result<String, String>
 }
 
 
+/// Always creates a new DM, see [`get_or_create_dm`] to reuse an existing
+/// one.
+///
+/// [`get_or_create_dm`]: crate::matrix::direct_messages::get_or_create_dm
 pub fn create_direct_room(user_id: String) -> Result<String, String> {
     tokio::task::block_in_place(|| {
         let runtime = GLOBAL_RUNTIME
@@ -296,62 +360,303 @@ pub fn create_direct_room(user_id: String) -> Result<String, String> {
             .expect("Global runtime not initialized");
         runtime.block_on(async {
             let app = GLOBAL_APP.get().expect("Global app not initialized");
-
-            use matrix_sdk::ruma::UserId;
-            use matrix_sdk::ruma::api::client::room::create_room::v3::Request as CreateRoomRequest;
-
             let user_id = UserId::parse(&user_id).map_err(|e| e.to_string())?;
-            
-            let mut request = CreateRoomRequest::new();
-            request.is_direct = true;
-            request.invite = vec![user_id.to_owned()];
-            request.preset = Some(matrix_sdk::ruma::api::client::room::create_room::v3::RoomPreset::TrustedPrivateChat);
-
-            let response = app.client
-                .create_room(request)
+
+            // Also records the room in `m.direct`.
+            let room = app
+                .client
+                .create_dm(&user_id)
                 .await
                 .map_err(|e| e.to_string())?;
 
-            Ok(response.room_id().to_string())
+            Ok(room.room_id().to_string())
         })
     })
 }
 
 pub fn create_group_room(name: String, user_ids: Vec<String>) -> Result<String, String> {
-    tokio::task::block_in_place(|| {
-        let runtime = GLOBAL_RUNTIME
-            .get()
-            .expect("Global runtime not initialized");
-        runtime.block_on(async {
-            let app = GLOBAL_APP.get().expect("Global app not initialized");
+    create_room(CreateRoomParams {
+        name: Some(name),
+        invite: user_ids,
+        ..Default::default()
+    })
+}
 
-            use matrix_sdk::ruma::UserId;
-            use matrix_sdk::ruma::api::client::room::create_room::v3::Request as CreateRoomRequest;
+/// Who can join a room created with [`create_room`].
+#[derive(Default)]
+pub enum RoomVisibility {
+    /// Anyone can join, and the room is published in the room directory.
+    Public,
+    /// Invite only.
+    #[default]
+    Private,
+    /// Anyone can ask to join, see `knocking`.
+    Knock,
+}
 
-            let mut request = CreateRoomRequest::new();
-            request.name = Some(name);
-            request.is_direct = false;
-            request.preset = Some(matrix_sdk::ruma::api::client::room::create_room::v3::RoomPreset::PrivateChat);
+pub enum RoomHistoryVisibility {
+    Invited,
+    Joined,
+    Shared,
+    WorldReadable,
+}
 
-            // Parse and add invited users
-            let mut invites = Vec::new();
-            for user_id_str in user_ids {
-                let user_id = UserId::parse(&user_id_str).map_err(|e| e.to_string())?;
-                invites.push(user_id);
+#[frb(ignore)]
+impl From<RoomHistoryVisibility> for HistoryVisibility {
+    fn from(visibility: RoomHistoryVisibility) -> Self {
+        match visibility {
+            RoomHistoryVisibility::Invited => HistoryVisibility::Invited,
+            RoomHistoryVisibility::Joined => HistoryVisibility::Joined,
+            RoomHistoryVisibility::Shared => HistoryVisibility::Shared,
+            RoomHistoryVisibility::WorldReadable => HistoryVisibility::WorldReadable,
+        }
+    }
+}
+
+pub struct UserPowerLevel {
+    pub user_id: String,
+    pub power_level: i64,
+}
+
+pub struct EventPowerLevel {
+    pub event_type: String,
+    pub power_level: i64,
+}
+
+/// Power levels to change from the server defaults when creating a room; unset
+/// fields keep their default.
+#[derive(Default)]
+pub struct PowerLevelOverrides {
+    pub ban: Option<i64>,
+    pub invite: Option<i64>,
+    pub kick: Option<i64>,
+    pub redact: Option<i64>,
+    pub events_default: Option<i64>,
+    pub state_default: Option<i64>,
+    pub users_default: Option<i64>,
+    pub events: Vec<EventPowerLevel>,
+    pub users: Vec<UserPowerLevel>,
+}
+
+#[derive(Default)]
+pub struct CreateRoomParams {
+    pub name: Option<String>,
+    pub topic: Option<String>,
+    /// `mxc://` URI of an already uploaded avatar.
+    pub avatar_url: Option<String>,
+    /// Local part of the alias to create, e.g. `team` for `#team:example.org`.
+    pub alias: Option<String>,
+    pub visibility: RoomVisibility,
+    pub is_encrypted: bool,
+    pub history_visibility: Option<RoomHistoryVisibility>,
+    /// Whether guests can join, `None` keeps the server's default for the
+    /// preset.
+    pub guest_access: Option<bool>,
+    pub power_level_overrides: Option<PowerLevelOverrides>,
+    pub room_version: Option<String>,
+    /// Space to add the room to, we need to be allowed to add children to it.
+    pub space_parent_id: Option<String>,
+    pub invite: Vec<String>,
+    pub is_direct: bool,
+    /// Create a space instead of a room, see `spaces::create_space`.
+    pub is_space: bool,
+}
+
+fn get_power_levels_override(
+    overrides: PowerLevelOverrides,
+    own_user_id: &UserId,
+) -> Result<Raw<RoomPowerLevelsEventContent>, String> {
+    let mut content = serde_json::Map::new();
+    for (key, value) in [
+        ("ban", overrides.ban),
+        ("invite", overrides.invite),
+        ("kick", overrides.kick),
+        ("redact", overrides.redact),
+        ("events_default", overrides.events_default),
+        ("state_default", overrides.state_default),
+        ("users_default", overrides.users_default),
+    ] {
+        if let Some(value) = value {
+            content.insert(key.to_owned(), value.into());
+        }
+    }
+
+    if !overrides.events.is_empty() {
+        let events: serde_json::Map<_, _> = overrides
+            .events
+            .into_iter()
+            .map(|event| (event.event_type, event.power_level.into()))
+            .collect();
+        content.insert("events".to_owned(), events.into());
+    }
+
+    if !overrides.users.is_empty() {
+        let mut users: serde_json::Map<_, _> = overrides
+            .users
+            .into_iter()
+            .map(|user| (user.user_id, user.power_level.into()))
+            .collect();
+        // The override replaces the whole `users` map, keep the creator admin.
+        users
+            .entry(own_user_id.to_string())
+            .or_insert_with(|| 100.into());
+        content.insert("users".to_owned(), users.into());
+    }
+
+    let json = serde_json::value::to_raw_value(&content).map_err(|e| e.to_string())?;
+    Ok(Raw::from_json(json))
+}
+
+#[frb(ignore)]
+pub async fn create_room_with_params(
+    client: &Client,
+    params: CreateRoomParams,
+) -> Result<OwnedRoomId, String> {
+    let own_user_id = client.user_id().ok_or("Client not logged in")?.to_owned();
+
+    let mut request = CreateRoomRequest::new();
+    request.name = params.name;
+    request.topic = params.topic;
+    request.room_alias_name = params.alias;
+    request.is_direct = params.is_direct;
+    request.invite = params
+        .invite
+        .iter()
+        .map(|user_id| UserId::parse(user_id).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    request.room_version = params
+        .room_version
+        .map(|version| RoomVersionId::try_from(version.as_str()))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    let mut initial_state = Vec::new();
+    match params.visibility {
+        RoomVisibility::Public => {
+            request.preset = Some(RoomPreset::PublicChat);
+            request.visibility = Visibility::Public;
+        }
+        RoomVisibility::Private => {
+            request.preset = Some(if params.is_direct {
+                RoomPreset::TrustedPrivateChat
+            } else {
+                RoomPreset::PrivateChat
+            });
+        }
+        RoomVisibility::Knock => {
+            request.preset = Some(RoomPreset::PrivateChat);
+            initial_state.push(
+                InitialStateEvent::new(RoomJoinRulesEventContent::new(JoinRule::Knock))
+                    .to_raw_any(),
+            );
+        }
+    }
+
+    if params.is_encrypted {
+        initial_state.push(
+            InitialStateEvent::new(RoomEncryptionEventContent::with_recommended_defaults())
+                .to_raw_any(),
+        );
+    }
+    if let Some(history_visibility) = params.history_visibility {
+        initial_state.push(
+            InitialStateEvent::new(RoomHistoryVisibilityEventContent::new(
+                history_visibility.into(),
+            ))
+            .to_raw_any(),
+        );
+    }
+    if let Some(guest_access) = params.guest_access {
+        let guest_access = if guest_access {
+            GuestAccess::CanJoin
+        } else {
+            GuestAccess::Forbidden
+        };
+        initial_state.push(
+            InitialStateEvent::new(RoomGuestAccessEventContent::new(guest_access)).to_raw_any(),
+        );
+    }
+    if let Some(avatar_url) = params.avatar_url {
+        let mut content = RoomAvatarEventContent::new();
+        content.url = Some(OwnedMxcUri::from(avatar_url));
+        initial_state.push(InitialStateEvent::new(content).to_raw_any());
+    }
+
+    let space_parent = params
+        .space_parent_id
+        .map(|space_id| RoomId::parse(&space_id))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let via = vec![own_user_id.server_name().to_owned()];
+    if let Some(space_id) = &space_parent {
+        let mut content = SpaceParentEventContent::new(via.clone());
+        content.canonical = true;
+        initial_state.push(
+            InitialStateEvent {
+                content,
+                state_key: space_id.clone(),
             }
-            request.invite = invites;
+            .to_raw_any(),
+        );
+    }
+    request.initial_state = initial_state;
 
-            let response = app.client
-                .create_room(request)
-                .await
-                .map_err(|e| e.to_string())?;
+    if params.is_space {
+        let mut creation_content = CreationContent::new();
+        creation_content.room_type = Some(RoomType::Space);
+        request.creation_content =
+            Some(Raw::new(&creation_content).map_err(|e| e.to_string())?);
+    }
+
+    if let Some(overrides) = params.power_level_overrides {
+        request.power_level_content_override =
+            Some(get_power_levels_override(overrides, &own_user_id)?);
+    }
+
+    let room = client
+        .create_room(request)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // The parent link alone isn't enough for the space to list the room, it
+    // needs a child event on its side too.
+    if let Some(space_id) = space_parent {
+        match client.get_room(&space_id) {
+            Some(space) => {
+                if let Err(e) = space
+                    .send_state_event_for_key(room.room_id(), SpaceChildEventContent::new(via))
+                    .await
+                {
+                    log_warn(format!(
+                        "Failed to add {} to space {}: {}",
+                        room.room_id(),
+                        space_id,
+                        e
+                    ));
+                }
+            }
+            None => log_warn(format!("Space not found: {}", space_id)),
+        }
+    }
 
-            Ok(response.room_id().to_string())
+    Ok(room.room_id().to_owned())
+}
+
+/// Create a room with all the options of [`CreateRoomParams`]. Returns the ID
+/// of the new room.
+pub fn create_room(params: CreateRoomParams) -> Result<String, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let room_id = create_room_with_params(&app.client, params).await?;
+            Ok(room_id.to_string())
         })
     })
 }
 
-
 pub fn join_room(room_id: String) -> Result<String, String> {
     tokio::task::block_in_place(|| {
         let runtime = GLOBAL_RUNTIME
@@ -360,8 +665,17 @@ pub fn join_room(room_id: String) -> Result<String, String> {
         runtime.block_on(async {
             let app = GLOBAL_APP.get().expect("Global app not initialized");
             let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-            let room = &app.client.get_room(&room_id).ok_or("Room not found")?;
-            let _ = room.join().await.map_err(|e| e.to_string())?;
+            match app.client.get_room(&room_id) {
+                Some(room) => join_and_mark_dm(&room).await?,
+                // Rooms we were never in can still be joined by ID, see
+                // `joining::join_room_by_id_or_alias` to pass via servers.
+                None => {
+                    app.client
+                        .join_room_by_id(&room_id)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                }
+            }
             Ok(room_id.to_string())
         })
     })
@@ -373,9 +687,7 @@ pub fn leave_room(room_id: String) -> Result<String, String> {
             .get()
             .expect("Global runtime not initialized");
         runtime.block_on(async {
-            let app = GLOBAL_APP.get().expect("Global app not initialized");
-            let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-            let room = &app.client.get_room(&room_id).ok_or("Room not found")?;
+            let room = &get_room(&room_id)?;
             let _ = room.leave().await.map_err(|e| e.to_string())?; 
             Ok(room_id.to_string())
         })
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    deserialized_responses::SyncOrStrippedState,
+    ruma::{
+        api::client::space::get_hierarchy,
+        events::{
+            space::{child::SpaceChildEventContent, parent::SpaceParentEventContent},
+            SyncStateEvent,
+        },
+        room::RoomType,
+        OwnedRoomId, OwnedServerName, RoomId, UInt,
+    },
+    Client, Room, RoomState,
+};
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    matrix::{
+        rooms::{create_room, get_room_update_data, CreateRoomParams, RoomUpdate, UpdateType},
+        sync_service::GLOBAL_APP,
+    },
+};
+
+/// A room or subspace in the hierarchy of a space.
+pub struct SpaceHierarchyRoom {
+    pub room_id: String,
+    pub name: Option<String>,
+    pub topic: Option<String>,
+    pub avatar_url: Option<String>,
+    pub canonical_alias: Option<String>,
+    pub num_joined_members: u64,
+    pub is_space: bool,
+    /// Whether the parent space suggests joining this room.
+    pub suggested: bool,
+    /// IDs of the direct children, for subspaces.
+    pub children: Vec<String>,
+    /// Our membership in the room, if we know the room already.
+    pub membership: Option<UpdateType>,
+}
+
+pub struct SpaceHierarchyPage {
+    pub rooms: Vec<SpaceHierarchyRoom>,
+    /// Token to pass as `from` to get the next page, `None` on the last page.
+    pub next_batch: Option<String>,
+}
+
+/// IDs of the rooms `space` lists as children with `m.space.child` events.
+#[frb(ignore)]
+pub async fn get_space_children(space: &Room) -> Vec<OwnedRoomId> {
+    let events = match space
+        .get_state_events_static::<SpaceChildEventContent>()
+        .await
+    {
+        Ok(events) => events,
+        Err(e) => {
+            log_warn(format!(
+                "Failed to get the children of {}: {}",
+                space.room_id(),
+                e
+            ));
+            return Vec::new();
+        }
+    };
+
+    events
+        .into_iter()
+        .filter_map(|event| match event.deserialize().ok()? {
+            // A child event without `via` is a removed child.
+            SyncOrStrippedState::Sync(SyncStateEvent::Original(event))
+                if !event.content.via.is_empty() =>
+            {
+                Some(event.state_key)
+            }
+            SyncOrStrippedState::Stripped(event) if event.content.via.is_some() => {
+                Some(event.state_key)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_space(client: &Client, space_id: &str) -> Result<Room, String> {
+    let space_id = RoomId::parse(space_id).map_err(|e| e.to_string())?;
+    let space = client.get_room(&space_id).ok_or("Space not found")?;
+    if !space.is_space() {
+        return Err("Room is not a space".to_string());
+    }
+    Ok(space)
+}
+
+fn get_own_server(client: &Client) -> Result<Vec<OwnedServerName>, String> {
+    let user_id = client.user_id().ok_or("Client not logged in")?;
+    Ok(vec![user_id.server_name().to_owned()])
+}
+
+/// Link `child` to `space` with an `m.space.child` event, and the other way
+/// around with `m.space.parent` when we're allowed to in the child.
+#[frb(ignore)]
+pub async fn add_child_to_space(
+    client: &Client,
+    space: &Room,
+    child_id: &RoomId,
+    suggested: bool,
+) -> Result<(), String> {
+    let via = get_own_server(client)?;
+
+    let mut content = SpaceChildEventContent::new(via.clone());
+    content.suggested = suggested;
+    space
+        .send_state_event_for_key(child_id, content)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // The child event is enough for the space to list the room, the parent
+    // event only helps clients find the space from the room.
+    if let Some(child) = client.get_room(child_id) {
+        if let Err(e) = child
+            .send_state_event_for_key(space.room_id(), SpaceParentEventContent::new(via))
+            .await
+        {
+            log_warn(format!(
+                "Failed to set {} as parent of {}: {}",
+                space.room_id(),
+                child_id,
+                e
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// All the spaces we are joined to.
+pub fn get_joined_spaces() -> Vec<RoomUpdate> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let mut spaces = Vec::new();
+            for room in app.client.joined_rooms() {
+                if room.is_space() {
+                    spaces.push(get_room_update_data(&room).await);
+                }
+            }
+            spaces
+        })
+    })
+}
+
+/// A page of the hierarchy of a space, walked depth first from the space
+/// itself, which is the first room of the first page.
+pub fn get_space_hierarchy(
+    space_id: String,
+    from: Option<String>,
+    limit: Option<u32>,
+    max_depth: Option<u32>,
+    suggested_only: bool,
+) -> Result<SpaceHierarchyPage, String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let space_id = RoomId::parse(&space_id).map_err(|e| e.to_string())?;
+
+            let mut request = get_hierarchy::v1::Request::new(space_id);
+            request.from = from;
+            request.limit = limit.map(UInt::from);
+            request.max_depth = max_depth.map(UInt::from);
+            request.suggested_only = suggested_only;
+
+            let response = app.client.send(request).await.map_err(|e| e.to_string())?;
+
+            // The `suggested` flag lives on the parent's child event.
+            let mut suggested = HashMap::new();
+            for chunk in &response.rooms {
+                for event in &chunk.children_state {
+                    if let Ok(event) = event.deserialize() {
+                        suggested.insert(event.state_key, event.content.suggested);
+                    }
+                }
+            }
+
+            let rooms = response
+                .rooms
+                .into_iter()
+                .map(|chunk| {
+                    let membership =
+                        app.client
+                            .get_room(&chunk.room_id)
+                            .map(|room| match room.state() {
+                                RoomState::Joined => UpdateType::Joined,
+                                RoomState::Invited => UpdateType::Invited,
+                                RoomState::Knocked => UpdateType::Knocked,
+                                RoomState::Banned => UpdateType::Banned,
+                                RoomState::Left => UpdateType::Left,
+                            });
+                    let children = chunk
+                        .children_state
+                        .iter()
+                        .filter_map(|event| event.deserialize().ok())
+                        .map(|event| event.state_key.to_string())
+                        .collect();
+
+                    SpaceHierarchyRoom {
+                        suggested: suggested.get(&chunk.room_id).copied().unwrap_or(false),
+                        room_id: chunk.room_id.to_string(),
+                        name: chunk.name,
+                        topic: chunk.topic,
+                        avatar_url: chunk.avatar_url.map(|uri| uri.to_string()),
+                        canonical_alias: chunk.canonical_alias.map(|alias| alias.to_string()),
+                        num_joined_members: chunk.num_joined_members.into(),
+                        is_space: chunk.room_type == Some(RoomType::Space),
+                        children,
+                        membership,
+                    }
+                })
+                .collect();
+
+            Ok(SpaceHierarchyPage {
+                rooms,
+                next_batch: response.next_batch,
+            })
+        })
+    })
+}
+
+/// Create a space, same options as for rooms. Returns the ID of the space.
+pub fn create_space(params: CreateRoomParams) -> Result<String, String> {
+    create_room(CreateRoomParams {
+        is_space: true,
+        ..params
+    })
+}
+
+/// Add `room_id` to a space we are allowed to manage.
+pub fn add_room_to_space(space_id: String, room_id: String, suggested: bool) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let space = get_space(&app.client, &space_id)?;
+            let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
+            add_child_to_space(&app.client, &space, &room_id, suggested).await
+        })
+    })
+}
+
+/// Remove `room_id` from a space, clearing the `m.space.parent` event too when
+/// we're allowed to.
+pub fn remove_room_from_space(space_id: String, room_id: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let app = GLOBAL_APP.get().expect("Global app not initialized");
+            let space = get_space(&app.client, &space_id)?;
+            let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
+
+            // State events can't be deleted, an empty `via` marks them removed.
+            space
+                .send_state_event_for_key(&room_id, SpaceChildEventContent::new(Vec::new()))
+                .await
+                .map_err(|e| e.to_string())?;
+
+            if let Some(room) = app.client.get_room(&room_id) {
+                if let Err(e) = room
+                    .send_state_event_for_key(
+                        space.room_id(),
+                        SpaceParentEventContent::new(Vec::new()),
+                    )
+                    .await
+                {
+                    log_warn(format!(
+                        "Failed to unset {} as parent of {}: {}",
+                        space.room_id(),
+                        room_id,
+                        e
+                    ));
+                }
+            }
+            Ok(())
+        })
+    })
+}
@@ -1,24 +1,30 @@
-use crate::api::logger::{log_error, log_warn};
-use crate::matrix::rooms::{ExtraRoomInfo, RoomInfos, RoomList};
+use crate::api::logger::log_warn;
+use crate::matrix::ignored_users::spawn_ignored_users_task;
+use crate::matrix::room_list::refresh_room_list_filters;
+use crate::matrix::rooms::{ExtraRoomInfo, RoomDiffs, RoomInfos, RoomList};
+use crate::matrix::spaces::get_space_children;
 use crate::matrix::status::Status;
-use crate::matrix::timelines::{RoomView, Timeline, Timelines};
+use crate::matrix::tags::get_room_tags;
+use crate::matrix::timelines::{preload_room_timelines, RoomView, Timelines, PRELOADED_TIMELINES};
 use crate::{api::platform::GLOBAL_RUNTIME, matrix::client::get_global_client};
 use flutter_rust_bridge::frb;
 use futures::{pin_mut, StreamExt};
 use imbl::Vector;
 use matrix_sdk::Client;
 use matrix_sdk::Room;
-use matrix_sdk_ui::room_list_service::filters::new_filter_non_left;
+use matrix_sdk_ui::eyeball_im::VectorDiff;
+use matrix_sdk_ui::room_list_service::filters::{
+    new_filter_all, new_filter_deduplicate_versions, new_filter_non_left,
+};
 use matrix_sdk_ui::room_list_service::{self};
 use matrix_sdk_ui::sync_service::SyncService;
-use matrix_sdk_ui::timeline::{EventTimelineItem, RoomExt, TimelineFocus, VirtualTimelineItem};
+use matrix_sdk_ui::timeline::{EventTimelineItem, VirtualTimelineItem};
 use once_cell::sync::OnceCell;
 pub use std::collections::HashMap;
-use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Instant;
-use tokio::spawn;
+use tokio::{spawn, sync::broadcast};
 
 #[frb(ignore)]
 pub static GLOBAL_SYNC_SERVICE: OnceCell<Option<Arc<SyncService>>> = OnceCell::new();
@@ -29,6 +35,10 @@ pub static GLOBAL_APP: OnceCell<Arc<App>> = OnceCell::new();
 #[frb(ignore)]
 pub type Rooms = Arc<Mutex<Vector<Room>>>;
 
+/// Number of room list diff batches buffered for slow subscribers before they
+/// have to start over from a reset.
+const ROOM_DIFFS_CAPACITY: usize = 64;
+
 pub struct RoomUpdate {
     pub room_id: String,
     pub raw_name: Option<String>,
@@ -73,6 +83,10 @@ pub struct App {
     /// Timelines data structures for each room.
     pub timelines: Timelines,
 
+    /// Event-focused timelines (jump to message, permalinks), at most one per
+    /// room.
+    pub focused_timelines: Timelines,
+
     /// The room list widget on the left-hand side of the screen.
     pub room_list: RoomList,
 
@@ -90,6 +104,7 @@ pub struct App {
 impl App {
     async fn new(client: Client, sync_service: Arc<SyncService>) -> Result<Self, ()> {
         let rooms = Rooms::default();
+        let (room_diffs, _) = broadcast::channel(ROOM_DIFFS_CAPACITY);
         let room_infos = RoomInfos::default();
         let timelines = Timelines::default();
 
@@ -102,11 +117,14 @@ impl App {
         // Spawn the listen task but don't store it in the struct since JoinHandle can't be cloned
         let _listen_task = spawn(Self::listen_task(
             rooms.clone(),
+            room_diffs.clone(),
             room_infos.clone(),
             timelines.clone(),
             all_rooms.unwrap(),
         ));
 
+        spawn_ignored_users_task(client.clone());
+
         // This will sync (with encryption) until an error happens or the program is
         // stopped.
         sync_service.start().await;
@@ -115,6 +133,7 @@ impl App {
         let room_list = RoomList::new(
             client.clone(),
             rooms,
+            room_diffs,
             room_infos,
             sync_service.clone(),
             status.handle(),
@@ -125,6 +144,7 @@ impl App {
         Ok(Self {
             sync_service,
             timelines,
+            focused_timelines: Timelines::default(),
             room_list,
             room_view,
             client,
@@ -135,35 +155,51 @@ impl App {
 
     async fn listen_task(
         rooms: Rooms,
+        room_diffs: RoomDiffs,
         room_infos: RoomInfos,
         timelines: Timelines,
         all_rooms: room_list_service::RoomList,
     ) {
         let (stream, entries_controller) = all_rooms.entries_with_dynamic_adapters(50_000);
-        entries_controller.set_filter(Box::new(new_filter_non_left()));
+        // Once the successor of an upgraded room is joined, only show that one.
+        entries_controller.set_filter(Box::new(new_filter_all(vec![
+            Box::new(new_filter_non_left()),
+            Box::new(new_filter_deduplicate_versions()),
+        ])));
 
         pin_mut!(stream);
 
-        let mut previous_rooms = HashSet::new();
+        let mut preloaded = false;
 
         while let Some(diffs) = stream.next().await {
-            let all_rooms = {
+            // Only the rooms added or replaced by this batch need their info
+            // refreshed, the others didn't change.
+            let touched_rooms: Vec<Room> = diffs.iter().flat_map(rooms_touched_by).collect();
+
+            let top_rooms = {
                 // Apply the diffs to the list of room entries.
                 let mut rooms = rooms.lock().unwrap();
 
-                for diff in diffs {
+                for diff in diffs.clone() {
                     diff.apply(&mut rooms);
                 }
 
+                // Subscribers snapshot `rooms` under the same lock, so they see
+                // each batch exactly once. Sending fails when nobody listens,
+                // which is fine.
+                let _ = room_diffs.send(diffs);
+
                 // Collect rooms early to release the room entries list lock.
-                (*rooms).clone()
+                rooms
+                    .iter()
+                    .take(PRELOADED_TIMELINES)
+                    .cloned()
+                    .collect::<Vec<_>>()
             };
 
-            let mut new_rooms = HashMap::new();
-            let mut new_timelines = Vec::new();
+            let mut filters_stale = false;
 
-            // Update all the room info for all rooms.
-            for room in all_rooms.iter() {
+            for room in touched_rooms.iter() {
                 let raw_name = room.name();
                 let display_name = room
                     .cached_display_name()
@@ -177,70 +213,63 @@ impl App {
                         ));
                     })
                     .ok();
-                room_infos.lock().unwrap().insert(
-                    room.room_id().to_owned(),
-                    ExtraRoomInfo {
-                        raw_name,
-                        display_name,
-                        is_dm,
-                    },
-                );
-            }
-
-            // Initialize all the new rooms.
-            for room in all_rooms
-                .into_iter()
-                .filter(|room| !previous_rooms.contains(room.room_id()))
-            {
-                // Initialize the timeline.
-                let Ok(timeline) = room
-                    .timeline_builder()
-                    .with_focus(TimelineFocus::Live {
-                        hide_threaded_events: true,
-                    })
-                    .build()
+                let tags = get_room_tags(room)
                     .await
-                else {
-                    log_error(format!("error when creating default timeline"));
-                    continue;
+                    .into_iter()
+                    .map(|tag| tag.name)
+                    .collect();
+                let space_children = if room.is_space() {
+                    get_space_children(room).await
+                } else {
+                    Vec::new()
+                };
+                let info = ExtraRoomInfo {
+                    raw_name,
+                    display_name,
+                    is_dm,
+                    tags,
+                    space_children,
                 };
 
-                // Save the timeline in the cache.
-                let (items, stream): (Vector<Arc<matrix_sdk_ui::timeline::TimelineItem>>, _) =
-                    timeline.subscribe().await;
-                let items = Arc::new(Mutex::new(items));
-
-                // Spawn a timeline task that will listen to all the timeline item changes.
-                let i = items.clone();
-                let timeline_task = spawn(async move {
-                    pin_mut!(stream);
-                    let items = i;
-                    while let Some(diffs) = stream.next().await {
-                        let mut items = items.lock().unwrap();
-
-                        for diff in diffs {
-                            diff.apply(&mut items);
-                        }
-                    }
-                });
-
-                new_timelines.push((
-                    room.room_id().to_owned(),
-                    Timeline {
-                        timeline: Arc::new(timeline),
-                        items,
-                        task: timeline_task,
-                    },
-                ));
-
-                // Save the room list service room in the cache.
-                new_rooms.insert(room.room_id().to_owned(), room);
+                let mut infos = room_infos.lock().unwrap();
+                filters_stale |= match infos.get(room.room_id()) {
+                    Some(old) => old.tags != info.tags || old.space_children != info.space_children,
+                    None => !info.tags.is_empty() || !info.space_children.is_empty(),
+                };
+                infos.insert(room.room_id().to_owned(), info);
+            }
+
+            // The tag and space filters read `room_infos`, the SDK can't
+            // tell when their result changes.
+            if filters_stale {
+                refresh_room_list_filters(&room_infos);
             }
 
-            previous_rooms.extend(new_rooms.into_keys());
+            // Timelines are built lazily when a room is opened, only the top
+            // of the list is warmed up once the first rooms come in.
+            if !preloaded && !top_rooms.is_empty() {
+                preloaded = true;
+                preload_room_timelines(&timelines, top_rooms).await;
+            }
+        }
+    }
+}
 
-            timelines.lock().unwrap().extend(new_timelines);
+/// Rooms added or replaced by a room list diff.
+fn rooms_touched_by(diff: &VectorDiff<Room>) -> Vec<Room> {
+    match diff {
+        VectorDiff::Append { values } | VectorDiff::Reset { values } => {
+            values.iter().cloned().collect()
         }
+        VectorDiff::PushFront { value }
+        | VectorDiff::PushBack { value }
+        | VectorDiff::Insert { value, .. }
+        | VectorDiff::Set { value, .. } => vec![value.clone()],
+        VectorDiff::Clear
+        | VectorDiff::PopFront
+        | VectorDiff::PopBack
+        | VectorDiff::Remove { .. }
+        | VectorDiff::Truncate { .. } => Vec::new(),
     }
 }
 
@@ -0,0 +1,113 @@
+use flutter_rust_bridge::frb;
+use matrix_sdk::{
+    ruma::events::tag::{TagInfo, TagName},
+    Room,
+};
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    matrix::rooms::get_room,
+};
+
+/// A `m.tag` entry of a room: `m.favourite`, `m.lowpriority` or a custom
+/// `u.*` tag, with its optional ordering in `[0, 1]`.
+#[derive(Clone, Debug)]
+pub struct RoomTag {
+    pub name: String,
+    pub order: Option<f64>,
+}
+
+#[frb(ignore)]
+pub async fn get_room_tags(room: &Room) -> Vec<RoomTag> {
+    match room.tags().await {
+        Ok(tags) => tags
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, info)| RoomTag {
+                name: name.to_string(),
+                order: info.order,
+            })
+            .collect(),
+        Err(e) => {
+            log_warn(format!(
+                "Failed to read tags of room {}: {}",
+                room.room_id(),
+                e
+            ));
+            Vec::new()
+        }
+    }
+}
+
+pub fn set_room_favourite(
+    room_id: String,
+    is_favourite: bool,
+    order: Option<f64>,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            // Also removes the low priority tag, the two are exclusive.
+            room.set_is_favourite(is_favourite, order)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn set_room_low_priority(
+    room_id: String,
+    is_low_priority: bool,
+    order: Option<f64>,
+) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            // Also removes the favourite tag, the two are exclusive.
+            room.set_is_low_priority(is_low_priority, order)
+                .await
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+/// Set a tag on a room. Custom tags should use the `u.` prefix, e.g.
+/// `u.work`.
+pub fn set_room_tag(room_id: String, tag: String, order: Option<f64>) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+
+            let mut tag_info = TagInfo::new();
+            tag_info.order = order;
+            room.set_tag(TagName::from(tag), tag_info)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+    })
+}
+
+pub fn remove_room_tag(room_id: String, tag: String) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = &get_room(&room_id)?;
+            room.remove_tag(TagName::from(tag))
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+    })
+}
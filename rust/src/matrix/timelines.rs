@@ -1,20 +1,29 @@
 use flutter_rust_bridge::frb;
-use futures::StreamExt;
+use futures::{pin_mut, StreamExt};
 use imbl::Vector;
-use matrix_sdk::ruma::{OwnedEventId, OwnedRoomId};
-use matrix_sdk::Client;
-use matrix_sdk_ui::timeline::{RoomExt, TimelineFocus, TimelineItem};
+use matrix_sdk::ruma::events::FullStateEventContent;
+use matrix_sdk::ruma::{EventId, OwnedEventId, OwnedRoomId, RoomId};
+use matrix_sdk::{Client, Room};
+use matrix_sdk_ui::eyeball_im::VectorDiff;
+use matrix_sdk_ui::timeline::{
+    AnyOtherFullStateEventContent, RoomExt, TimelineFocus, TimelineItem, TimelineItemContent,
+};
 use matrix_sdk_ui::Timeline as SdkTimeline;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
-use tokio::sync::OnceCell;
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, OnceCell};
 use tokio::task::JoinHandle;
 
-use crate::api::logger::{log_error, log_info};
+use crate::api::logger::{log_debug, log_error, log_warn};
 use crate::api::platform::GLOBAL_RUNTIME;
 use crate::frb_generated::StreamSink;
+use crate::matrix::ignored_users::filter_ignored_users_events;
+use crate::matrix::receipts::ReadReceipt;
+use crate::matrix::rooms::get_room;
 use crate::matrix::status::StatusHandle;
 use crate::matrix::sync_service::GLOBAL_APP;
 
@@ -22,8 +31,20 @@ use crate::matrix::sync_service::GLOBAL_APP;
 pub enum MessageType {
     Message,
     DateDivider,
-    ReadMarker,
+    /// Follows the last message we read. `own_receipt_event_id` is the event
+    /// of our own latest read receipt, if we have one.
+    ReadMarker {
+        own_receipt_event_id: Option<String>,
+    },
     TimelineStart,
+    /// The room was upgraded, its conversation continues in another room.
+    Tombstone {
+        replacement_room_id: String,
+    },
+    /// The room was created by upgrading `predecessor_room_id`.
+    Predecessor {
+        predecessor_room_id: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +69,16 @@ pub struct Message {
     pub content: String,
     pub timestamp: u64,
     pub message_type: MessageType,
+    /// Members who have read up to this message, for "seen by" avatars.
+    pub read_receipts: Vec<ReadReceipt>,
+}
+
+/// Back-pagination state of a room's timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaginationStatus {
+    Idle,
+    Paginating,
+    ReachedStart,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,11 +94,139 @@ pub struct Timeline {
     pub timeline: Arc<SdkTimeline>,
     pub items: Arc<Mutex<Vector<Arc<TimelineItem>>>>,
     pub task: JoinHandle<()>,
+    /// Back-pagination state, also used to make sure only one pagination
+    /// runs at a time on this timeline.
+    pub pagination_status: Arc<watch::Sender<PaginationStatus>>,
+    /// Tasks forwarding the timeline diffs to Dart streams, by subscription
+    /// ID. Timelines without subscriptions can be evicted from the cache.
+    pub subscriptions: HashMap<u64, JoinHandle<()>>,
+    /// Last time the timeline was opened or paginated, for LRU eviction.
+    pub last_used: Instant,
+}
+
+#[frb(ignore)]
+impl Drop for Timeline {
+    fn drop(&mut self) {
+        self.task.abort();
+        for (_, subscription) in self.subscriptions.drain() {
+            subscription.abort();
+        }
+    }
+}
+
+#[frb(ignore)]
+impl Timeline {
+    /// Wrap an SDK timeline, keeping `items` up to date in a background task.
+    pub async fn new(timeline: SdkTimeline) -> Self {
+        let (items, stream) = timeline.subscribe().await;
+        let items = Arc::new(Mutex::new(items));
+
+        // Spawn a timeline task that will listen to all the timeline item changes.
+        let task = tokio::spawn({
+            let items = items.clone();
+            async move {
+                pin_mut!(stream);
+                while let Some(diffs) = stream.next().await {
+                    let mut items = items.lock().unwrap();
+
+                    for diff in diffs {
+                        diff.apply(&mut items);
+                    }
+                }
+            }
+        });
+
+        Self {
+            timeline: Arc::new(timeline),
+            items,
+            task,
+            pagination_status: Arc::new(watch::channel(PaginationStatus::Idle).0),
+            subscriptions: HashMap::new(),
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Build the live timeline of a room.
+    pub async fn build_live(room: &Room) -> Result<Self, String> {
+        let timeline = room
+            .timeline_builder()
+            .event_filter(filter_ignored_users_events)
+            .track_read_marker_and_receipts()
+            .with_focus(TimelineFocus::Live {
+                hide_threaded_events: true,
+            })
+            .build()
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(Self::new(timeline).await)
+    }
 }
 
 #[frb(ignore)]
 pub type Timelines = Arc<Mutex<HashMap<OwnedRoomId, Timeline>>>;
 
+/// Number of events loaded on each side of the target event when opening an
+/// event-focused timeline without an explicit window.
+const DEFAULT_CONTEXT_EVENTS: u16 = 20;
+
+/// Maximum number of live timelines kept in memory. Timelines with active
+/// subscriptions are never evicted, so this can be exceeded temporarily.
+const MAX_CACHED_TIMELINES: usize = 20;
+
+/// Number of rooms at the top of the room list whose timeline is built ahead
+/// of time.
+pub const PRELOADED_TIMELINES: usize = 5;
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Drop the least recently used timelines that nobody is subscribed to until
+/// at most `max_cached` timelines remain. The timeline of `keep`, if any, is
+/// never dropped.
+fn evict_idle_timelines(
+    timelines: &mut HashMap<OwnedRoomId, Timeline>,
+    max_cached: usize,
+    keep: Option<&RoomId>,
+) {
+    while timelines.len() > max_cached {
+        let Some(room_id) = timelines
+            .iter()
+            .filter(|(room_id, timeline)| {
+                timeline.subscriptions.is_empty() && Some(&***room_id) != keep
+            })
+            .min_by_key(|(_, timeline)| timeline.last_used)
+            .map(|(room_id, _)| room_id.clone())
+        else {
+            break;
+        };
+        timelines.remove(&room_id);
+    }
+}
+
+/// Build the live timelines of `rooms` that aren't cached yet, at most
+/// [`PRELOADED_TIMELINES`] of them.
+#[frb(ignore)]
+pub async fn preload_room_timelines(timelines: &Timelines, rooms: Vec<Room>) {
+    for room in rooms.into_iter().take(PRELOADED_TIMELINES) {
+        if timelines.lock().unwrap().contains_key(room.room_id()) {
+            continue;
+        }
+
+        let timeline = match Timeline::build_live(&room).await {
+            Ok(timeline) => timeline,
+            Err(e) => {
+                log_error(format!("error when preloading timeline: {e}"));
+                continue;
+            }
+        };
+
+        let mut timelines = timelines.lock().unwrap();
+        timelines
+            .entry(room.room_id().to_owned())
+            .or_insert(timeline);
+        evict_idle_timelines(&mut timelines, MAX_CACHED_TIMELINES, Some(room.room_id()));
+    }
+}
+
 #[frb(ignore)]
 pub enum TimelineKind {
     Room {
@@ -98,8 +257,6 @@ pub struct RoomView {
 
     status_handle: StatusHandle,
 
-    current_pagination: Arc<Mutex<Option<JoinHandle<()>>>>,
-
     kind: TimelineKind,
 }
 
@@ -132,31 +289,127 @@ impl RoomView {
             client,
             timelines,
             status_handle,
-            current_pagination: Default::default(),
             kind: TimelineKind::Room { room: None },
         }
     }
 }
 
-fn get_timeline_sdk_by_room_id(room_id: String) -> Option<Arc<SdkTimeline>> {
-    match GLOBAL_APP.get() {
-        Some(app) => {
-            let room_id: OwnedRoomId = room_id.parse().ok()?;
-            let timelines = app.timelines.lock().ok()?;
-            let room_id_ref: &matrix_sdk::ruma::RoomId = room_id.as_ref();
-            timelines
-                .get(room_id_ref)
-                .map(|timeline| timeline.timeline.clone())
+/// Run `f` on the shared live timeline of a room, under the cache lock,
+/// building the timeline first if no one created it yet.
+///
+/// Holding the lock keeps the timeline from being evicted while `f` runs, e.g.
+/// until a subscription is registered on it.
+async fn with_live_timeline<T>(
+    room_id: &RoomId,
+    f: impl FnOnce(&mut Timeline) -> T,
+) -> Result<T, String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+
+    if let Some(timeline) = app.timelines.lock().unwrap().get_mut(room_id) {
+        timeline.last_used = Instant::now();
+        return Ok(f(timeline));
+    }
+
+    let room = get_room(room_id.as_str())?;
+    let timeline = Timeline::build_live(&room).await.map_err(|e| {
+        log_error(format!("Failed to build timeline: {}", e));
+        e
+    })?;
+
+    // Another caller may have built the timeline while we were awaiting, keep
+    // the first one.
+    let mut timelines = app.timelines.lock().unwrap();
+    let result = f(timelines.entry(room_id.to_owned()).or_insert(timeline));
+    evict_idle_timelines(&mut timelines, MAX_CACHED_TIMELINES, Some(room_id));
+    Ok(result)
+}
+
+/// Get the shared live timeline of a room, building it if no one created it
+/// yet.
+///
+/// Every subscriber and every pagination request of a room goes through this
+/// timeline, so paginated items show up in all the streams.
+#[frb(ignore)]
+pub async fn get_or_create_timeline(
+    room_id: &str,
+) -> Result<(Arc<SdkTimeline>, Arc<watch::Sender<PaginationStatus>>), String> {
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+    with_live_timeline(&room_id, |timeline| {
+        (
+            timeline.timeline.clone(),
+            timeline.pagination_status.clone(),
+        )
+    })
+    .await
+}
+
+/// Paginate backwards on `timeline`, or wait for the pagination already
+/// running on it.
+///
+/// Returns whether there is more history to load.
+async fn paginate_backwards(
+    timeline: Arc<SdkTimeline>,
+    status: Arc<watch::Sender<PaginationStatus>>,
+    count: u16,
+) -> Result<bool, String> {
+    let mut previous = PaginationStatus::Idle;
+    status.send_if_modified(|current| {
+        previous = *current;
+        if *current == PaginationStatus::Idle {
+            *current = PaginationStatus::Paginating;
+            true
+        } else {
+            false
         }
-        None => {
-            println!("No global app found");
-            None
+    });
+
+    match previous {
+        PaginationStatus::ReachedStart => return Ok(false),
+        PaginationStatus::Paginating => {
+            let mut receiver = status.subscribe();
+            let current = *receiver
+                .wait_for(|status| *status != PaginationStatus::Paginating)
+                .await
+                .map_err(|e| e.to_string())?;
+            return Ok(current != PaginationStatus::ReachedStart);
         }
+        PaginationStatus::Idle => {}
     }
+
+    match timeline.paginate_backwards(count).await {
+        Ok(hit_start) => {
+            status.send_replace(if hit_start {
+                PaginationStatus::ReachedStart
+            } else {
+                PaginationStatus::Idle
+            });
+            Ok(!hit_start)
+        }
+        Err(e) => {
+            status.send_replace(PaginationStatus::Idle);
+            log_error(format!("Failed to paginate backwards: {}", e));
+            Err(e.to_string())
+        }
+    }
+}
+
+/// Event ID of our own latest read receipt in `timeline`, see
+/// `MessageType::ReadMarker`.
+async fn get_own_receipt_event_id(timeline: &SdkTimeline) -> Option<String> {
+    let user_id = GLOBAL_APP.get()?.client.user_id()?.to_owned();
+    timeline
+        .latest_user_read_receipt(&user_id)
+        .await
+        .map(|(event_id, _)| event_id.to_string())
 }
 
+/// Convert a timeline item, `own_receipt_event_id` being the event of our own
+/// latest read receipt for the read marker.
 #[frb(ignore)]
-pub fn get_message_from_timeline_item(item: &TimelineItem) -> Message {
+pub fn get_message_from_timeline_item(
+    item: &TimelineItem,
+    own_receipt_event_id: Option<&str>,
+) -> Message {
     match item.kind() {
         matrix_sdk_ui::timeline::TimelineItemKind::Event(event_timeline_item) => {
             let event_id = event_timeline_item
@@ -164,18 +417,58 @@ pub fn get_message_from_timeline_item(item: &TimelineItem) -> Message {
                 .map(|id| id.to_string())
                 .unwrap_or_else(|| "unknown".to_string());
             let sender = event_timeline_item.sender().to_string();
-            let content = event_timeline_item
+            let mut content = event_timeline_item
                 .content()
                 .as_message()
                 .map(|msg| msg.body().to_string())
                 .unwrap_or_else(|| "".to_string());
             let timestamp = u64::from(event_timeline_item.timestamp().0);
+
+            let mut message_type = MessageType::Message;
+            if let TimelineItemContent::OtherState(state) = event_timeline_item.content() {
+                match state.content() {
+                    AnyOtherFullStateEventContent::RoomTombstone(
+                        FullStateEventContent::Original {
+                            content: tombstone, ..
+                        },
+                    ) => {
+                        content = tombstone.body.clone();
+                        message_type = MessageType::Tombstone {
+                            replacement_room_id: tombstone.replacement_room.to_string(),
+                        };
+                    }
+                    AnyOtherFullStateEventContent::RoomCreate(
+                        FullStateEventContent::Original {
+                            content: create, ..
+                        },
+                    ) => {
+                        if let Some(predecessor) = &create.predecessor {
+                            message_type = MessageType::Predecessor {
+                                predecessor_room_id: predecessor.room_id.to_string(),
+                            };
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let read_receipts = event_timeline_item
+                .read_receipts()
+                .iter()
+                .map(|(user_id, receipt)| ReadReceipt {
+                    user_id: user_id.to_string(),
+                    event_id: event_id.clone(),
+                    timestamp: receipt.ts.map(|ts| u64::from(ts.get())),
+                })
+                .collect();
+
             Message {
                 event_id,
                 sender,
                 content,
                 timestamp,
-                message_type: MessageType::Message,
+                message_type,
+                read_receipts,
             }
         }
         matrix_sdk_ui::timeline::TimelineItemKind::Virtual(virtual_timeline_item) => {
@@ -188,13 +481,17 @@ pub fn get_message_from_timeline_item(item: &TimelineItem) -> Message {
                     content: format!("Date: {}", u64::from(milli_seconds_since_unix_epoch.0)),
                     timestamp: u64::from(milli_seconds_since_unix_epoch.0),
                     message_type: MessageType::DateDivider,
+                    read_receipts: Vec::new(),
                 },
                 matrix_sdk_ui::timeline::VirtualTimelineItem::ReadMarker => Message {
                     event_id: "".to_string(),
                     sender: "".to_string(),
                     content: "".to_string(),
                     timestamp: 0,
-                    message_type: MessageType::ReadMarker,
+                    message_type: MessageType::ReadMarker {
+                        own_receipt_event_id: own_receipt_event_id.map(ToOwned::to_owned),
+                    },
+                    read_receipts: Vec::new(),
                 },
                 matrix_sdk_ui::timeline::VirtualTimelineItem::TimelineStart => Message {
                     event_id: "".to_string(),
@@ -202,6 +499,7 @@ pub fn get_message_from_timeline_item(item: &TimelineItem) -> Message {
                     content: "".to_string(),
                     timestamp: 0,
                     message_type: MessageType::TimelineStart,
+                    read_receipts: Vec::new(),
                 },
             }
         }
@@ -212,224 +510,567 @@ pub fn get_timeline_items_by_room_id(room_id: String) -> Vec<Message> {
     let rt = GLOBAL_RUNTIME.get().unwrap();
     rt.block_on(async {
         let mut messages = Vec::new();
-        match GLOBAL_APP.get() {
-            Some(app) => {
-                let room_id: OwnedRoomId = match room_id.parse() {
-                    Ok(id) => id,
-                    Err(_) => {
-                        println!("Failed to parse room ID");
-                        return messages;
-                    }
-                };
+        let timeline = match get_or_create_timeline(&room_id).await {
+            Ok((timeline, _)) => timeline,
+            Err(e) => {
+                log_error(format!("Failed to get timeline: {}", e));
+                return messages;
+            }
+        };
 
-                let room_id_ref = room_id.as_ref();
-                let room = match app.client.get_room(room_id_ref) {
-                    Some(room) => room,
-                    None => {
-                        println!("Room not found");
-                        return messages;
-                    }
-                };
+        let items = timeline.items().await;
+        let own_receipt_event_id = get_own_receipt_event_id(&timeline).await;
 
-                let timeline = match room.timeline().await {
-                    Ok(timeline) => timeline,
-                    Err(e) => {
-                        println!("Failed to get timeline: {}", e);
-                        return messages;
-                    }
-                };
+        for item in items.iter() {
+            messages.push(get_message_from_timeline_item(
+                item,
+                own_receipt_event_id.as_deref(),
+            ));
+        }
+        messages
+    })
+}
+
+/// Allocate the ID of a timeline subscription, to pass to
+/// [`subscribe_to_timeline_updates`] and later to
+/// [`unsubscribe_from_timeline_updates`].
+pub fn new_timeline_subscription_id() -> u64 {
+    NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Stream the updates of a room's live timeline.
+///
+/// Each item of the stream is the batch of updates produced by one timeline
+/// change and must be applied as a whole. With `throttle_ms`, batches coming
+/// in during that window are merged and coalesced into a single one, which
+/// keeps busy rooms from flooding the bridge.
+///
+/// The first batch is a `Reset` with the current items, so the stream alone
+/// is enough to render the room.
+///
+/// All the subscribers of a room share the same timeline, which is built the
+/// first time the room is opened. `subscription_id` comes from
+/// [`new_timeline_subscription_id`] and is what
+/// [`unsubscribe_from_timeline_updates`] stops; the timeline then stays cached
+/// until it gets evicted by more recently used rooms. A stream closed on the
+/// Dart side is only noticed when the next batch fails to be sent, so quiet
+/// rooms keep their subscription until then.
+pub async fn subscribe_to_timeline_updates(
+    stream: StreamSink<Vec<MessageUpdate>>,
+    room_id: String,
+    subscription_id: u64,
+    throttle_ms: Option<u64>,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+
+    // Register the subscription before releasing the cache lock, so the
+    // timeline can't be evicted in between.
+    with_live_timeline(&room_id, |timeline| {
+        add_subscription(
+            &app.timelines,
+            timeline,
+            room_id.clone(),
+            subscription_id,
+            stream,
+            throttle_ms.map(Duration::from_millis),
+            MAX_CACHED_TIMELINES,
+        )
+    })
+    .await
+    .map_err(|e| {
+        log_error(format!("Failed to get timeline: {}", e));
+        e
+    })?
+}
+
+/// Stop a live timeline subscription started by
+/// [`subscribe_to_timeline_updates`]. Once a room has no subscriptions left,
+/// its timeline is up for eviction.
+pub fn unsubscribe_from_timeline_updates(
+    room_id: String,
+    subscription_id: u64,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+
+    let mut timelines = app.timelines.lock().unwrap();
+    if let Some(subscription) = timelines
+        .get_mut(&room_id)
+        .and_then(|timeline| timeline.subscriptions.remove(&subscription_id))
+    {
+        subscription.abort();
+    }
+    evict_idle_timelines(&mut timelines, MAX_CACHED_TIMELINES, None);
+    Ok(())
+}
+
+/// Build the timelines of the rooms currently visible in the room list, so
+/// opening them is instant. Only the first [`PRELOADED_TIMELINES`] rooms are
+/// considered.
+pub async fn preload_timelines(room_ids: Vec<String>) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
 
-                let items = timeline.items().await;
+    let mut rooms = Vec::new();
+    for room_id in room_ids.into_iter().take(PRELOADED_TIMELINES) {
+        let room_id = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
+        match app.client.get_room(&room_id) {
+            Some(room) => rooms.push(room),
+            None => log_warn(format!("Room not found: {}", room_id)),
+        }
+    }
 
-                for item in items.iter() {
-                    messages.push(get_message_from_timeline_item(item));
+    preload_room_timelines(&app.timelines, rooms).await;
+    Ok(())
+}
+
+/// Spawn a task streaming the items of `timeline` to `stream`, registered as
+/// the subscription `subscription_id` of the room's timeline in `timelines`.
+///
+/// Must be called with `timelines` locked, `timeline` being its entry for
+/// `room_id`. The first batch sent is a `Reset` with the current items. When
+/// the subscription ends, idle timelines beyond `max_cached` are evicted.
+fn add_subscription(
+    timelines: &Timelines,
+    timeline: &mut Timeline,
+    room_id: OwnedRoomId,
+    subscription_id: u64,
+    stream: StreamSink<Vec<MessageUpdate>>,
+    throttle: Option<Duration>,
+    max_cached: usize,
+) -> Result<(), String> {
+    if timeline.subscriptions.contains_key(&subscription_id) {
+        return Err("Subscription ID already in use".to_string());
+    }
+
+    // The caller holds the lock, so the task can't release the subscription
+    // before it is registered.
+    let task = tokio::spawn({
+        let timelines = timelines.clone();
+        let sdk_timeline = timeline.timeline.clone();
+        async move {
+            let (initial_items, diff_stream) = sdk_timeline.subscribe().await;
+            let reset = vec![VectorDiff::Reset {
+                values: initial_items,
+            }];
+            let mut open = stream
+                .add(get_message_updates_from_diffs(&sdk_timeline, reset).await)
+                .is_ok();
+
+            pin_mut!(diff_stream);
+            while open {
+                let Some(diffs) = diff_stream.next().await else {
+                    break;
+                };
+                let mut updates = get_message_updates_from_diffs(&sdk_timeline, diffs).await;
+
+                if let Some(throttle) = throttle {
+                    let window = tokio::time::sleep(throttle);
+                    pin_mut!(window);
+                    loop {
+                        tokio::select! {
+                            _ = &mut window => break,
+                            diffs = diff_stream.next() => match diffs {
+                                Some(diffs) => updates.extend(
+                                    get_message_updates_from_diffs(&sdk_timeline, diffs).await,
+                                ),
+                                None => break,
+                            },
+                        }
+                    }
+                    updates = coalesce_message_updates(updates);
                 }
+
+                log_debug(format!(
+                    "Sending {} timeline updates for {}",
+                    updates.len(),
+                    room_id
+                ));
+                open = stream.add(updates).is_ok();
             }
-            None => {
-                println!("No global app found");
-            }
+            release_subscription(&timelines, &room_id, subscription_id, max_cached);
         }
-        messages
-    })
+    });
+    timeline.subscriptions.insert(subscription_id, task);
+    timeline.last_used = Instant::now();
+
+    Ok(())
+}
+
+fn release_subscription(
+    timelines: &Timelines,
+    room_id: &RoomId,
+    subscription_id: u64,
+    max_cached: usize,
+) {
+    let mut timelines = timelines.lock().unwrap();
+    if let Some(timeline) = timelines.get_mut(room_id) {
+        timeline.subscriptions.remove(&subscription_id);
+    }
+    evict_idle_timelines(&mut timelines, max_cached, None);
 }
 
-pub async fn subscribe_to_timeline_updates(stream: StreamSink<MessageUpdate>, room_id: String) {
-    let room_id: OwnedRoomId = match room_id.parse() {
-        Ok(id) => id,
-        Err(_) => {
-            log_error(format!("Failed to parse room ID"));
-            return;
+/// Shrink a batch of updates without changing the result of applying it.
+///
+/// Everything before the last `Reset` or `Clear` is dropped, and consecutive
+/// `Set`s of the same index are folded into the last one.
+#[frb(ignore)]
+pub fn coalesce_message_updates(updates: Vec<MessageUpdate>) -> Vec<MessageUpdate> {
+    let last_reset = updates.iter().rposition(|update| {
+        matches!(
+            update.message_update_type,
+            MessageUpdateType::Reset | MessageUpdateType::Clear
+        )
+    });
+
+    let mut coalesced: Vec<MessageUpdate> = Vec::with_capacity(updates.len());
+    for update in updates.into_iter().skip(last_reset.unwrap_or(0)) {
+        if let (Some(previous), MessageUpdateType::Set) =
+            (coalesced.last_mut(), &update.message_update_type)
+        {
+            if matches!(previous.message_update_type, MessageUpdateType::Set)
+                && previous.index == update.index
+            {
+                *previous = update;
+                continue;
+            }
         }
+        coalesced.push(update);
+    }
+    coalesced
+}
+
+/// Convert a batch of diffs of `timeline`, looking our read receipt up once
+/// for the whole batch.
+async fn get_message_updates_from_diffs(
+    timeline: &SdkTimeline,
+    diffs: Vec<VectorDiff<Arc<TimelineItem>>>,
+) -> Vec<MessageUpdate> {
+    let own_receipt_event_id = get_own_receipt_event_id(timeline).await;
+    diffs
+        .into_iter()
+        .map(|diff| get_message_update_from_diff(diff, own_receipt_event_id.as_deref()))
+        .collect()
+}
+
+#[frb(ignore)]
+pub fn get_message_update_from_diff(
+    diff: VectorDiff<Arc<TimelineItem>>,
+    own_receipt_event_id: Option<&str>,
+) -> MessageUpdate {
+    let message_from =
+        |value: &TimelineItem| get_message_from_timeline_item(value, own_receipt_event_id);
+    let messages_from = |values: Vector<Arc<TimelineItem>>| {
+        values
+            .iter()
+            .map(|value| message_from(value))
+            .collect::<Vec<_>>()
     };
 
-    let room_id_ref = room_id.as_ref();
-    let room = match GLOBAL_APP.get().unwrap().client.get_room(room_id_ref) {
-        Some(room) => room,
-        None => {
-            log_error(format!("Room not found"));
-            return;
+    match diff {
+        VectorDiff::Append { values } => MessageUpdate {
+            message_update_type: MessageUpdateType::Append,
+            messages: Some(messages_from(values)),
+            index: None,
+            length: None,
+        },
+        VectorDiff::Clear => MessageUpdate {
+            message_update_type: MessageUpdateType::Clear,
+            messages: None,
+            index: None,
+            length: None,
+        },
+        VectorDiff::PushFront { value } => MessageUpdate {
+            message_update_type: MessageUpdateType::PushFront,
+            messages: Some(vec![message_from(&value)]),
+            index: None,
+            length: None,
+        },
+        VectorDiff::PushBack { value } => MessageUpdate {
+            message_update_type: MessageUpdateType::PushBack,
+            messages: Some(vec![message_from(&value)]),
+            index: None,
+            length: None,
+        },
+        VectorDiff::PopFront => MessageUpdate {
+            message_update_type: MessageUpdateType::PopFront,
+            messages: None,
+            index: None,
+            length: None,
+        },
+        VectorDiff::PopBack => MessageUpdate {
+            message_update_type: MessageUpdateType::PopBack,
+            messages: None,
+            index: None,
+            length: None,
+        },
+        VectorDiff::Insert { index, value } => MessageUpdate {
+            message_update_type: MessageUpdateType::Insert,
+            messages: Some(vec![message_from(&value)]),
+            index: Some(index),
+            length: None,
+        },
+        VectorDiff::Set { index, value } => MessageUpdate {
+            message_update_type: MessageUpdateType::Set,
+            messages: Some(vec![message_from(&value)]),
+            index: Some(index),
+            length: None,
+        },
+        VectorDiff::Remove { index } => MessageUpdate {
+            message_update_type: MessageUpdateType::Remove,
+            messages: None,
+            index: Some(index),
+            length: None,
+        },
+        VectorDiff::Truncate { length } => MessageUpdate {
+            message_update_type: MessageUpdateType::Truncate,
+            messages: None,
+            index: None,
+            length: Some(length),
+        },
+        VectorDiff::Reset { values } => MessageUpdate {
+            message_update_type: MessageUpdateType::Reset,
+            messages: Some(messages_from(values)),
+            index: None,
+            length: None,
+        },
+    }
+}
+
+/// Load `count` older events in the live timeline of a room.
+///
+/// The new items reach Dart through [`subscribe_to_timeline_updates`]; this
+/// only returns whether there is more history to load. Concurrent calls for
+/// the same room share a single pagination.
+pub async fn get_older_messages(room_id: String, count: u16) -> Result<bool, String> {
+    let (timeline, status) = get_or_create_timeline(&room_id).await?;
+    paginate_backwards(timeline, status, count).await
+}
+
+/// Stream the back-pagination state of a room's live timeline.
+///
+/// The stream ends when the timeline is evicted from the cache; subscribe
+/// again after reopening the room to follow the rebuilt timeline.
+pub async fn subscribe_to_pagination_status(
+    stream: StreamSink<PaginationStatus>,
+    room_id: String,
+) -> Result<(), String> {
+    // Only keep a receiver, so dropping the timeline closes the channel and
+    // ends the loop below.
+    let mut receiver = get_or_create_timeline(&room_id).await?.1.subscribe();
+
+    let _ = stream.add(*receiver.borrow_and_update());
+    while receiver.changed().await.is_ok() {
+        let current = *receiver.borrow_and_update();
+        if stream.add(current).is_err() {
+            break;
         }
-    };
+    }
+    Ok(())
+}
+
+/// Open a timeline centered on `event_id` (e.g. a search result or a
+/// notification tap) and stream its updates.
+///
+/// The first batch is always a `Reset` with the items around the event, the
+/// following ones are regular diffs, batched like in
+/// [`subscribe_to_timeline_updates`]. Only one event-focused timeline is kept
+/// per room: opening a new one replaces the previous one.
+pub async fn subscribe_to_event_timeline_updates(
+    stream: StreamSink<Vec<MessageUpdate>>,
+    room_id: String,
+    event_id: String,
+    num_context_events: Option<u16>,
+    throttle_ms: Option<u64>,
+) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+    let event_id = EventId::parse(&event_id).map_err(|e| e.to_string())?;
+    let room = get_room(room_id.as_str())?;
+
     let timeline = room
         .timeline_builder()
-        .with_focus(TimelineFocus::Live {
+        .event_filter(filter_ignored_users_events)
+        .track_read_marker_and_receipts()
+        .with_focus(TimelineFocus::Event {
+            target: event_id,
+            num_context_events: num_context_events.unwrap_or(DEFAULT_CONTEXT_EVENTS),
             hide_threaded_events: true,
         })
         .build()
-        .await;
+        .await
+        .map_err(|e| {
+            log_error(format!("Failed to build event-focused timeline: {}", e));
+            e.to_string()
+        })?;
 
-    let timeline = match timeline {
-        Ok(timeline) => timeline,
-        Err(e) => {
-            log_error(format!("Failed to build timeline: {}", e));
-            return;
-        }
-    };
-    let (_events, mut diff_stream) = timeline.subscribe().await;
-
-    while let Some(diffs) = diff_stream.next().await {
-        for diff in diffs {
-            log_info(format!("Received timeline diff: {:?}", diff));
-            match diff {
-                matrix_sdk_ui::eyeball_im::VectorDiff::Append { values } => {
-                    let mut messages = Vec::new();
-                    for value in values {
-                        let message = get_message_from_timeline_item(&value);
-                        messages.push(message);
-                    }
-                    let _ = stream.add(MessageUpdate {
-                        messages: Some(messages),
-                        message_update_type: MessageUpdateType::Append,
-                        index: None,
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Clear => {
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Clear,
-                        messages: None,
-                        index: None,
-                        length: None,
-                    });
-                }
+    let timeline = Timeline::new(timeline).await;
 
-                matrix_sdk_ui::eyeball_im::VectorDiff::PushFront { value } => {
-                    let mut messages = Vec::new();
-                    let message = get_message_from_timeline_item(&value);
-                    messages.push(message);
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::PushFront,
-                        messages: Some(messages),
-                        index: None,
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::PushBack { value } => {
-                    let mut messages = Vec::new();
-                    let message = get_message_from_timeline_item(&value);
-                    messages.push(message);
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::PushBack,
-                        messages: Some(messages),
-                        index: None,
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::PopFront => {
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::PopFront,
-                        messages: None,
-                        index: None,
-                        length: None,
-                    });
-                }
+    // Replacing a previous event-focused timeline drops it, which stops its
+    // subscription. The new one gets its subscription under the same lock, so
+    // it can't be evicted before.
+    let mut focused_timelines = app.focused_timelines.lock().unwrap();
+    focused_timelines.insert(room_id.clone(), timeline);
+    let timeline = focused_timelines
+        .get_mut(&room_id)
+        .expect("the timeline was just inserted");
+    // Event-focused timelines are never kept around once their stream closes.
+    add_subscription(
+        &app.focused_timelines,
+        timeline,
+        room_id,
+        new_timeline_subscription_id(),
+        stream,
+        throttle_ms.map(Duration::from_millis),
+        0,
+    )
+}
 
-                matrix_sdk_ui::eyeball_im::VectorDiff::PopBack => {
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::PopBack,
-                        messages: None,
-                        index: None,
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Insert { index, value } => {
-                    let mut messages = Vec::new();
-                    let message = get_message_from_timeline_item(&value);
-                    messages.push(message);
-
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Insert,
-                        messages: Some(messages),
-                        index: Some(index),
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Set { index, value } => {
-                    let mut messages = Vec::new();
-                    let message = get_message_from_timeline_item(&value);
-                    messages.push(message);
-
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Set,
-                        messages: Some(messages),
-                        index: Some(index),
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Remove { index } => {
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Remove,
-                        messages: None,
-                        index: Some(index),
-                        length: None,
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Truncate { length } => {
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Truncate,
-                        messages: None,
-                        index: None,
-                        length: Some(length),
-                    });
-                }
-                matrix_sdk_ui::eyeball_im::VectorDiff::Reset { values } => {
-                    let mut messages = Vec::new();
-                    for value in values {
-                        let message = get_message_from_timeline_item(&value);
-                        messages.push(message);
-                    }
-                    let _ = stream.add(MessageUpdate {
-                        message_update_type: MessageUpdateType::Reset,
-                        messages: Some(messages),
-                        index: None,
-                        length: None,
-                    });
-                }
-            }
-        }
+fn get_focused_timeline_by_room_id(
+    room_id: &str,
+) -> Result<(Arc<SdkTimeline>, Arc<watch::Sender<PaginationStatus>>), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+    let timelines = app.focused_timelines.lock().unwrap();
+    timelines
+        .get(&room_id)
+        .map(|timeline| {
+            (
+                timeline.timeline.clone(),
+                timeline.pagination_status.clone(),
+            )
+        })
+        .ok_or_else(|| "No event-focused timeline for this room".to_string())
+}
+
+/// Load `count` older events in the event-focused timeline of a room.
+///
+/// Returns whether there are older events left to load.
+pub async fn paginate_event_timeline_backwards(
+    room_id: String,
+    count: u16,
+) -> Result<bool, String> {
+    let (timeline, status) = get_focused_timeline_by_room_id(&room_id)?;
+    paginate_backwards(timeline, status, count).await
+}
+
+/// Load `count` newer events in the event-focused timeline of a room.
+///
+/// Returns whether there are newer events left to load. Once it returns
+/// `false` the timeline caught up with the live end of the room and the
+/// caller should switch back with [`return_to_live_timeline`].
+pub async fn paginate_event_timeline_forwards(room_id: String, count: u16) -> Result<bool, String> {
+    let (timeline, _) = get_focused_timeline_by_room_id(&room_id)?;
+    let hit_end = timeline.paginate_forwards(count).await.map_err(|e| {
+        log_error(format!("Failed to paginate forwards: {}", e));
+        e.to_string()
+    })?;
+    Ok(!hit_end)
+}
+
+/// Drop the event-focused timeline of a room, ending its update stream.
+///
+/// Used when the user scrolled back to the bottom; the live timeline
+/// (see [`subscribe_to_timeline_updates`]) takes over from there.
+pub fn return_to_live_timeline(room_id: String) -> Result<(), String> {
+    let app = GLOBAL_APP.get().ok_or("Global app not initialized")?;
+    let room_id: OwnedRoomId = room_id.parse().map_err(|_| "Failed to parse room ID")?;
+    if app
+        .focused_timelines
+        .lock()
+        .unwrap()
+        .remove(&room_id)
+        .is_none()
+    {
+        log_warn(format!(
+            "No event-focused timeline to close for {}",
+            room_id
+        ));
     }
+    Ok(())
 }
 
-pub async fn get_older_messages(room_id: String, count: u16) -> Result<Vec<Message>, String> {
-    let owned_rrom_id: OwnedRoomId = match room_id.parse() {
-        Ok(id) => id,
-        Err(_) => {
-            println!("Failed to parse room ID");
-            return Err("Failed to parse room ID".to_string());
+#[cfg(test)]
+mod tests {
+    use super::{coalesce_message_updates, Message, MessageType, MessageUpdate, MessageUpdateType};
+
+    fn message(event_id: &str) -> Message {
+        Message {
+            event_id: event_id.to_owned(),
+            sender: "@alice:example.org".to_owned(),
+            content: event_id.to_owned(),
+            timestamp: 0,
+            message_type: MessageType::Message,
+            read_receipts: Vec::new(),
         }
-    };
+    }
 
-    let global_app = GLOBAL_APP.get().unwrap();
-    let room_id_ref = owned_rrom_id.as_ref();
-    let room = global_app.client.get_room(room_id_ref).unwrap();
-    let timeline = room.timeline().await.unwrap();
-
-    let result = timeline.paginate_backwards(count).await;
-    match result {
-        Ok(_) => Ok(get_timeline_items_by_room_id(room_id)),
-        Err(_) => {
-            log_error(format!("Failed to paginate backwards"));
-            Err("Failed to paginate backwards".to_string())
+    fn update(
+        message_update_type: MessageUpdateType,
+        index: Option<usize>,
+        event_id: Option<&str>,
+    ) -> MessageUpdate {
+        MessageUpdate {
+            message_update_type,
+            messages: event_id.map(|event_id| vec![message(event_id)]),
+            index,
+            length: None,
         }
     }
+
+    fn event_ids(updates: &[MessageUpdate]) -> Vec<String> {
+        updates
+            .iter()
+            .flat_map(|update| update.messages.iter().flatten())
+            .map(|message| message.event_id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_coalesce_drops_updates_before_reset() {
+        let updates = vec![
+            update(MessageUpdateType::PushBack, None, Some("$a")),
+            update(MessageUpdateType::Set, Some(0), Some("$b")),
+            update(MessageUpdateType::Reset, None, Some("$c")),
+            update(MessageUpdateType::PushBack, None, Some("$d")),
+        ];
+
+        let coalesced = coalesce_message_updates(updates);
+
+        assert_eq!(coalesced.len(), 2);
+        assert!(matches!(
+            coalesced[0].message_update_type,
+            MessageUpdateType::Reset
+        ));
+        assert_eq!(event_ids(&coalesced), vec!["$c", "$d"]);
+    }
+
+    #[test]
+    fn test_coalesce_folds_consecutive_sets_of_same_index() {
+        let updates = vec![
+            update(MessageUpdateType::Set, Some(3), Some("$a")),
+            update(MessageUpdateType::Set, Some(3), Some("$b")),
+            update(MessageUpdateType::Set, Some(4), Some("$c")),
+            update(MessageUpdateType::Set, Some(3), Some("$d")),
+        ];
+
+        let coalesced = coalesce_message_updates(updates);
+
+        assert_eq!(event_ids(&coalesced), vec!["$b", "$c", "$d"]);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_order_of_unrelated_updates() {
+        let updates = vec![
+            update(MessageUpdateType::PushFront, None, Some("$a")),
+            update(MessageUpdateType::Remove, Some(2), None),
+            update(MessageUpdateType::Insert, Some(1), Some("$b")),
+        ];
+
+        let coalesced = coalesce_message_updates(updates);
+
+        assert_eq!(coalesced.len(), 3);
+        assert_eq!(coalesced[1].index, Some(2));
+        assert_eq!(event_ids(&coalesced), vec!["$a", "$b"]);
+    }
 }
@@ -0,0 +1,107 @@
+use std::{collections::BTreeMap, sync::Mutex, time::Duration};
+
+use matrix_sdk::{ruma::OwnedRoomId, Room};
+use tokio::{sync::broadcast::error::RecvError, task::JoinHandle};
+
+use crate::{
+    api::{logger::log_warn, platform::GLOBAL_RUNTIME},
+    frb_generated::StreamSink,
+    matrix::{rooms::get_room, user_serach::User},
+};
+
+/// How long we keep showing as typing after the last `set_typing(true)`.
+/// Servers drop the notice on their own a bit later anyway.
+const TYPING_EXPIRY: Duration = Duration::from_secs(5);
+
+/// Tasks clearing our typing notice, per room, restarted on every keystroke.
+static TYPING_EXPIRY_TASKS: Mutex<BTreeMap<OwnedRoomId, JoinHandle<()>>> =
+    Mutex::new(BTreeMap::new());
+
+async fn send_typing_notice(room: &Room, typing: bool) {
+    if let Err(e) = room.typing_notice(typing).await {
+        log_warn(format!(
+            "Failed to send typing notice to {}: {}",
+            room.room_id(),
+            e
+        ));
+    }
+}
+
+/// Tell the room whether we are typing. Meant to be called on every
+/// keystroke: the SDK only resends the notice every few seconds, and it's
+/// cleared automatically once the calls stop.
+pub fn set_typing(room_id: String, typing: bool) -> Result<(), String> {
+    tokio::task::block_in_place(|| {
+        let runtime = GLOBAL_RUNTIME
+            .get()
+            .expect("Global runtime not initialized");
+        runtime.block_on(async {
+            let room = get_room(&room_id)?;
+            let room_id = room.room_id().to_owned();
+
+            let expiry = typing.then(|| {
+                let room = room.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(TYPING_EXPIRY).await;
+                    send_typing_notice(&room, false).await;
+                })
+            });
+
+            // Swap the expiry task under a single lock, so concurrent calls
+            // can't leave a stale task running.
+            let previous = {
+                let mut tasks = TYPING_EXPIRY_TASKS.lock().unwrap();
+                match expiry {
+                    Some(task) => tasks.insert(room_id, task),
+                    None => tasks.remove(&room_id),
+                }
+            };
+            if let Some(previous) = previous {
+                previous.abort();
+            }
+
+            send_typing_notice(&room, typing).await;
+            Ok(())
+        })
+    })
+}
+
+/// Stream the users typing in a room, without ourselves; every item is the
+/// full list.
+pub async fn subscribe_to_typing_users(
+    stream: StreamSink<Vec<User>>,
+    room_id: String,
+) -> Result<(), String> {
+    let room = get_room(&room_id)?;
+
+    let (_guard, mut receiver) = room.subscribe_to_typing_notifications();
+
+    loop {
+        let user_ids = match receiver.recv().await {
+            Ok(user_ids) => user_ids,
+            // Only the latest list matters.
+            Err(RecvError::Lagged(_)) => continue,
+            Err(RecvError::Closed) => break,
+        };
+
+        let mut users = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            let member = room.get_member_no_sync(&user_id).await.ok().flatten();
+            users.push(User {
+                user_id: user_id.to_string(),
+                display_name: member
+                    .as_ref()
+                    .and_then(|member| member.display_name().map(|name| name.to_owned())),
+                avatar_url: member
+                    .as_ref()
+                    .and_then(|member| member.avatar_url().map(|uri| uri.to_string())),
+            });
+        }
+
+        if stream.add(users).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}